@@ -0,0 +1,261 @@
+use serde::Serialize;
+
+use super::*;
+
+pub const DEFAULT_PRIMARY_COLOR: &str = "#000000";
+pub const DEFAULT_SECONDARY_COLOR: &str = "#ffffff";
+
+// parses "#rrggbb" into rgb, None for anything else
+pub fn parse_hex_color(color: &str) -> Option<[u8; 3]> {
+    let hex = color.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |idx: usize| u8::from_str_radix(&hex[idx..idx + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stroke {
+    pub color: String,
+    pub points: Vec<Point>,
+}
+
+// things the drawer can undo
+#[derive(Debug, Clone)]
+enum Edit {
+    // the stroke is the last one on the canvas
+    Stroke,
+    // strokes that were on the canvas before the clear
+    Clear(Vec<Stroke>),
+}
+
+// things the drawer can redo
+#[derive(Debug, Clone)]
+enum Undone {
+    Stroke(Stroke),
+    Clear,
+}
+
+// what's on screen after a round's draw ops, every
+// engine folds the same ops into the same strokes
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    primary_color: String,
+    secondary_color: String,
+    strokes: Vec<Stroke>,
+    edits: Vec<Edit>,
+    undone: Vec<Undone>,
+    // true while continue stroke ops extend the last stroke
+    stroking: bool,
+}
+
+impl Default for Canvas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Canvas {
+    pub fn new() -> Self {
+        Self {
+            primary_color: String::from(DEFAULT_PRIMARY_COLOR),
+            secondary_color: String::from(DEFAULT_SECONDARY_COLOR),
+            strokes: Vec::new(),
+            edits: Vec::new(),
+            undone: Vec::new(),
+            stroking: false,
+        }
+    }
+    pub fn from_draw_ops(draw_ops: &[DrawOp]) -> Self {
+        let mut canvas = Self::new();
+        for draw_op in draw_ops {
+            canvas.apply(draw_op);
+        }
+        canvas
+    }
+    // visible strokes, oldest first
+    pub fn strokes(&self) -> &[Stroke] {
+        &self.strokes
+    }
+    pub fn primary_color(&self) -> &str {
+        &self.primary_color
+    }
+    pub fn secondary_color(&self) -> &str {
+        &self.secondary_color
+    }
+    pub fn color(&self, color_type: ColorType) -> &str {
+        match color_type {
+            ColorType::Primary => &self.primary_color,
+            ColorType::Secondary => &self.secondary_color,
+        }
+    }
+    pub fn can_undo(&self) -> bool {
+        !self.edits.is_empty()
+    }
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+    pub fn apply(&mut self, draw_op: &DrawOp) {
+        let Some(do_type) = &draw_op.do_type else {
+            return;
+        };
+        match do_type {
+            DoType::SetColor(set_color) => match set_color.color_type() {
+                ColorType::Primary => {
+                    self.primary_color.clone_from(&set_color.color)
+                }
+                ColorType::Secondary => {
+                    self.secondary_color.clone_from(&set_color.color)
+                }
+            },
+            DoType::StartStroke(start_stroke) => {
+                self.strokes.push(Stroke {
+                    color: self.color(start_stroke.color_type()).to_owned(),
+                    points: vec![Point {
+                        x: start_stroke.x,
+                        y: start_stroke.y,
+                    }],
+                });
+                self.edits.push(Edit::Stroke);
+                // a new edit makes anything undone unreachable
+                self.undone.clear();
+                self.stroking = true;
+            }
+            DoType::ContinueStroke(continue_stroke) => {
+                if !self.stroking {
+                    return;
+                }
+                if let Some(stroke) = self.strokes.last_mut() {
+                    stroke.points.push(Point {
+                        x: continue_stroke.x,
+                        y: continue_stroke.y,
+                    });
+                }
+            }
+            DoType::StrokePoints(stroke_points) => {
+                let Some(mut points) = stroke_points.decode() else {
+                    return;
+                };
+                if stroke_points.start {
+                    self.strokes.push(Stroke {
+                        color: self
+                            .color(stroke_points.color_type())
+                            .to_owned(),
+                        points,
+                    });
+                    self.edits.push(Edit::Stroke);
+                    self.undone.clear();
+                    self.stroking = true;
+                } else if self.stroking {
+                    if let Some(stroke) = self.strokes.last_mut() {
+                        stroke.points.append(&mut points);
+                    }
+                }
+            }
+            DoType::ClearScreen(_) => {
+                self.stroking = false;
+                // clearing a blank canvas isn't worth undoing
+                if self.strokes.is_empty() {
+                    return;
+                }
+                let cleared = std::mem::take(&mut self.strokes);
+                self.edits.push(Edit::Clear(cleared));
+                self.undone.clear();
+            }
+            DoType::Undo(_) => {
+                self.stroking = false;
+                match self.edits.pop() {
+                    Some(Edit::Stroke) => {
+                        if let Some(stroke) = self.strokes.pop() {
+                            self.undone.push(Undone::Stroke(stroke));
+                        }
+                    }
+                    Some(Edit::Clear(cleared)) => {
+                        self.strokes = cleared;
+                        self.undone.push(Undone::Clear);
+                    }
+                    None => (),
+                }
+            }
+            DoType::Redo(_) => {
+                self.stroking = false;
+                match self.undone.pop() {
+                    Some(Undone::Stroke(stroke)) => {
+                        self.strokes.push(stroke);
+                        self.edits.push(Edit::Stroke);
+                    }
+                    Some(Undone::Clear) => {
+                        let cleared = std::mem::take(&mut self.strokes);
+                        self.edits.push(Edit::Clear(cleared));
+                    }
+                    None => (),
+                }
+            }
+        }
+    }
+}
+
+impl Round {
+    // every drawer's canvas, the drawer's first
+    pub fn canvases(&self) -> Vec<Canvas> {
+        self.drawer_ids()
+            .filter_map(|drawer_id| self.canvas_of(drawer_id))
+            .collect()
+    }
+}
+
+// most draw ops a drawer can send in a round
+pub const MAX_DRAW_OPS_PER_ROUND: usize = 10_000;
+
+fn valid_point(x: f32, y: f32) -> bool {
+    (0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y)
+}
+
+impl DrawOp {
+    // NaN fails the range check too, so anything
+    // that passes is safe to render or export
+    pub fn validate(&self) -> Result<(), DrawOpRejectReason> {
+        match &self.do_type {
+            None => Err(DrawOpRejectReason::EmptyOp),
+            Some(DoType::SetColor(set_color)) => {
+                match parse_hex_color(&set_color.color) {
+                    Some(_) => Ok(()),
+                    None => Err(DrawOpRejectReason::InvalidColor),
+                }
+            }
+            Some(DoType::StartStroke(start_stroke)) => {
+                if valid_point(start_stroke.x, start_stroke.y) {
+                    Ok(())
+                } else {
+                    Err(DrawOpRejectReason::InvalidPoint)
+                }
+            }
+            Some(DoType::ContinueStroke(continue_stroke)) => {
+                if valid_point(continue_stroke.x, continue_stroke.y) {
+                    Ok(())
+                } else {
+                    Err(DrawOpRejectReason::InvalidPoint)
+                }
+            }
+            Some(DoType::StrokePoints(stroke_points)) => {
+                if stroke_points.deltas.len() / 2 + 1 > MAX_POINTS_PER_OP {
+                    return Err(DrawOpRejectReason::TooManyPoints);
+                }
+                match stroke_points.decode() {
+                    Some(_) => Ok(()),
+                    None => Err(DrawOpRejectReason::InvalidPoint),
+                }
+            }
+            Some(DoType::ClearScreen(_))
+            | Some(DoType::Undo(_))
+            | Some(DoType::Redo(_)) => Ok(()),
+        }
+    }
+}
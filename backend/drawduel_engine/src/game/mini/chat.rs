@@ -0,0 +1,60 @@
+use super::*;
+
+// older messages are dropped once the history is full
+pub const MAX_CHAT_HISTORY: usize = 100;
+// longer messages are cut off, counted in chars
+pub const MAX_CHAT_LEN: usize = 200;
+
+// trims the text and cuts it down to size,
+// None if there's nothing left to send
+pub fn sanitize_chat(text: &str) -> Option<String> {
+    let text: String = text.trim().chars().take(MAX_CHAT_LEN).collect();
+    let text = text.trim_end();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text.to_owned())
+    }
+}
+
+impl Round {
+    // true if any run of words in the text normalizes to the
+    // round's word, e.g. "i love ice cream" or "ICE-CREAM!"
+    // for "ice cream", but not "iced" or "screaming"
+    pub fn mentions_word(&self, text: &str) -> bool {
+        let Some(word) = self.word() else {
+            return false;
+        };
+        let word = normalize_guess(word);
+        if word.is_empty() {
+            return false;
+        }
+        word_runs(text, word.chars().count()).contains(&word)
+    }
+    // why the player can't send the text, None if they can,
+    // nobody can give the word away until it's revealed
+    pub fn chat_reject_reason(
+        &self,
+        player_id: PlayerId,
+        text: &str,
+    ) -> Option<ChatRejectReason> {
+        if self.word_revealed() || !self.mentions_word(text) {
+            return None;
+        }
+        if self.is_drawer(player_id) {
+            Some(ChatRejectReason::DrawerTypedWord)
+        } else {
+            Some(ChatRejectReason::AnswerInChat)
+        }
+    }
+}
+
+impl Game {
+    pub fn push_chat(&mut self, message: ChatMessage) {
+        if self.chat.len() >= MAX_CHAT_HISTORY {
+            let overflow = self.chat.len() + 1 - MAX_CHAT_HISTORY;
+            self.chat.drain(..overflow);
+        }
+        self.chat.push(message);
+    }
+}
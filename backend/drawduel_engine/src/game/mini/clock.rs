@@ -0,0 +1,53 @@
+use std::cell::Cell;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::EpochMs;
+
+// the engine never reads the time itself, it asks a
+// clock, so the same events always advance a game the
+// same way no matter where or when they're replayed
+pub trait Clock {
+    // ms since epoch
+    fn now(&self) -> EpochMs;
+    fn ms_from_now(&self, duration: Duration) -> EpochMs {
+        self.now() + duration.as_millis() as EpochMs
+    }
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> EpochMs {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time went backwards!?")
+            // truncating u128 to u64 is safe because the sun
+            // will explode before u64 overflows
+            .as_millis() as EpochMs
+    }
+}
+
+// only moves when told to, for tests and replays
+pub struct ManualClock {
+    now: Cell<EpochMs>,
+}
+
+impl ManualClock {
+    pub fn new(now: EpochMs) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+    pub fn set(&self, now: EpochMs) {
+        self.now.set(now);
+    }
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.ms_from_now(duration));
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> EpochMs {
+        self.now.get()
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+// two drawers and at least one guesser
+pub const MIN_DUEL_PLAYERS: usize = 3;
+
+impl GameSettings {
+    pub fn min_players(&self) -> usize {
+        if self.duel {
+            MIN_DUEL_PLAYERS
+        } else {
+            MIN_PLAYERS
+        }
+    }
+}
+
+impl Round {
+    pub fn is_duel(&self) -> bool {
+        self.rival_id.is_some()
+    }
+    pub fn is_drawer(&self, player_id: PlayerId) -> bool {
+        self.drawer_id == player_id || self.rival_id == Some(player_id)
+    }
+    // the drawer first, then the rival if it's a duel
+    pub fn drawer_ids(&self) -> impl Iterator<Item = PlayerId> {
+        std::iter::once(self.drawer_id).chain(self.rival_id)
+    }
+    // the drawer's own stream, None if they aren't drawing
+    pub fn draw_ops_of(&self, drawer_id: PlayerId) -> Option<&Vec<DrawOp>> {
+        if self.drawer_id == drawer_id {
+            Some(&self.draw_ops)
+        } else if self.rival_id == Some(drawer_id) {
+            Some(&self.rival_draw_ops)
+        } else {
+            None
+        }
+    }
+    pub fn draw_ops_of_mut(
+        &mut self,
+        drawer_id: PlayerId,
+    ) -> Option<&mut Vec<DrawOp>> {
+        if self.drawer_id == drawer_id {
+            Some(&mut self.draw_ops)
+        } else if self.rival_id == Some(drawer_id) {
+            Some(&mut self.rival_draw_ops)
+        } else {
+            None
+        }
+    }
+    pub fn canvas_of(&self, drawer_id: PlayerId) -> Option<Canvas> {
+        self.draw_ops_of(drawer_id)
+            .map(|draw_ops| Canvas::from_draw_ops(draw_ops))
+    }
+    // true if no drawer has drawn anything yet
+    pub fn is_blank(&self) -> bool {
+        self.draw_ops.is_empty() && self.rival_draw_ops.is_empty()
+    }
+    // the drawers a correct guess credits, the one the
+    // guesser was watching or every drawer if they weren't
+    pub fn credited_drawer_ids(&self, guess: &Guess) -> Vec<PlayerId> {
+        self.drawer_ids()
+            .filter(|&drawer_id| {
+                guess
+                    .watching_id
+                    .is_none_or(|watching_id| watching_id == drawer_id)
+            })
+            .collect()
+    }
+}
+
+impl Game {
+    pub fn min_players(&self) -> usize {
+        self.settings().min_players()
+    }
+    // the player after the drawer in the same order
+    // drawers take turns in, None if there's nobody else
+    pub fn next_rival_id(&self, drawer_id: PlayerId) -> Option<PlayerId> {
+        self.next_connected_after(Some(drawer_id))
+            .filter(|&rival_id| rival_id != drawer_id)
+    }
+}
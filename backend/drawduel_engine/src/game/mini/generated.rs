@@ -8,6 +8,84 @@ pub struct Game {
     pub players: ::std::collections::HashMap<u32, Player>,
     #[prost(message, optional, tag="2")]
     pub round: ::core::option::Option<Round>,
+    #[prost(message, optional, tag="3")]
+    pub settings: ::core::option::Option<GameSettings>,
+    #[prost(message, repeated, tag="4")]
+    pub round_likes: ::prost::alloc::vec::Vec<RoundLikes>,
+    #[prost(uint32, optional, tag="5")]
+    pub host_id: ::core::option::Option<u32>,
+    #[prost(uint32, tag="6")]
+    pub spectators: u32,
+    #[prost(message, repeated, tag="7")]
+    pub chat: ::prost::alloc::vec::Vec<ChatMessage>,
+}
+/// separate from guesses, never scored and
+/// open in every phase, oldest first
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ChatMessage {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+    #[prost(string, tag="2")]
+    pub text: ::prost::alloc::string::String,
+}
+/// per room, the host can change these in the lobby,
+/// durations are in secs, a hint interval of 0 turns
+/// hints off, rounds is how many times every player
+/// draws before the match is over, and like bonus is
+/// what the drawer scores per like, 0 turns it off,
+/// duel gives every round a 2nd drawer, the rival, who
+/// draws the same word on their own canvas, vote bonus
+/// is what the drawer with the best drawing scores
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GameSettings {
+    #[prost(enumeration="ScoringPolicyType", tag="1")]
+    pub scoring_policy: i32,
+    #[prost(uint32, tag="2")]
+    pub choose_word_secs: u32,
+    #[prost(uint32, tag="3")]
+    pub pre_play_secs: u32,
+    #[prost(uint32, tag="4")]
+    pub play_easy_secs: u32,
+    #[prost(uint32, tag="5")]
+    pub play_hard_secs: u32,
+    #[prost(uint32, tag="6")]
+    pub inactive_drawer_secs: u32,
+    #[prost(uint32, tag="7")]
+    pub post_play_secs: u32,
+    #[prost(uint32, tag="8")]
+    pub hint_interval_secs: u32,
+    #[prost(uint32, tag="9")]
+    pub rounds: u32,
+    #[prost(uint32, tag="10")]
+    pub max_players: u32,
+    #[prost(uint32, tag="11")]
+    pub base_score: u32,
+    #[prost(uint32, tag="12")]
+    pub first_correct_bonus: u32,
+    #[prost(uint32, tag="13")]
+    pub easy_multipler: u32,
+    #[prost(uint32, tag="14")]
+    pub hard_multipler: u32,
+    #[prost(uint32, tag="15")]
+    pub like_bonus: u32,
+    #[prost(bool, tag="16")]
+    pub duel: bool,
+    #[prost(uint32, tag="17")]
+    pub vote_secs: u32,
+    #[prost(uint32, tag="18")]
+    pub vote_bonus: u32,
+}
+/// one per round in the current match
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RoundLikes {
+    #[prost(uint32, tag="1")]
+    pub round_id: u32,
+    #[prost(uint32, tag="2")]
+    pub drawer_id: u32,
+    #[prost(uint32, repeated, tag="3")]
+    pub liker_ids: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, optional, tag="4")]
+    pub rival_id: ::core::option::Option<u32>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Player {
@@ -21,7 +99,13 @@ pub struct Player {
     pub round_score: u32,
     #[prost(bool, tag="6")]
     pub connected: bool,
+    #[prost(uint32, tag="7")]
+    pub times_drawn: u32,
+    #[prost(bool, tag="8")]
+    pub ready: bool,
 }
+/// in duel rounds the rival's draw ops go in their
+/// own stream, the drawer still picks the word
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Round {
     #[prost(uint32, tag="1")]
@@ -48,13 +132,46 @@ pub struct Round {
     pub guesses: ::prost::alloc::vec::Vec<Guess>,
     #[prost(message, repeated, tag="9")]
     pub hints: ::prost::alloc::vec::Vec<Hint>,
+    #[prost(uint32, optional, tag="13")]
+    pub rival_id: ::core::option::Option<u32>,
+    #[prost(message, repeated, tag="14")]
+    pub rival_draw_ops: ::prost::alloc::vec::Vec<DrawOp>,
+    #[prost(message, repeated, tag="15")]
+    pub votes: ::prost::alloc::vec::Vec<Vote>,
+}
+/// guessers pick the drawer with the best drawing
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Vote {
+    #[prost(uint32, tag="1")]
+    pub voter_id: u32,
+    #[prost(uint32, tag="2")]
+    pub drawer_id: u32,
 }
+/// players with the same place tied
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Standing {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+    #[prost(uint32, tag="2")]
+    pub place: u32,
+    #[prost(uint32, tag="3")]
+    pub score: u32,
+    #[prost(uint32, tag="4")]
+    pub draw_score: u32,
+    #[prost(uint32, tag="5")]
+    pub guess_score: u32,
+}
+/// in duel rounds watching is the drawer whose canvas
+/// the guesser had up, their draw ops are the ones
+/// after draw ops counts, unset means both canvases
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Guess {
     #[prost(uint32, tag="1")]
     pub guesser_id: u32,
     #[prost(uint32, tag="4")]
     pub after_draw_ops: u32,
+    #[prost(uint32, optional, tag="5")]
+    pub watching_id: ::core::option::Option<u32>,
     #[prost(oneof="guess::GuessType", tags="2, 3")]
     pub guess_type: ::core::option::Option<guess::GuessType>,
 }
@@ -68,15 +185,22 @@ pub mod guess {
         CorrectGuess(super::CorrectGuess),
     }
 }
+/// close guesses were a typo or two from the word, and
+/// guesses after solving come from players who already
+/// know it, neither is shown to players still guessing
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct IncorrectGuess {
     #[prost(string, tag="1")]
     pub guess: ::prost::alloc::string::String,
+    #[prost(bool, tag="2")]
+    pub close: bool,
+    #[prost(bool, tag="3")]
+    pub after_solving: bool,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CorrectGuess {
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Hint {
     #[prost(uint32, tag="3")]
     pub after_draw_ops: u32,
@@ -85,7 +209,7 @@ pub struct Hint {
 }
 /// Nested message and enum types in `Hint`.
 pub mod hint {
-    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum HintType {
         #[prost(message, tag="1")]
         RevealLength(super::RevealLength),
@@ -93,17 +217,26 @@ pub mod hint {
         RevealLetter(super::RevealLetter),
     }
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+/// hints carry everything needed to render
+/// them since guessers never see the word
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RevealLength {
+    #[prost(uint32, repeated, tag="1")]
+    pub word_lengths: ::prost::alloc::vec::Vec<u32>,
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RevealLetter {
     #[prost(uint32, tag="1")]
     pub index: u32,
+    #[prost(string, tag="2")]
+    pub letter: ::prost::alloc::string::String,
 }
+/// x & y are normalized, 0 is the left or top
+/// edge of the canvas and 1 is the right or bottom,
+/// colors are hex like #rrggbb
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DrawOp {
-    #[prost(oneof="draw_op::DoType", tags="1, 2, 3, 4, 5, 6")]
+    #[prost(oneof="draw_op::DoType", tags="1, 2, 3, 4, 5, 6, 7")]
     pub do_type: ::core::option::Option<draw_op::DoType>,
 }
 /// Nested message and enum types in `DrawOp`.
@@ -122,6 +255,8 @@ pub mod draw_op {
         Undo(super::DoUndo),
         #[prost(message, tag="6")]
         Redo(super::DoRedo),
+        #[prost(message, tag="7")]
+        StrokePoints(super::DoStrokePoints),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -147,6 +282,24 @@ pub struct DoContinueStroke {
     #[prost(float, tag="2")]
     pub y: f32,
 }
+/// a batch of stroke points quantized to a grid, the
+/// first point is absolute and every point after it is
+/// a dx, dy pair of deltas from the point before it,
+/// starts a new stroke if start is set, otherwise it
+/// continues the current stroke
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DoStrokePoints {
+    #[prost(bool, tag="1")]
+    pub start: bool,
+    #[prost(enumeration="ColorType", tag="2")]
+    pub color_type: i32,
+    #[prost(uint32, tag="3")]
+    pub x: u32,
+    #[prost(uint32, tag="4")]
+    pub y: u32,
+    #[prost(sint32, repeated, tag="5")]
+    pub deltas: ::prost::alloc::vec::Vec<i32>,
+}
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct DoClearScreen {
 }
@@ -162,12 +315,16 @@ pub struct DoRedo {
 // but can also be other things, like
 // communicating server errors to client
 
+/// spectators get an id that isn't in the game's
+/// players, and a view with the words hidden
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SeSetGame {
     #[prost(uint32, tag="1")]
     pub player_id: u32,
-    #[prost(message, optional, tag="2")]
-    pub game: ::core::option::Option<Game>,
+    #[prost(message, optional, boxed, tag="2")]
+    pub game: ::core::option::Option<::prost::alloc::boxed::Box<Game>>,
+    #[prost(bool, tag="3")]
+    pub spectating: bool,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SeError {
@@ -261,6 +418,8 @@ pub struct SeRoundNew {
     pub starting_phase: i32,
     #[prost(uint64, tag="6")]
     pub phase_ends_at: u64,
+    #[prost(uint32, optional, tag="7")]
+    pub rival_id: ::core::option::Option<u32>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SeRoundChangePhase {
@@ -269,6 +428,38 @@ pub struct SeRoundChangePhase {
     #[prost(uint64, tag="2")]
     pub phase_ends_at: u64,
 }
+/// round_id not necessary as it always
+/// refers to the current round
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SeRoundHint {
+    #[prost(message, optional, tag="1")]
+    pub hint: ::core::option::Option<Hint>,
+}
+/// round_id not necessary as it always
+/// refers to the current round
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SeRoundGuess {
+    #[prost(message, optional, tag="1")]
+    pub guess: ::core::option::Option<Guess>,
+}
+/// sent to guessers once they're allowed to
+/// know the word, e.g. after guessing it
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SeRoundRevealWords {
+    #[prost(uint32, tag="1")]
+    pub easy_word: u32,
+    #[prost(uint32, tag="2")]
+    pub hard_word: u32,
+}
+/// ends the round early, moving it
+/// straight to the post play phase
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SeRoundSkip {
+    #[prost(enumeration="SkipReason", tag="1")]
+    pub reason: i32,
+    #[prost(uint64, tag="2")]
+    pub phase_ends_at: u64,
+}
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SePlayerChooseWord {
     #[prost(uint32, tag="1")]
@@ -284,6 +475,134 @@ pub struct SePlayerGuessWord {
     pub guess: ::prost::alloc::string::String,
     #[prost(uint32, tag="3")]
     pub after_draw_ops: u32,
+    #[prost(uint32, optional, tag="4")]
+    pub watching_id: ::core::option::Option<u32>,
+}
+/// only sent to the guesser, lets them know
+/// they're a typo or two away from the word
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerGuessClose {
+    #[prost(uint32, tag="1")]
+    pub guesser_id: u32,
+}
+/// a duel's like goes to both drawers, so
+/// the rival is set if it was a duel round
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct MostLiked {
+    #[prost(uint32, tag="1")]
+    pub round_id: u32,
+    #[prost(uint32, tag="2")]
+    pub drawer_id: u32,
+    #[prost(uint32, tag="3")]
+    pub likes: u32,
+    #[prost(uint32, optional, tag="4")]
+    pub rival_id: ::core::option::Option<u32>,
+}
+/// last round of the match is over, best player first,
+/// most liked is unset if nobody liked any drawing
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SeGameOver {
+    #[prost(message, repeated, tag="1")]
+    pub standings: ::prost::alloc::vec::Vec<Standing>,
+    #[prost(message, optional, tag="2")]
+    pub most_liked: ::core::option::Option<MostLiked>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerPlayAgain {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerStartMatch {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+}
+/// players ready up in the lobby, the match starts
+/// on its own once every connected player is ready
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerReady {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+    #[prost(bool, tag="2")]
+    pub ready: bool,
+}
+/// spectators watch without joining the players,
+/// only how many of them there are is shared
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SeSpectators {
+    #[prost(uint32, tag="1")]
+    pub spectators: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerVote {
+    #[prost(uint32, tag="1")]
+    pub voter_id: u32,
+    #[prost(uint32, tag="2")]
+    pub drawer_id: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SeRoundVote {
+    #[prost(message, optional, tag="1")]
+    pub vote: ::core::option::Option<Vote>,
+}
+/// the drawers with the most votes split the vote
+/// bonus, nobody wins if nobody voted, the bonus
+/// follows in its own score events
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SeVoteResult {
+    #[prost(uint32, repeated, tag="1")]
+    pub winner_ids: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, tag="2")]
+    pub inc_by: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SePlayerChat {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+    #[prost(string, tag="2")]
+    pub text: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SeRoomChat {
+    #[prost(message, optional, tag="1")]
+    pub message: ::core::option::Option<ChatMessage>,
+}
+/// only sent to the player, nobody else saw
+/// their message since it gave away the word
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerChatReject {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+    #[prost(enumeration="ChatRejectReason", tag="2")]
+    pub reason: i32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerEndGame {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerKick {
+    #[prost(uint32, tag="1")]
+    pub host_id: u32,
+    #[prost(uint32, tag="2")]
+    pub player_id: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerChangeSettings {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+    #[prost(message, optional, tag="2")]
+    pub settings: ::core::option::Option<GameSettings>,
+}
+/// only sent to the drawer, their draw op
+/// wasn't applied and nobody else saw it
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SePlayerDrawOpReject {
+    #[prost(uint32, tag="1")]
+    pub drawer_id: u32,
+    #[prost(enumeration="DrawOpRejectReason", tag="2")]
+    pub reason: i32,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct SePlayerLikeRound {
@@ -292,9 +611,18 @@ pub struct SePlayerLikeRound {
     #[prost(uint32, tag="2")]
     pub round_id: u32,
 }
+/// the like as the server recorded it, the drawers'
+/// bonus follows in its own score events
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SeRoundLike {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+    #[prost(uint32, tag="2")]
+    pub round_id: u32,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ServerEvent {
-    #[prost(oneof="server_event::SeType", tags="1, 2, 3, 4, 10, 11, 5, 6, 7, 8, 9, 12, 13, 14, 15, 16, 17, 18")]
+    #[prost(oneof="server_event::SeType", tags="1, 2, 3, 4, 10, 11, 5, 6, 7, 8, 9, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39")]
     pub se_type: ::core::option::Option<server_event::SeType>,
 }
 /// Nested message and enum types in `ServerEvent`.
@@ -337,6 +665,48 @@ pub mod server_event {
         RoundIncGuessScore(super::SeRoundIncGuessScore),
         #[prost(message, tag="18")]
         RoundChangePhase(super::SeRoundChangePhase),
+        #[prost(message, tag="19")]
+        RoundSkip(super::SeRoundSkip),
+        #[prost(message, tag="20")]
+        RoundHint(super::SeRoundHint),
+        #[prost(message, tag="21")]
+        RoundGuess(super::SeRoundGuess),
+        #[prost(message, tag="22")]
+        RoundRevealWords(super::SeRoundRevealWords),
+        #[prost(message, tag="23")]
+        PlayerGuessClose(super::SePlayerGuessClose),
+        #[prost(message, tag="24")]
+        PlayerChangeSettings(super::SePlayerChangeSettings),
+        #[prost(message, tag="25")]
+        GameOver(super::SeGameOver),
+        #[prost(message, tag="26")]
+        PlayerPlayAgain(super::SePlayerPlayAgain),
+        #[prost(message, tag="27")]
+        PlayerDrawOpReject(super::SePlayerDrawOpReject),
+        #[prost(message, tag="28")]
+        PlayerStartMatch(super::SePlayerStartMatch),
+        #[prost(message, tag="29")]
+        PlayerEndGame(super::SePlayerEndGame),
+        #[prost(message, tag="30")]
+        PlayerKick(super::SePlayerKick),
+        #[prost(message, tag="31")]
+        PlayerReady(super::SePlayerReady),
+        #[prost(message, tag="32")]
+        Spectators(super::SeSpectators),
+        #[prost(message, tag="33")]
+        PlayerVote(super::SePlayerVote),
+        #[prost(message, tag="34")]
+        RoundVote(super::SeRoundVote),
+        #[prost(message, tag="35")]
+        VoteResult(super::SeVoteResult),
+        #[prost(message, tag="36")]
+        PlayerChat(super::SePlayerChat),
+        #[prost(message, tag="37")]
+        RoomChat(super::SeRoomChat),
+        #[prost(message, tag="38")]
+        PlayerChatReject(super::SePlayerChatReject),
+        #[prost(message, tag="39")]
+        RoundLike(super::SeRoundLike),
     }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -364,6 +734,8 @@ pub struct CeGuessWord {
     pub guess: ::prost::alloc::string::String,
     #[prost(uint32, tag="3")]
     pub after_draw_ops: u32,
+    #[prost(uint32, optional, tag="4")]
+    pub watching_id: ::core::option::Option<u32>,
 }
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CeLikeRound {
@@ -375,9 +747,43 @@ pub struct CeDrawOp {
     #[prost(message, optional, tag="1")]
     pub draw_op: ::core::option::Option<DrawOp>,
 }
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CeChangeSettings {
+    #[prost(message, optional, tag="1")]
+    pub settings: ::core::option::Option<GameSettings>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CePlayAgain {
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CeStartMatch {
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CeEndGame {
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CeKickPlayer {
+    #[prost(uint32, tag="1")]
+    pub player_id: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CeReady {
+    #[prost(bool, tag="1")]
+    pub ready: bool,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CeVote {
+    #[prost(uint32, tag="1")]
+    pub drawer_id: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CeChat {
+    #[prost(string, tag="1")]
+    pub text: ::prost::alloc::string::String,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientEvent {
-    #[prost(oneof="client_event::CeType", tags="1, 2, 3, 4, 5")]
+    #[prost(oneof="client_event::CeType", tags="1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13")]
     pub ce_type: ::core::option::Option<client_event::CeType>,
 }
 /// Nested message and enum types in `ClientEvent`.
@@ -394,12 +800,63 @@ pub mod client_event {
         LikeRound(super::CeLikeRound),
         #[prost(message, tag="5")]
         DrawOp(super::CeDrawOp),
+        #[prost(message, tag="6")]
+        ChangeSettings(super::CeChangeSettings),
+        #[prost(message, tag="7")]
+        PlayAgain(super::CePlayAgain),
+        #[prost(message, tag="8")]
+        StartMatch(super::CeStartMatch),
+        #[prost(message, tag="9")]
+        EndGame(super::CeEndGame),
+        #[prost(message, tag="10")]
+        KickPlayer(super::CeKickPlayer),
+        #[prost(message, tag="11")]
+        Ready(super::CeReady),
+        #[prost(message, tag="12")]
+        Vote(super::CeVote),
+        #[prost(message, tag="13")]
+        Chat(super::CeChat),
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ScoringPolicyType {
+    Classic = 0,
+    LinearDecay = 1,
+    DrawerPerGuess = 2,
+    Ranked = 3,
+}
+impl ScoringPolicyType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Classic => "CLASSIC",
+            Self::LinearDecay => "LINEAR_DECAY",
+            Self::DrawerPerGuess => "DRAWER_PER_GUESS",
+            Self::Ranked => "RANKED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CLASSIC" => Some(Self::Classic),
+            "LINEAR_DECAY" => Some(Self::LinearDecay),
+            "DRAWER_PER_GUESS" => Some(Self::DrawerPerGuess),
+            "RANKED" => Some(Self::Ranked),
+            _ => None,
+        }
     }
 }
 /// choose word 10 secs
 /// pre play 5 secs
 /// play 45 - 60 secs
+/// vote 10 secs, only after duel rounds since solo
+/// rounds have just the one drawing to vote for
 /// post play 5 secs
+/// game over until someone plays again
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
 pub enum Phase {
@@ -407,6 +864,8 @@ pub enum Phase {
     PrePlay = 1,
     Play = 2,
     PostPlay = 3,
+    GameOver = 4,
+    Vote = 5,
 }
 impl Phase {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -419,6 +878,8 @@ impl Phase {
             Self::PrePlay => "PRE_PLAY",
             Self::Play => "PLAY",
             Self::PostPlay => "POST_PLAY",
+            Self::GameOver => "GAME_OVER",
+            Self::Vote => "VOTE",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -428,6 +889,8 @@ impl Phase {
             "PRE_PLAY" => Some(Self::PrePlay),
             "PLAY" => Some(Self::Play),
             "POST_PLAY" => Some(Self::PostPlay),
+            "GAME_OVER" => Some(Self::GameOver),
+            "VOTE" => Some(Self::Vote),
             _ => None,
         }
     }
@@ -490,6 +953,10 @@ pub enum SeErrorType {
     Unknown = 0,
     AlreadyConnected = 1,
     FullGame = 2,
+    NameTooShort = 3,
+    NameTooLong = 4,
+    NameBlocked = 5,
+    Kicked = 6,
 }
 impl SeErrorType {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -501,6 +968,10 @@ impl SeErrorType {
             Self::Unknown => "UNKNOWN",
             Self::AlreadyConnected => "ALREADY_CONNECTED",
             Self::FullGame => "FULL_GAME",
+            Self::NameTooShort => "NAME_TOO_SHORT",
+            Self::NameTooLong => "NAME_TOO_LONG",
+            Self::NameBlocked => "NAME_BLOCKED",
+            Self::Kicked => "KICKED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -509,6 +980,97 @@ impl SeErrorType {
             "UNKNOWN" => Some(Self::Unknown),
             "ALREADY_CONNECTED" => Some(Self::AlreadyConnected),
             "FULL_GAME" => Some(Self::FullGame),
+            "NAME_TOO_SHORT" => Some(Self::NameTooShort),
+            "NAME_TOO_LONG" => Some(Self::NameTooLong),
+            "NAME_BLOCKED" => Some(Self::NameBlocked),
+            "KICKED" => Some(Self::Kicked),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SkipReason {
+    InactiveDrawer = 0,
+}
+impl SkipReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::InactiveDrawer => "INACTIVE_DRAWER",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INACTIVE_DRAWER" => Some(Self::InactiveDrawer),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ChatRejectReason {
+    AnswerInChat = 0,
+    DrawerTypedWord = 1,
+}
+impl ChatRejectReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::AnswerInChat => "ANSWER_IN_CHAT",
+            Self::DrawerTypedWord => "DRAWER_TYPED_WORD",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ANSWER_IN_CHAT" => Some(Self::AnswerInChat),
+            "DRAWER_TYPED_WORD" => Some(Self::DrawerTypedWord),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum DrawOpRejectReason {
+    InvalidPoint = 0,
+    InvalidColor = 1,
+    EmptyOp = 2,
+    TooManyOps = 3,
+    TooFast = 4,
+    TooManyPoints = 5,
+}
+impl DrawOpRejectReason {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::InvalidPoint => "INVALID_POINT",
+            Self::InvalidColor => "INVALID_COLOR",
+            Self::EmptyOp => "EMPTY_OP",
+            Self::TooManyOps => "TOO_MANY_OPS",
+            Self::TooFast => "TOO_FAST",
+            Self::TooManyPoints => "TOO_MANY_POINTS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INVALID_POINT" => Some(Self::InvalidPoint),
+            "INVALID_COLOR" => Some(Self::InvalidColor),
+            "EMPTY_OP" => Some(Self::EmptyOp),
+            "TOO_MANY_OPS" => Some(Self::TooManyOps),
+            "TOO_FAST" => Some(Self::TooFast),
+            "TOO_MANY_POINTS" => Some(Self::TooManyPoints),
             _ => None,
         }
     }
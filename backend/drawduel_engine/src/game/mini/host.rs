@@ -0,0 +1,46 @@
+use super::*;
+
+impl Game {
+    pub fn is_host(&self, player_id: PlayerId) -> bool {
+        self.host_id == Some(player_id)
+    }
+    // the first player to join hosts, if the host leaves or
+    // disconnects the connected player who's been around
+    // the longest takes over, so the room always has one
+    pub fn hand_off_host(&mut self) {
+        let host_connected = self
+            .host_id
+            .and_then(|host_id| self.players.get(&host_id))
+            .is_some_and(|host| host.connected);
+        if host_connected {
+            return;
+        }
+        self.host_id = self
+            .players
+            .iter()
+            .filter(|(_, player)| player.connected)
+            .map(|(&player_id, _)| player_id)
+            .min();
+    }
+    // the host can only start a match from the lobby,
+    // and only if there's enough players for a round
+    pub fn can_start_match(&self, player_id: PlayerId) -> bool {
+        self.is_host(player_id)
+            && self.in_lobby()
+            && self.connected_players() >= self.min_players()
+    }
+    // ends the match early or after its last round
+    pub fn end_match(&mut self, send_buf: &mut Vec<ServerEvent>) {
+        let Some(round) = &mut self.round else {
+            return;
+        };
+        round.phase = Phase::GameOver.into();
+        self.unready_all();
+        send_buf.push(ServerEvent {
+            se_type: Some(SeType::GameOver(SeGameOver {
+                standings: standings(&self.players),
+                most_liked: most_liked(&self.round_likes),
+            })),
+        });
+    }
+}
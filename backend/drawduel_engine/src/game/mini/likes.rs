@@ -0,0 +1,76 @@
+use super::*;
+
+// the round with the most likes, the earlier round wins
+// ties, None if nobody liked anything
+pub fn most_liked(round_likes: &[RoundLikes]) -> Option<MostLiked> {
+    round_likes
+        .iter()
+        .filter(|round_likes| !round_likes.liker_ids.is_empty())
+        // max_by_key keeps the last max, so go backwards
+        .rev()
+        .max_by_key(|round_likes| round_likes.liker_ids.len())
+        .map(|round_likes| MostLiked {
+            round_id: round_likes.round_id,
+            drawer_id: round_likes.drawer_id,
+            likes: round_likes.liker_ids.len() as u32,
+            rival_id: round_likes.rival_id,
+        })
+}
+
+impl Game {
+    pub fn likes(&self, round_id: RoundId) -> u32 {
+        self.round_likes
+            .iter()
+            .find(|round_likes| round_likes.round_id == round_id)
+            .map(|round_likes| round_likes.liker_ids.len() as u32)
+            .unwrap_or(0)
+    }
+    // every drawer of the round scores the like bonus, it
+    // only counts toward round scores if the round's still on
+    pub fn like_score_events(&self, round_id: RoundId) -> Vec<SeType> {
+        let like_bonus = self.settings().like_bonus;
+        let Some(round_likes) = self
+            .round_likes
+            .iter()
+            .find(|round_likes| round_likes.round_id == round_id)
+        else {
+            return Vec::new();
+        };
+        if like_bonus == 0 {
+            return Vec::new();
+        }
+        let current = self
+            .round
+            .as_ref()
+            .is_some_and(|round| round.round_id == round_id);
+        std::iter::once(round_likes.drawer_id)
+            .chain(round_likes.rival_id)
+            .flat_map(|drawer_id| {
+                if current {
+                    drawer_score_events(drawer_id, like_bonus).to_vec()
+                } else {
+                    vec![SeType::PlayerIncDrawScore(SePlayerIncDrawScore {
+                        drawer_id,
+                        inc_by: like_bonus,
+                    })]
+                }
+            })
+            .collect()
+    }
+    pub fn most_liked(&self) -> Option<MostLiked> {
+        most_liked(&self.round_likes)
+    }
+    // players can like any round in the current match
+    // once, except for the ones they drew themselves,
+    // a duel's like goes to both drawers
+    pub fn can_like(&self, player_id: PlayerId, round_id: RoundId) -> bool {
+        !self.is_game_over()
+            && self.players.contains_key(&player_id)
+            && self.round_likes.iter().any(|round_likes| {
+                round_likes.round_id == round_id
+                    && round_likes.drawer_id != player_id
+                    && round_likes.rival_id != Some(player_id)
+                    && !round_likes.liker_ids.contains(&player_id)
+            })
+    }
+}
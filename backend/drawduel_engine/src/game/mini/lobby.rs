@@ -0,0 +1,25 @@
+use super::*;
+
+impl Game {
+    // needs enough players for a round, disconnected
+    // players don't hold the rest of the lobby up
+    pub fn all_ready(&self) -> bool {
+        self.connected_players() >= self.min_players()
+            && self
+                .players
+                .values()
+                .filter(|player| player.connected)
+                .all(|player| player.ready)
+    }
+    // true when the server should start the
+    // match without waiting on the host
+    pub fn should_auto_start(&self) -> bool {
+        self.round.is_none() && self.all_ready()
+    }
+    // everyone has to ready up again for the next match
+    pub fn unready_all(&mut self) {
+        for player in self.players.values_mut() {
+            player.ready = false;
+        }
+    }
+}
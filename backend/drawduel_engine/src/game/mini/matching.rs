@@ -0,0 +1,100 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+use super::*;
+
+pub enum GuessMatch {
+    Correct,
+    // a typo or two away from the word
+    Close,
+    Incorrect,
+}
+
+// lowercases, strips accents, and drops everything that
+// isn't a letter or digit, so "Ice Cream", "icecream"
+// and "ice-cream " all normalize to "icecream"
+pub fn normalize_guess(guess: &str) -> String {
+    guess
+        .nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+// levenshtein distance, counted in chars not bytes
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(a_char != *b_char);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+// every run of consecutive words in the text, normalized and
+// joined, e.g. "its ice cream" has "its", "itsice", "icecream"
+// and so on, runs over max len chars are left out since they
+// can't match or be a few typos away from anything that short
+pub fn word_runs(text: &str, max_len: usize) -> Vec<String> {
+    let tokens: Vec<String> =
+        text.split_whitespace().map(normalize_guess).collect();
+    let mut runs = Vec::new();
+    for start in 0..tokens.len() {
+        let mut run = String::new();
+        for token in &tokens[start..] {
+            run.push_str(token);
+            if run.chars().count() > max_len {
+                break;
+            }
+            if !token.is_empty() {
+                runs.push(run.clone());
+            }
+        }
+    }
+    runs
+}
+
+// how many typos we forgive before a guess is no longer
+// close, short words get less slack since a single typo
+// can turn them into an entirely different word
+fn close_distance(word_len: usize) -> usize {
+    if word_len <= 5 {
+        1
+    } else {
+        2
+    }
+}
+
+impl Round {
+    pub fn judge_guess(&self, guess: &str) -> GuessMatch {
+        let Some(word) = self.word() else {
+            return GuessMatch::Incorrect;
+        };
+        let word = normalize_guess(word);
+        // same check that keeps the word out of chat, a guess
+        // saying it among other words, e.g. "its ice cream",
+        // knows it, judged incorrect it'd be shown to everyone
+        if self.mentions_word(guess) {
+            return GuessMatch::Correct;
+        }
+        // the near miss can be any run of the guess's words,
+        // e.g. "its ice creem", which as a whole is too far
+        // from the word to be close
+        let word_len = word.chars().count();
+        let close = close_distance(word_len);
+        let is_close = word_runs(guess, word_len + close)
+            .iter()
+            .any(|run| edit_distance(run, &word) <= close);
+        if is_close {
+            GuessMatch::Close
+        } else {
+            GuessMatch::Incorrect
+        }
+    }
+}
@@ -1,5 +1,17 @@
 use std::collections::HashMap;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::Duration;
+
+mod canvas;
+pub use canvas::*;
+
+mod chat;
+pub use chat::*;
+
+mod clock;
+pub use clock::*;
+
+mod duel;
+pub use duel::*;
 
 mod generated;
 pub use generated::client_event::CeType;
@@ -9,6 +21,42 @@ pub use generated::hint::HintType;
 pub use generated::server_event::SeType;
 pub use generated::*;
 
+mod host;
+
+mod likes;
+pub use likes::*;
+
+mod lobby;
+
+mod matching;
+pub use matching::*;
+
+mod names;
+pub use names::*;
+
+mod replay;
+pub use replay::*;
+
+mod scoring;
+pub use scoring::*;
+
+mod settings;
+pub use settings::*;
+
+mod standings;
+pub use standings::*;
+
+mod stroke_points;
+pub use stroke_points::*;
+
+mod svg;
+pub use svg::*;
+
+mod view;
+pub use view::*;
+
+mod vote;
+
 mod words;
 pub use words::*;
 
@@ -18,28 +66,8 @@ pub type Score = u32;
 pub type EpochMs = u64;
 pub type WordIdx = u32;
 
-// scoring
-pub const BASE_SCORE: u32 = 120;
-pub const EASY_MULTIPLER: u32 = 2;
-pub const HARD_MULTIPLER: u32 = 3;
-
-// timing
-pub const CHOOSE_WORD_DURATION: Duration = Duration::from_secs(10);
-pub const PRE_PLAY_DURATION: Duration = Duration::from_secs(5);
-pub const PLAY_EASY_DURATION: Duration = Duration::from_secs(45);
-pub const PLAY_HARD_DURATION: Duration = Duration::from_secs(60);
-pub const POST_PLAY_DURATION: Duration = Duration::from_secs(5);
-
-pub fn epoch_ms_from_now(duration: Duration) -> EpochMs {
-    let now = SystemTime::now();
-    let future_time = now + duration;
-    future_time
-        .duration_since(UNIX_EPOCH)
-        .expect("time went backwards!?")
-        // truncating u128 to u64 is safe because the sun
-        // will explode before u64 overflows
-        .as_millis() as EpochMs
-}
+// a round needs a drawer and at least one guesser
+pub const MIN_PLAYERS: usize = 2;
 
 pub struct TimedEvent {
     // event is only valid in this round
@@ -59,45 +87,43 @@ pub enum TimedEventType {
     // 10 secs after play phase start
     InactiveDrawer,
     PlayPhaseOver,
+    VotePhaseOver,
     PostPlayPhaseOver,
     GiveHint,
 }
 
 impl Round {
-    pub fn new(
-        round_id: RoundId,
-        drawer_id: PlayerId,
-        easy_word: WordIdx,
-        hard_word: WordIdx,
-    ) -> Self {
+    pub fn new(new_round: &SeRoundNew) -> Self {
         Round {
-            round_id,
-            phase: Phase::ChooseWord.into(),
-            drawer_id: drawer_id,
+            round_id: new_round.round_id,
+            phase: new_round.starting_phase,
+            drawer_id: new_round.drawer_id,
             draw_ops: Vec::with_capacity(128),
-            easy_word: easy_word,
-            hard_word: hard_word,
+            easy_word: new_round.easy_word,
+            hard_word: new_round.hard_word,
             word_choice: WordChoice::Easy.into(),
             draw_score: 0,
             guess_score: 0,
             guesses: Vec::new(),
             hints: Vec::new(),
-            phase_ends_at: 0,
+            phase_ends_at: new_round.phase_ends_at,
+            rival_id: new_round.rival_id,
+            rival_draw_ops: Vec::new(),
+            votes: Vec::new(),
         }
     }
-    pub fn next(
-        &mut self,
-        round_id: RoundId,
-        drawer_id: PlayerId,
-        easy_word: WordIdx,
-        hard_word: WordIdx,
-    ) {
-        self.round_id = round_id;
-        self.phase = Phase::ChooseWord.into();
-        self.drawer_id = drawer_id;
+    // reuses the round's allocations
+    pub fn next(&mut self, new_round: &SeRoundNew) {
+        self.round_id = new_round.round_id;
+        self.phase = new_round.starting_phase;
+        self.phase_ends_at = new_round.phase_ends_at;
+        self.drawer_id = new_round.drawer_id;
         self.draw_ops.clear();
-        self.easy_word = easy_word;
-        self.hard_word = hard_word;
+        self.rival_id = new_round.rival_id;
+        self.rival_draw_ops.clear();
+        self.votes.clear();
+        self.easy_word = new_round.easy_word;
+        self.hard_word = new_round.hard_word;
         self.word_choice = WordChoice::Easy.into();
         self.draw_score = 0;
         self.guess_score = 0;
@@ -106,16 +132,122 @@ impl Round {
     }
 }
 
+impl Round {
+    // the word the drawer is drawing, None if
+    // it's been hidden from this player
+    pub fn word(&self) -> Option<&'static str> {
+        if self.word_choice() == WordChoice::Hard {
+            HARD_WORDS.get(self.hard_word as usize).copied()
+        } else {
+            EASY_WORDS.get(self.easy_word as usize).copied()
+        }
+    }
+    pub fn reveal_words_event(&self) -> ServerEvent {
+        ServerEvent {
+            se_type: Some(SeType::RoundRevealWords(SeRoundRevealWords {
+                easy_word: self.easy_word,
+                hard_word: self.hard_word,
+            })),
+        }
+    }
+    // reveals the word's length first, then one letter at a
+    // time, returns None once half the letters are revealed
+    pub fn next_hint(&self) -> Option<Hint> {
+        let word = self.word()?;
+        let after_draw_ops = self.draw_ops.len() as u32;
+        if self.hints.is_empty() {
+            let word_lengths = word
+                .split_whitespace()
+                .map(|part| part.chars().count() as u32)
+                .collect();
+            return Some(Hint {
+                hint_type: Some(HintType::RevealLength(RevealLength {
+                    word_lengths,
+                })),
+                after_draw_ops,
+            });
+        }
+        let revealed: Vec<u32> = self
+            .hints
+            .iter()
+            .filter_map(|hint| match &hint.hint_type {
+                Some(HintType::RevealLetter(letter)) => Some(letter.index),
+                _ => None,
+            })
+            .collect();
+        // skip spaces in multi-word answers, e.g. "ice cream"
+        let hidden: Vec<u32> = word
+            .chars()
+            .enumerate()
+            .filter(|(idx, c)| {
+                !c.is_whitespace() && !revealed.contains(&(*idx as u32))
+            })
+            .map(|(idx, _)| idx as u32)
+            .collect();
+        let letters = revealed.len() + hidden.len();
+        if revealed.len() >= letters / 2 {
+            return None;
+        }
+        // spread reveals across the word instead of going left
+        // to right, the 1st letter depends on the round and every
+        // one after is as far as it can be from those revealed,
+        // deterministic so every engine picks the same
+        let index = if revealed.is_empty() {
+            hidden[self.round_id as usize % hidden.len()]
+        } else {
+            let spread = |idx: u32| {
+                revealed.iter().map(|r| r.abs_diff(idx)).min().unwrap_or(0)
+            };
+            // max_by_key keeps the last of ties, so going in
+            // reverse picks the leftmost
+            hidden
+                .iter()
+                .rev()
+                .copied()
+                .max_by_key(|&idx| spread(idx))
+                .unwrap_or(hidden[0])
+        };
+        let letter = word
+            .chars()
+            .nth(index as usize)
+            .map(String::from)
+            .unwrap_or_default();
+        Some(Hint {
+            hint_type: Some(HintType::RevealLetter(RevealLetter {
+                index,
+                letter,
+            })),
+            after_draw_ops,
+        })
+    }
+}
+
 impl Game {
     pub fn new() -> Self {
         Self {
             players: HashMap::new(),
             round: None,
+            settings: Some(DEFAULT_SETTINGS),
+            round_likes: Vec::new(),
+            host_id: None,
+            spectators: 0,
+            chat: Vec::new(),
+        }
+    }
+    pub fn with_settings(settings: GameSettings) -> Self {
+        Self {
+            settings: Some(settings.sanitized()),
+            ..Self::new()
         }
     }
+    // keeps the room's settings
     pub fn reset(&mut self) {
         self.players.clear();
         self.round = None;
+        self.round_likes.clear();
+        self.host_id = None;
+        self.spectators = 0;
+        self.chat.clear();
     }
     // true if no players, or all players disconnected
     pub fn is_empty(&self) -> bool {
@@ -133,38 +265,94 @@ impl Game {
             },
         )
     }
-    // true if all connected players have scored this round
-    pub fn all_connected_players_scored(&self) -> bool {
-        self.players
+    // true if there's no round in progress or the current
+    // round has run past the end of its post play phase
+    pub fn round_over(&self, now: EpochMs) -> bool {
+        match &self.round {
+            None => true,
+            Some(round) => {
+                round.phase() == Phase::PostPlay && round.phase_ends_at <= now
+            }
+        }
+    }
+    // picks the connected player with the next highest id
+    // after the last drawer, wrapping around to the lowest,
+    // so everyone gets a turn in join order
+    pub fn next_drawer_id(&self) -> Option<PlayerId> {
+        // the rival drew after the drawer in duel rounds
+        let prev_drawer_id = self
+            .round
+            .as_ref()
+            .map(|r| r.rival_id.unwrap_or(r.drawer_id));
+        self.next_connected_after(prev_drawer_id)
+    }
+    fn next_connected_after(&self, prev: Option<PlayerId>) -> Option<PlayerId> {
+        let mut connected: Vec<PlayerId> = self
+            .players
             .iter()
-            .all(|(_, p)| !p.connected || p.round_score > 0)
+            .filter(|(_, p)| p.connected)
+            .map(|(&id, _)| id)
+            .collect();
+        connected.sort_unstable();
+        match prev {
+            Some(prev) => connected
+                .iter()
+                .find(|&&id| id > prev)
+                .or(connected.first())
+                .copied(),
+            None => connected.first().copied(),
+        }
+    }
+    // true if all connected guessers have guessed the word this
+    // round, checks guesses instead of round scores since some
+    // scoring policies can score a late correct guess as 0
+    pub fn all_connected_players_scored(&self) -> bool {
+        let Some(round) = &self.round else {
+            return false;
+        };
+        self.players.iter().all(|(&player_id, p)| {
+            !p.connected
+                || round.is_drawer(player_id)
+                || round.guessed_correctly(player_id)
+        })
     }
     pub fn advance_all(
         &mut self,
         events: ServerEvents,
         send_buf: &mut Vec<ServerEvent>,
+        timer_buf: &mut Vec<TimedEvent>,
+        clock: &impl Clock,
     ) {
         for event in events.events {
-            self.advance(event, send_buf);
+            self.advance(event, send_buf, timer_buf, clock);
         }
     }
     pub fn advance(
         &mut self,
         event: ServerEvent,
         send_buf: &mut Vec<ServerEvent>,
+        timer_buf: &mut Vec<TimedEvent>,
+        clock: &impl Clock,
     ) {
         match event.se_type.as_ref().unwrap() {
             SeType::PlayerJoin(player_join) => {
+                let player_id = player_join.player_id;
+                // every engine settles on the same name, the
+                // event passed on carries the one they got
+                let name = self
+                    .unique_name(&normalize_name(&player_join.name), player_id);
                 let overwrote_existing_player = self
                     .players
                     .insert(
-                        player_join.player_id,
+                        player_id,
                         Player {
-                            name: player_join.name.clone(),
+                            name: name.clone(),
                             round_score: 0,
                             draw_score: 0,
                             guess_score: 0,
                             connected: true,
+                            times_drawn: 0,
+                            ready: false,
                         },
                     )
                     .is_some();
@@ -173,19 +361,39 @@ impl Game {
                         "overwrote existing player, this should never happen!"
                     );
                 }
-                send_buf.push(event);
+                self.hand_off_host();
+                send_buf.push(ServerEvent {
+                    se_type: Some(SeType::PlayerJoin(SePlayerJoin {
+                        player_id,
+                        name,
+                    })),
+                });
             }
             SeType::PlayerLeave(player_leave) => {
                 let player_id = player_leave.player_id;
                 if self.players.remove(&player_id).is_some() {
+                    self.hand_off_host();
                     send_buf.push(event);
                 }
             }
+            // the server already turned away names that are
+            // blocked, but any engine can tidy up the rest
             SeType::PlayerRename(player_rename) => {
                 let player_id = player_rename.player_id;
+                let name = normalize_name(&player_rename.name);
+                let len = name.chars().count();
+                if !(MIN_NAME_LEN..=MAX_NAME_LEN).contains(&len) {
+                    return;
+                }
+                let name = self.unique_name(&name, player_id);
                 if let Some(player) = self.players.get_mut(&player_id) {
-                    player.name = player_rename.name.clone();
-                    send_buf.push(event);
+                    player.name = name.clone();
+                    send_buf.push(ServerEvent {
+                        se_type: Some(SeType::PlayerRename(SePlayerRename {
+                            player_id,
+                            name,
+                        })),
+                    });
                 }
             }
             SeType::PlayerIncRoundScore(inc_score) => {
@@ -214,6 +422,7 @@ impl Game {
                 if let Some(player) = self.players.get_mut(&player_id) {
                     if !player.connected {
                         player.connected = true;
+                        self.hand_off_host();
                         send_buf.push(event);
                     }
                 }
@@ -223,6 +432,7 @@ impl Game {
                 if let Some(player) = self.players.get_mut(&player_id) {
                     if player.connected {
                         player.connected = false;
+                        self.hand_off_host();
                         send_buf.push(event);
                     }
                 }
@@ -230,18 +440,43 @@ impl Game {
             SeType::PlayerDrawOp(player_draw_op) => {
                 if let Some(round) = &mut self.round {
                     if round.phase() == Phase::Play {
-                        if round.drawer_id == player_draw_op.drawer_id {
-                            if let Some(draw_op) =
-                                player_draw_op.draw_op.clone()
-                            {
-                                round.draw_ops.push(draw_op);
-                                send_buf.push(event);
+                        // duel drawers each have their own stream
+                        if let Some(draw_ops) =
+                            round.draw_ops_of_mut(player_draw_op.drawer_id)
+                        {
+                            let validated = match &player_draw_op.draw_op {
+                                None => Err(DrawOpRejectReason::EmptyOp),
+                                Some(_)
+                                    if draw_ops.len()
+                                        >= MAX_DRAW_OPS_PER_ROUND =>
+                                {
+                                    Err(DrawOpRejectReason::TooManyOps)
+                                }
+                                Some(draw_op) => {
+                                    draw_op.validate().map(|()| draw_op.clone())
+                                }
+                            };
+                            match validated {
+                                Ok(draw_op) => {
+                                    draw_ops.push(draw_op);
+                                    send_buf.push(event);
+                                }
+                                // only the drawer hears about it
+                                Err(reason) => send_buf.push(ServerEvent {
+                                    se_type: Some(SeType::PlayerDrawOpReject(
+                                        SePlayerDrawOpReject {
+                                            drawer_id: player_draw_op.drawer_id,
+                                            reason: reason.into(),
+                                        },
+                                    )),
+                                }),
                             }
                         }
                     }
                 }
             }
             SeType::PlayerChooseWord(choose_word) => {
+                let pre_play_duration = self.settings().pre_play_duration();
                 if let Some(round) = &mut self.round {
                     if round.phase() == Phase::ChooseWord {
                         if round.drawer_id == choose_word.drawer_id {
@@ -249,13 +484,12 @@ impl Game {
                                 round.word_choice = choose_word.choice;
                                 send_buf.push(event);
                             }
-                            let phase_duration =
-                                if round.word_choice() == WordChoice::Easy {
-                                    PLAY_EASY_DURATION
-                                } else {
-                                    PLAY_HARD_DURATION
-                                };
-                            let ends_at_ms = epoch_ms_from_now(phase_duration);
+                            // drawer picked, no need to wait
+                            // for the choose word phase to time out
+                            let ends_at_ms =
+                                clock.ms_from_now(pre_play_duration);
+                            round.phase = Phase::PrePlay.into();
+                            round.phase_ends_at = ends_at_ms;
                             send_buf.push(ServerEvent {
                                 se_type: Some(SeType::RoundChangePhase(
                                     SeRoundChangePhase {
@@ -264,6 +498,13 @@ impl Game {
                                     },
                                 )),
                             });
+                            timer_buf.push(TimedEvent {
+                                target_round_id: round.round_id,
+                                target_phase: Phase::PrePlay,
+                                timed_event_type:
+                                    TimedEventType::PrePlayPhaseOver,
+                                times_out_at: ends_at_ms,
+                            });
                         }
                     }
                 }
@@ -277,179 +518,281 @@ impl Game {
                     // players map because clients cannot
                     // effect this field, it's only set on
                     // the server
-                    if round.drawer_id != guess_word.guesser_id {
+                    if !round.is_drawer(guess_word.guesser_id) {
+                        let guesser_id = guess_word.guesser_id;
+                        // players who solved can keep typing, but
+                        // can't score again or be told they're close
+                        let after_solving = round.guessed_correctly(guesser_id);
                         // check if it's correct
-                        let (is_correct, multipler, phase_duration) =
-                            if round.word_choice == WordChoice::Hard.into() {
-                                (
-                                    HARD_WORDS[round.hard_word as usize]
-                                        == guess_word.guess,
-                                    HARD_MULTIPLER,
-                                    PLAY_HARD_DURATION,
-                                )
-                            } else {
-                                (
-                                    EASY_WORDS[round.easy_word as usize]
-                                        == guess_word.guess,
-                                    EASY_MULTIPLER,
-                                    PLAY_EASY_DURATION,
-                                )
-                            };
+                        let guess_match = if after_solving {
+                            GuessMatch::Incorrect
+                        } else {
+                            round.judge_guess(&guess_word.guess)
+                        };
+                        let is_correct =
+                            matches!(guess_match, GuessMatch::Correct);
 
                         let guess_type = if is_correct {
                             GuessType::CorrectGuess(CorrectGuess {})
                         } else {
                             GuessType::IncorrectGuess(IncorrectGuess {
                                 guess: guess_word.guess.clone(),
+                                close: matches!(guess_match, GuessMatch::Close),
+                                after_solving,
                             })
                         };
                         // add guess to guesses
-                        let guesser_id = guess_word.guesser_id;
-                        round.guesses.push(Guess {
+                        // only a duel has more than one canvas to watch
+                        let watching_id =
+                            guess_word.watching_id.filter(|&watching_id| {
+                                round.is_duel() && round.is_drawer(watching_id)
+                            });
+                        let guess = Guess {
                             guesser_id: guess_word.guesser_id,
                             guess_type: Some(guess_type),
                             after_draw_ops: guess_word.after_draw_ops,
-                        });
-                        send_buf.push(event);
-                        // let guess = &round.guesses.last().unwrap().guess;
-
-                        // if not then return
-                        if !is_correct {
-                            return;
-                        }
-
-                        // if correct then calculate score
-                        let first_correct_bonus =
-                            if round.guess_score == 0 { 10 } else { 0 };
-
-                        let hints_gotten = round.hints.len() as u32;
-
-                        let now = SystemTime::now();
-                        let phase_started_at = UNIX_EPOCH
-                            + Duration::from_millis(round.phase_ends_at)
-                            - phase_duration;
-                        let secs_elapsed: u32 = if let Ok(duration) =
-                            now.duration_since(phase_started_at)
-                        {
-                            // truncates u64
-                            duration.as_secs() as u32
-                        } else {
-                            0
+                            watching_id,
                         };
+                        round.guesses.push(guess.clone());
+                        // send the judged guess rather than the raw one,
+                        // correct guesses would give away the word, and
+                        // it's only routed to players it can't spoil
+                        send_buf.push(ServerEvent {
+                            se_type: Some(SeType::RoundGuess(SeRoundGuess {
+                                guess: Some(guess),
+                            })),
+                        });
 
-                        let inc_score = ((first_correct_bonus + BASE_SCORE)
-                            - (secs_elapsed + hints_gotten))
-                            * multipler;
-
-                        // score correct guess
-                        if let Some(player) = self.players.get_mut(&guesser_id)
-                        {
-                            player.guess_score += inc_score;
+                        if matches!(guess_match, GuessMatch::Close) {
                             send_buf.push(ServerEvent {
-                                se_type: Some(SeType::PlayerIncRoundScore(
-                                    SePlayerIncRoundScore {
-                                        player_id: guesser_id,
-                                        inc_by: inc_score,
-                                    },
+                                se_type: Some(SeType::PlayerGuessClose(
+                                    SePlayerGuessClose { guesser_id },
                                 )),
                             });
                         }
 
-                        round.guess_score += inc_score;
-                        send_buf.push(ServerEvent {
-                            se_type: Some(SeType::RoundIncGuessScore(
-                                SeRoundIncGuessScore { inc_by: inc_score },
-                            )),
-                        });
+                        // if not then return
+                        if !is_correct {
+                            return;
+                        }
 
-                        // score drawing for 1st correct guess
-                        if first_correct_bonus > 0 {
-                            if let Some(player) =
-                                self.players.get_mut(&round.drawer_id)
-                            {
-                                player.draw_score += inc_score;
-                                send_buf.push(ServerEvent {
-                                    se_type: Some(SeType::PlayerIncRoundScore(
-                                        SePlayerIncRoundScore {
-                                            player_id: round.drawer_id,
-                                            inc_by: inc_score,
-                                        },
-                                    )),
-                                });
-                            }
+                        // guesser knows the word now, let their
+                        // client know which one it was
+                        send_buf.push(round.reveal_words_event());
 
-                            round.draw_score += inc_score;
-                            send_buf.push(ServerEvent {
-                                se_type: Some(SeType::RoundIncDrawScore(
-                                    SeRoundIncDrawScore { inc_by: inc_score },
-                                )),
-                            });
-                        }
+                        self.score_correct_guess(
+                            guesser_id, send_buf, timer_buf, clock,
+                        );
 
                         if self.all_connected_players_scored() {
-                            send_buf.push(ServerEvent {
-                                se_type: Some(SeType::RoundChangePhase(
-                                    SeRoundChangePhase {
-                                        phase: Phase::PostPlay.into(),
-                                        phase_ends_at: epoch_ms_from_now(
-                                            POST_PLAY_DURATION,
-                                        ),
-                                    },
-                                )),
-                            });
+                            self.end_play(clock.now(), send_buf, timer_buf);
                         }
-
-                        // TODO: as currently implemented, this
-                        // assumes every guess is incorrect, however
-                        // if we get a correct guess we must:
-                        // - inc guesser's score & guess_score
-                        // - inc round's guess_score
-                        // - if 1st correct guess also:
-                        //   - inc drawer's score & draw_score
-                        //   - inc round's draw_score
-                        // - check if all connected guessers have correct guesses:
-                        //   - if so, switch to post-play phase
-                        // how to calc score:
-                        // base = 120
-                        // guesser: (firstCorrectBonus = 10 for 1st correct guesser)
-                        // ((firstCorrectBonus + base) - (floor(roundTimeElapsedSecs) + len(hints) * 10)) * difficulty multipler
-                        // drawer (only for first guess):
-                        // ((10 + base) - (floor(roundTimeElapsedSecs) + len(hints) * 10)) * difficulty multipler
                     }
                 }
             }
             // only server can generate this event
             SeType::NewRound(new_round) => {
-                // not efficient impl but this
-                // only runs during testing,
-                // shouldn't ever run on prod
                 if let Some(round) = &mut self.round {
-                    if new_round.round_id != round.round_id {
-                        round.next(
-                            new_round.round_id,
-                            new_round.drawer_id,
-                            new_round.easy_word,
-                            new_round.hard_word,
-                        );
-                        send_buf.push(event);
+                    if new_round.round_id == round.round_id {
+                        return;
+                    }
+                    // reuse the existing round's allocations
+                    round.next(new_round);
+                } else {
+                    self.round = Some(Round::new(new_round));
+                }
+                self.round_likes.push(RoundLikes {
+                    round_id: new_round.round_id,
+                    drawer_id: new_round.drawer_id,
+                    liker_ids: Vec::new(),
+                    rival_id: new_round.rival_id,
+                });
+                for player in self.players.values_mut() {
+                    player.round_score = 0;
+                }
+                let drawer_ids = std::iter::once(new_round.drawer_id)
+                    .chain(new_round.rival_id);
+                for drawer_id in drawer_ids {
+                    if let Some(drawer) = self.players.get_mut(&drawer_id) {
+                        drawer.times_drawn += 1;
                     }
                 }
+                if new_round.starting_phase() == Phase::ChooseWord {
+                    timer_buf.push(TimedEvent {
+                        target_round_id: new_round.round_id,
+                        target_phase: Phase::ChooseWord,
+                        timed_event_type: TimedEventType::ChooseWordPhaseOver,
+                        times_out_at: new_round.phase_ends_at,
+                    });
+                }
+                send_buf.push(event);
             }
             // only server can generate this event
             SeType::SetGame(set_game) => {
                 // not efficient impl but this
                 // only runs during testing,
                 // shouldn't ever run on prod
-                *self = set_game.game.as_ref().unwrap().clone();
+                *self = set_game.game.as_deref().unwrap().clone();
+                send_buf.push(event);
+            }
+            SeType::PlayerChangeSettings(change_settings) => {
+                if !self.is_host(change_settings.player_id) || !self.in_lobby()
+                {
+                    return;
+                }
+                let Some(settings) = change_settings.settings else {
+                    return;
+                };
+                let settings = settings.sanitized();
+                self.settings = Some(settings);
+                send_buf.push(ServerEvent {
+                    se_type: Some(SeType::PlayerChangeSettings(
+                        SePlayerChangeSettings {
+                            player_id: change_settings.player_id,
+                            settings: Some(settings),
+                        },
+                    )),
+                });
+            }
+            // only server can create this event
+            SeType::PlayerDrawOpReject(_draw_op_reject) => {
+                // no-op, but pass thru
                 send_buf.push(event);
             }
-            SeType::PlayerLikeRound(_like_round) => {
+            // only server can create this event
+            SeType::PlayerGuessClose(_guess_close) => {
                 // no-op, but pass thru
                 send_buf.push(event);
             }
+            SeType::PlayerLikeRound(like_round) => {
+                let (player_id, round_id) =
+                    (like_round.player_id, like_round.round_id);
+                if !self.can_like(player_id, round_id) {
+                    return;
+                }
+                // like guesses, clients get the recorded like
+                // and the bonus separately, as score events
+                let round_like = ServerEvent {
+                    se_type: Some(SeType::RoundLike(SeRoundLike {
+                        player_id,
+                        round_id,
+                    })),
+                };
+                self.advance(round_like, send_buf, timer_buf, clock);
+                let score_events = self.like_score_events(round_id);
+                self.apply_scores(
+                    score_events,
+                    clock.now(),
+                    send_buf,
+                    timer_buf,
+                );
+            }
+            // only server can create this event
+            SeType::RoundLike(round_like) => {
+                let (player_id, round_id) =
+                    (round_like.player_id, round_like.round_id);
+                if !self.can_like(player_id, round_id) {
+                    return;
+                }
+                let Some(round_likes) = self
+                    .round_likes
+                    .iter_mut()
+                    .find(|round_likes| round_likes.round_id == round_id)
+                else {
+                    return;
+                };
+                round_likes.liker_ids.push(player_id);
+                send_buf.push(event);
+            }
             SeType::Error(_error) => {
                 // no-op
             }
+            SeType::PlayerVote(player_vote) => {
+                let Some(round) = &mut self.round else {
+                    return;
+                };
+                if !round.can_vote(player_vote.voter_id, player_vote.drawer_id)
+                {
+                    return;
+                }
+                let vote = Vote {
+                    voter_id: player_vote.voter_id,
+                    drawer_id: player_vote.drawer_id,
+                };
+                round.votes.push(vote);
+                // like guesses, clients get the recorded vote
+                // and the server alone decides when it closes
+                send_buf.push(ServerEvent {
+                    se_type: Some(SeType::RoundVote(SeRoundVote {
+                        vote: Some(vote),
+                    })),
+                });
+                if self.all_voted() {
+                    self.close_vote(clock.now(), send_buf, timer_buf);
+                }
+            }
+            SeType::PlayerChat(player_chat) => {
+                let player_id = player_chat.player_id;
+                if !self.players.contains_key(&player_id) {
+                    return;
+                }
+                let Some(text) = sanitize_chat(&player_chat.text) else {
+                    return;
+                };
+                let reject_reason = self.round.as_ref().and_then(|round| {
+                    round.chat_reject_reason(player_id, &text)
+                });
+                if let Some(reason) = reject_reason {
+                    // only the sender hears about it
+                    send_buf.push(ServerEvent {
+                        se_type: Some(SeType::PlayerChatReject(
+                            SePlayerChatReject {
+                                player_id,
+                                reason: reason.into(),
+                            },
+                        )),
+                    });
+                    return;
+                }
+                let message = ChatMessage { player_id, text };
+                self.push_chat(message.clone());
+                send_buf.push(ServerEvent {
+                    se_type: Some(SeType::RoomChat(SeRoomChat {
+                        message: Some(message),
+                    })),
+                });
+            }
+            // only server can create this event
+            SeType::RoomChat(room_chat) => {
+                if let Some(message) = &room_chat.message {
+                    self.push_chat(message.clone());
+                    send_buf.push(event);
+                }
+            }
+            // only server can create this event
+            SeType::PlayerChatReject(_chat_reject) => {
+                // no-op, but pass thru
+                send_buf.push(event);
+            }
+            // only server can create this event
+            SeType::RoundVote(round_vote) => {
+                if let Some(round) = &mut self.round {
+                    if let Some(vote) = &round_vote.vote {
+                        round.votes.push(*vote);
+                        send_buf.push(event);
+                    }
+                }
+            }
+            // only server can create this event
+            SeType::VoteResult(_vote_result) => {
+                // no-op, but pass thru
+                send_buf.push(event);
+            }
+            // only server can create this event
+            SeType::Spectators(spectators) => {
+                self.spectators = spectators.spectators;
+                send_buf.push(event);
+            }
             // only server can create this event
             SeType::RoundIncDrawScore(inc_score) => {
                 if let Some(round) = &mut self.round {
@@ -468,34 +811,140 @@ impl Game {
             SeType::RoundChangePhase(round_change_phase) => {
                 if let Some(round) = &mut self.round {
                     round.phase = round_change_phase.phase;
+                    // the server's deadline, overrides whatever
+                    // a client worked out from its own clock
+                    round.phase_ends_at = round_change_phase.phase_ends_at;
+                    send_buf.push(event);
+                }
+            }
+            // only server can create this event
+            SeType::RoundHint(round_hint) => {
+                if let Some(round) = &mut self.round {
+                    if let Some(hint) = &round_hint.hint {
+                        round.hints.push(hint.clone());
+                        send_buf.push(event);
+                    }
+                }
+            }
+            // only server can create this event
+            SeType::RoundGuess(round_guess) => {
+                if let Some(round) = &mut self.round {
+                    if let Some(guess) = &round_guess.guess {
+                        round.guesses.push(guess.clone());
+                        send_buf.push(event);
+                    }
+                }
+            }
+            // only server can create this event
+            SeType::RoundRevealWords(reveal_words) => {
+                if let Some(round) = &mut self.round {
+                    round.easy_word = reveal_words.easy_word;
+                    round.hard_word = reveal_words.hard_word;
+                    send_buf.push(event);
+                }
+            }
+            // only server can create this event
+            SeType::GameOver(_game_over) => {
+                if let Some(round) = &mut self.round {
+                    round.phase = Phase::GameOver.into();
+                    self.unready_all();
                     send_buf.push(event);
                 }
             }
+            SeType::PlayerPlayAgain(play_again) => {
+                if self.is_host(play_again.player_id) && self.is_game_over() {
+                    self.play_again();
+                    send_buf.push(event);
+                }
+            }
+            // the server starts the first round right after this
+            SeType::PlayerStartMatch(start_match) => {
+                if !self.can_start_match(start_match.player_id) {
+                    return;
+                }
+                if self.is_game_over() {
+                    self.play_again();
+                }
+                send_buf.push(event);
+            }
+            // the server starts the first round once
+            // this leaves every connected player ready
+            SeType::PlayerReady(player_ready) => {
+                if !self.in_lobby() {
+                    return;
+                }
+                let Some(player) =
+                    self.players.get_mut(&player_ready.player_id)
+                else {
+                    return;
+                };
+                if player.ready == player_ready.ready {
+                    return;
+                }
+                player.ready = player_ready.ready;
+                if self.is_game_over() && self.all_ready() {
+                    self.play_again();
+                }
+                send_buf.push(event);
+            }
+            SeType::PlayerEndGame(end_game) => {
+                if self.is_host(end_game.player_id)
+                    && self.round.is_some()
+                    && !self.is_game_over()
+                {
+                    self.end_match(send_buf);
+                }
+            }
+            SeType::PlayerKick(kick) => {
+                if !self.is_host(kick.host_id) || kick.host_id == kick.player_id
+                {
+                    return;
+                }
+                if self.players.remove(&kick.player_id).is_some() {
+                    send_buf.push(event);
+                }
+            }
+            // only server can create this event
+            SeType::RoundSkip(round_skip) => {
+                if let Some(round) = &mut self.round {
+                    round.phase = Phase::PostPlay.into();
+                    round.phase_ends_at = round_skip.phase_ends_at;
+                    send_buf.push(event);
+                }
+            }
+        }
+    }
+    // true if the timed event still targets the
+    // current round and phase, stale events are ignored
+    pub fn is_timely(&self, event: &TimedEvent) -> bool {
+        match &self.round {
+            Some(round) => {
+                round.round_id == event.target_round_id
+                    && round.phase() == event.target_phase
+            }
+            None => false,
         }
     }
+    // doesn't need a clock, every new deadline is measured
+    // from when the timer was due rather than when it fired
     pub fn timed_advance(
         &mut self,
         event: TimedEvent,
         send_buf: &mut Vec<ServerEvent>,
         timer_buf: &mut Vec<TimedEvent>,
     ) {
-        if self.round.is_none() {
+        if !self.is_timely(&event) {
             return;
         }
+        let settings = *self.settings();
+        let match_over = self.match_over();
+        let mut end_match = false;
+        let mut end_play = false;
+        let mut close_vote = false;
         let round = self.round.as_mut().unwrap();
-        if round.round_id != event.target_round_id {
-            return;
-        }
-        if round.phase != event.target_phase.into() {
-            return;
-        }
         match event.timed_event_type {
             TimedEventType::PrePlayPhaseOver => {
-                let play_duration = if round.word_choice() == WordChoice::Hard {
-                    PLAY_HARD_DURATION
-                } else {
-                    PLAY_EASY_DURATION
-                };
+                let play_duration = settings.play_duration(round.word_choice());
                 let play_phase_over_at =
                     event.times_out_at + play_duration.as_millis() as u64;
                 round.phase = Phase::Play.into();
@@ -514,10 +963,27 @@ impl Game {
                     timed_event_type: TimedEventType::PlayPhaseOver,
                     times_out_at: play_phase_over_at,
                 });
+                if let Some(hint_interval) = settings.hint_interval() {
+                    timer_buf.push(TimedEvent {
+                        target_round_id: round.round_id,
+                        target_phase: Phase::Play,
+                        timed_event_type: TimedEventType::GiveHint,
+                        times_out_at: event.times_out_at
+                            + hint_interval.as_millis() as u64,
+                    });
+                }
+                timer_buf.push(TimedEvent {
+                    target_round_id: round.round_id,
+                    target_phase: Phase::Play,
+                    timed_event_type: TimedEventType::InactiveDrawer,
+                    times_out_at: event.times_out_at
+                        + settings.inactive_drawer_duration().as_millis()
+                            as u64,
+                });
             }
             TimedEventType::ChooseWordPhaseOver => {
-                let pre_play_phase_over_at =
-                    event.times_out_at + PRE_PLAY_DURATION.as_millis() as u64;
+                let pre_play_phase_over_at = event.times_out_at
+                    + settings.pre_play_duration().as_millis() as u64;
                 round.phase = Phase::PrePlay.into();
                 round.phase_ends_at = pre_play_phase_over_at;
                 send_buf.push(ServerEvent {
@@ -535,20 +1001,24 @@ impl Game {
                     times_out_at: pre_play_phase_over_at,
                 });
             }
-            TimedEventType::InactiveDrawer => todo!(),
-            TimedEventType::PlayPhaseOver => {
-                let post_play_phase_over_at =
-                    event.times_out_at + POST_PLAY_DURATION.as_millis() as u64;
+            TimedEventType::InactiveDrawer => {
+                if !round.is_blank() {
+                    return;
+                }
+                // nobody can guess a blank canvas so skip
+                // ahead to post play, the next round will
+                // have a different drawer
+                let post_play_phase_over_at = event.times_out_at
+                    + settings.post_play_duration().as_millis() as u64;
                 round.phase = Phase::PostPlay.into();
                 round.phase_ends_at = post_play_phase_over_at;
                 send_buf.push(ServerEvent {
-                    se_type: Some(SeType::RoundChangePhase(
-                        SeRoundChangePhase {
-                            phase: Phase::PostPlay.into(),
-                            phase_ends_at: post_play_phase_over_at,
-                        },
-                    )),
+                    se_type: Some(SeType::RoundSkip(SeRoundSkip {
+                        reason: SkipReason::InactiveDrawer.into(),
+                        phase_ends_at: post_play_phase_over_at,
+                    })),
                 });
+                send_buf.push(round.reveal_words_event());
                 timer_buf.push(TimedEvent {
                     target_round_id: round.round_id,
                     target_phase: Phase::PostPlay,
@@ -556,27 +1026,51 @@ impl Game {
                     times_out_at: post_play_phase_over_at,
                 });
             }
+            TimedEventType::PlayPhaseOver => {
+                end_play = true;
+            }
+            TimedEventType::VotePhaseOver => {
+                close_vote = true;
+            }
             TimedEventType::PostPlayPhaseOver => {
-                let post_play_phase_over_at =
-                    event.times_out_at + POST_PLAY_DURATION.as_millis() as u64;
-                round.phase = Phase::PostPlay.into();
-                round.phase_ends_at = post_play_phase_over_at;
+                // the round is over and the server decides who
+                // draws next and with which words, unless that
+                // was the match's last round
+                end_match = match_over;
+            }
+            TimedEventType::GiveHint => {
+                let Some(hint) = round.next_hint() else {
+                    return;
+                };
+                round.hints.push(hint.clone());
                 send_buf.push(ServerEvent {
-                    se_type: Some(SeType::RoundChangePhase(
-                        SeRoundChangePhase {
-                            phase: Phase::PostPlay.into(),
-                            phase_ends_at: post_play_phase_over_at,
-                        },
-                    )),
-                });
-                timer_buf.push(TimedEvent {
-                    target_round_id: round.round_id,
-                    target_phase: Phase::PostPlay,
-                    timed_event_type: TimedEventType::PostPlayPhaseOver,
-                    times_out_at: post_play_phase_over_at,
+                    se_type: Some(SeType::RoundHint(SeRoundHint {
+                        hint: Some(hint),
+                    })),
                 });
+                let Some(hint_interval) = settings.hint_interval() else {
+                    return;
+                };
+                let next_hint_at =
+                    event.times_out_at + hint_interval.as_millis() as u64;
+                if next_hint_at < round.phase_ends_at {
+                    timer_buf.push(TimedEvent {
+                        target_round_id: round.round_id,
+                        target_phase: Phase::Play,
+                        timed_event_type: TimedEventType::GiveHint,
+                        times_out_at: next_hint_at,
+                    });
+                }
             }
-            TimedEventType::GiveHint => todo!(),
+        }
+        if end_play {
+            self.end_play(event.times_out_at, send_buf, timer_buf);
+        }
+        if close_vote {
+            self.close_vote(event.times_out_at, send_buf, timer_buf);
+        }
+        if end_match {
+            self.end_match(send_buf);
         }
     }
 }
@@ -607,6 +1101,54 @@ impl ServerEvent {
                     guesser_id: player_id,
                     guess: guess_word.guess,
                     after_draw_ops: guess_word.after_draw_ops,
+                    watching_id: guess_word.watching_id,
+                })),
+            },
+            CeType::ChangeSettings(change_settings) => ServerEvent {
+                se_type: Some(SeType::PlayerChangeSettings(
+                    SePlayerChangeSettings {
+                        player_id,
+                        settings: change_settings.settings,
+                    },
+                )),
+            },
+            CeType::StartMatch(_start_match) => ServerEvent {
+                se_type: Some(SeType::PlayerStartMatch(SePlayerStartMatch {
+                    player_id,
+                })),
+            },
+            CeType::EndGame(_end_game) => ServerEvent {
+                se_type: Some(SeType::PlayerEndGame(SePlayerEndGame {
+                    player_id,
+                })),
+            },
+            CeType::KickPlayer(kick_player) => ServerEvent {
+                se_type: Some(SeType::PlayerKick(SePlayerKick {
+                    host_id: player_id,
+                    player_id: kick_player.player_id,
+                })),
+            },
+            CeType::Ready(ready) => ServerEvent {
+                se_type: Some(SeType::PlayerReady(SePlayerReady {
+                    player_id,
+                    ready: ready.ready,
+                })),
+            },
+            CeType::Chat(chat) => ServerEvent {
+                se_type: Some(SeType::PlayerChat(SePlayerChat {
+                    player_id,
+                    text: chat.text,
+                })),
+            },
+            CeType::Vote(vote) => ServerEvent {
+                se_type: Some(SeType::PlayerVote(SePlayerVote {
+                    voter_id: player_id,
+                    drawer_id: vote.drawer_id,
+                })),
+            },
+            CeType::PlayAgain(_play_again) => ServerEvent {
+                se_type: Some(SeType::PlayerPlayAgain(SePlayerPlayAgain {
+                    player_id,
                 })),
             },
             CeType::LikeRound(like_round) => ServerEvent {
@@ -0,0 +1,122 @@
+use unicode_normalization::UnicodeNormalization;
+
+use super::*;
+
+// counted in chars after normalizing
+pub const MIN_NAME_LEN: usize = 1;
+pub const MAX_NAME_LEN: usize = 20;
+
+// zero width and bidi control chars aren't caught by
+// char::is_control but can make two names look alike
+fn is_invisible(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{FEFF}'
+    )
+}
+
+// composes lookalike forms, e.g. fullwidth letters, drops
+// control & invisible chars, and collapses whitespace runs
+// into single spaces, so "  Ｂｏｂ\u{200B}  " becomes "Bob"
+pub fn normalize_name(name: &str) -> String {
+    let cleaned: String = name
+        .nfkc()
+        .filter(|&c| !c.is_control() || c.is_whitespace())
+        .filter(|&c| !is_invisible(c))
+        .collect();
+    cleaned.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn name_error(se_error_type: SeErrorType, message: &str) -> SeError {
+    SeError {
+        se_error_type: se_error_type.into(),
+        message: String::from(message),
+    }
+}
+
+// terms no name can contain, matched after normalizing
+// both the same way as guesses, so spacing, case and
+// accents don't get around it
+#[derive(Debug, Clone, Default)]
+pub struct NameBlocklist {
+    terms: Vec<String>,
+}
+
+impl NameBlocklist {
+    pub fn new<T: AsRef<str>>(terms: impl IntoIterator<Item = T>) -> Self {
+        let terms = terms
+            .into_iter()
+            .map(|term| normalize_guess(term.as_ref()))
+            .filter(|term| !term.is_empty())
+            .collect();
+        Self { terms }
+    }
+    // one term per line, blank lines and lines
+    // starting with # are skipped
+    pub fn parse(list: &str) -> Self {
+        Self::new(
+            list.lines()
+                .map(str::trim)
+                .filter(|line| !line.starts_with('#')),
+        )
+    }
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+    pub fn blocks(&self, name: &str) -> bool {
+        let name = normalize_guess(name);
+        self.terms.iter().any(|term| name.contains(term.as_str()))
+    }
+}
+
+// the name as it should be shown, or why it can't be
+pub fn validate_name(
+    name: &str,
+    blocklist: &NameBlocklist,
+) -> Result<String, SeError> {
+    let name = normalize_name(name);
+    let len = name.chars().count();
+    if len < MIN_NAME_LEN {
+        return Err(name_error(SeErrorType::NameTooShort, "name is too short"));
+    }
+    if len > MAX_NAME_LEN {
+        return Err(name_error(SeErrorType::NameTooLong, "name is too long"));
+    }
+    if blocklist.blocks(&name) {
+        return Err(name_error(SeErrorType::NameBlocked, "name isn't allowed"));
+    }
+    Ok(name)
+}
+
+impl Game {
+    // true if another player already goes by the name,
+    // ignoring case so "bob" and "Bob" can't both play
+    pub fn name_taken(&self, name: &str, player_id: PlayerId) -> bool {
+        let name = name.to_lowercase();
+        self.players.iter().any(|(&other_id, other)| {
+            other_id != player_id && other.name.to_lowercase() == name
+        })
+    }
+    // adds a number to the name if it's taken, e.g. "bob 2",
+    // cutting the name short if that would make it too long
+    pub fn unique_name(&self, name: &str, player_id: PlayerId) -> String {
+        if !self.name_taken(name, player_id) {
+            return name.to_owned();
+        }
+        (2..)
+            .map(|n| {
+                let suffix = format!(" {n}");
+                let keep = MAX_NAME_LEN.saturating_sub(suffix.len());
+                let base: String = name.chars().take(keep).collect();
+                format!("{}{suffix}", base.trim_end())
+            })
+            .find(|candidate| !self.name_taken(candidate, player_id))
+            .expect("found an untaken name")
+    }
+}
@@ -0,0 +1,179 @@
+use super::*;
+
+#[derive(Debug, Clone, Copy)]
+pub enum ReplayStep<'a> {
+    DrawOp(&'a DrawOp),
+    Guess(&'a Guess),
+    Hint(&'a Hint),
+}
+
+impl ReplayStep<'_> {
+    // the step as the event that originally carried it,
+    // so clients can replay a round with the same code
+    // they use to follow a live one
+    pub fn to_server_event(&self, drawer_id: PlayerId) -> ServerEvent {
+        let se_type = match *self {
+            ReplayStep::DrawOp(draw_op) => {
+                SeType::PlayerDrawOp(SePlayerDrawOp {
+                    drawer_id,
+                    draw_op: Some(draw_op.clone()),
+                })
+            }
+            ReplayStep::Guess(guess) => SeType::RoundGuess(SeRoundGuess {
+                guess: Some(guess.clone()),
+            }),
+            ReplayStep::Hint(hint) => SeType::RoundHint(SeRoundHint {
+                hint: Some(hint.clone()),
+            }),
+        };
+        ServerEvent {
+            se_type: Some(se_type),
+        }
+    }
+}
+
+// walks a drawer's draw ops, guesses and hints in the order
+// they happened, guesses and hints are placed by their
+// after_draw_ops, hints go before guesses at the same spot
+// since a guess may have been inspired by the hint, in duels
+// each drawer's stream replays on its own, with the guesses
+// made watching it, and hints go with the drawer's stream
+pub struct Replay<'a> {
+    round: &'a Round,
+    drawer_id: PlayerId,
+    stream: &'a [DrawOp],
+    stream_hints: &'a [Hint],
+    draw_ops: usize,
+    guesses: usize,
+    hints: usize,
+}
+
+impl<'a> Replay<'a> {
+    // None if the player isn't drawing this round
+    pub fn new(round: &'a Round, drawer_id: PlayerId) -> Option<Self> {
+        let stream = round.draw_ops_of(drawer_id)?;
+        let stream_hints: &[Hint] = if drawer_id == round.drawer_id {
+            &round.hints
+        } else {
+            &[]
+        };
+        Some(Self {
+            round,
+            drawer_id,
+            stream,
+            stream_hints,
+            draw_ops: 0,
+            guesses: 0,
+            hints: 0,
+        })
+    }
+    pub fn drawer_id(&self) -> PlayerId {
+        self.drawer_id
+    }
+    // how many draw ops have been replayed so far
+    pub fn draw_ops(&self) -> usize {
+        self.draw_ops
+    }
+    // the canvas as it looked at this point in the replay
+    pub fn canvas(&self) -> Canvas {
+        Canvas::from_draw_ops(&self.stream[..self.draw_ops])
+    }
+    // jumps to just after the given number of draw ops, with
+    // every guess and hint up to that point already replayed
+    pub fn seek(&mut self, draw_ops: usize) {
+        self.draw_ops = 0;
+        self.guesses = 0;
+        self.hints = 0;
+        let draw_ops = draw_ops.min(self.stream.len());
+        while self.draw_ops < draw_ops {
+            self.next();
+        }
+        // also skip whatever happened right after the last op
+        while !matches!(self.peek(), None | Some(ReplayStep::DrawOp(_))) {
+            self.next();
+        }
+    }
+    // true if the guess's after_draw_ops counts this stream's ops
+    fn in_stream(&self, guess: &Guess) -> bool {
+        match self.round.rival_id {
+            Some(rival_id) if self.drawer_id == rival_id => {
+                guess.watching_id == Some(rival_id)
+            }
+            Some(rival_id) => guess.watching_id != Some(rival_id),
+            None => true,
+        }
+    }
+    // the next guess in this stream and its index in the round
+    fn next_guess(&self) -> Option<(usize, &'a Guess)> {
+        let round = self.round;
+        round.guesses[self.guesses..]
+            .iter()
+            .enumerate()
+            .find(|(_, guess)| self.in_stream(guess))
+            .map(|(idx, guess)| (self.guesses + idx, guess))
+    }
+    fn peek(&self) -> Option<ReplayStep<'a>> {
+        let at = self.draw_ops;
+        let last = self.stream.len();
+        let due = |after_draw_ops: u32| {
+            // anything anchored past the last op
+            // comes after every op has been replayed
+            after_draw_ops as usize <= at || at == last
+        };
+        if let Some(hint) = self.stream_hints.get(self.hints) {
+            if due(hint.after_draw_ops) {
+                return Some(ReplayStep::Hint(hint));
+            }
+        }
+        if let Some((_, guess)) = self.next_guess() {
+            if due(guess.after_draw_ops) {
+                return Some(ReplayStep::Guess(guess));
+            }
+        }
+        self.stream.get(at).map(ReplayStep::DrawOp)
+    }
+}
+
+impl<'a> Iterator for Replay<'a> {
+    type Item = ReplayStep<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = self.peek()?;
+        match step {
+            ReplayStep::DrawOp(_) => self.draw_ops += 1,
+            ReplayStep::Guess(_) => {
+                if let Some((idx, _)) = self.next_guess() {
+                    self.guesses = idx + 1;
+                }
+            }
+            ReplayStep::Hint(_) => self.hints += 1,
+        }
+        Some(step)
+    }
+}
+
+impl Round {
+    // the drawer's replay, see replay_of for the rival's
+    pub fn replay(&self) -> Replay<'_> {
+        Replay::new(self, self.drawer_id).expect("drawer has draw ops")
+    }
+    // None if the player isn't drawing this round
+    pub fn replay_of(&self, drawer_id: PlayerId) -> Option<Replay<'_>> {
+        Replay::new(self, drawer_id)
+    }
+    // the whole round as events, in the order they happened,
+    // in duels the drawer's stream comes first, then the
+    // rival's, since the two weren't recorded against each other
+    pub fn replay_events(&self) -> ServerEvents {
+        ServerEvents {
+            events: self
+                .drawer_ids()
+                .filter_map(|drawer_id| self.replay_of(drawer_id))
+                .flat_map(|replay| {
+                    let drawer_id = replay.drawer_id();
+                    replay.map(move |step| step.to_server_event(drawer_id))
+                })
+                .collect(),
+        }
+    }
+}
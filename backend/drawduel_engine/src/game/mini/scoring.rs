@@ -0,0 +1,229 @@
+use super::*;
+
+// everything a scoring policy might want
+// to know about a correct guess
+pub struct CorrectGuessInfo {
+    // 0 for the 1st correct guess of the round, 1 for the 2nd, etc
+    pub rank: u32,
+    // connected players who aren't drawing
+    pub guessers: u32,
+    pub secs_elapsed: u32,
+    pub play_secs: u32,
+    pub hints: u32,
+    pub multipler: u32,
+    pub base_score: u32,
+    pub first_correct_bonus: u32,
+}
+
+pub struct GuessScores {
+    pub guesser: Score,
+    pub drawer: Score,
+}
+
+pub trait ScoringPolicy {
+    fn score_guess(&self, info: &CorrectGuessInfo) -> GuessScores;
+}
+
+// guesser:
+// ((firstCorrectBonus + base) - (roundTimeElapsedSecs + len(hints))) * difficulty multipler
+// drawer (only for first guess):
+// same as the first guesser
+pub struct ClassicScoring;
+
+impl ScoringPolicy for ClassicScoring {
+    fn score_guess(&self, info: &CorrectGuessInfo) -> GuessScores {
+        let first_correct_bonus = if info.rank == 0 {
+            info.first_correct_bonus
+        } else {
+            0
+        };
+        let guesser = (first_correct_bonus + info.base_score)
+            .saturating_sub(info.secs_elapsed.saturating_add(info.hints))
+            .saturating_mul(info.multipler);
+        GuessScores {
+            guesser,
+            drawer: if info.rank == 0 { guesser } else { 0 },
+        }
+    }
+}
+
+// guesser score falls from base to 0 over the
+// play phase, no matter how long the phase is
+pub struct LinearDecayScoring;
+
+impl ScoringPolicy for LinearDecayScoring {
+    fn score_guess(&self, info: &CorrectGuessInfo) -> GuessScores {
+        let secs_left = info.play_secs.saturating_sub(info.secs_elapsed);
+        let decayed = (info.base_score as u64 * secs_left as u64)
+            .checked_div(info.play_secs as u64)
+            .unwrap_or(0) as u32;
+        let guesser = decayed
+            .saturating_sub(info.hints)
+            .saturating_mul(info.multipler);
+        GuessScores {
+            guesser,
+            drawer: if info.rank == 0 { guesser } else { 0 },
+        }
+    }
+}
+
+// classic guesser score, but the drawer gets half of
+// every correct guess instead of only the first one
+pub struct DrawerPerGuessScoring;
+
+impl ScoringPolicy for DrawerPerGuessScoring {
+    fn score_guess(&self, info: &CorrectGuessInfo) -> GuessScores {
+        let guesser = ClassicScoring.score_guess(info).guesser;
+        GuessScores {
+            guesser,
+            drawer: guesser / 2,
+        }
+    }
+}
+
+// only the order players guessed in matters, the first
+// correct guess gets the base score, the last gets
+// base / guessers, and everyone between is spread evenly
+pub struct RankedScoring;
+
+impl ScoringPolicy for RankedScoring {
+    fn score_guess(&self, info: &CorrectGuessInfo) -> GuessScores {
+        let guessers = info.guessers.max(1);
+        let places_left = guessers.saturating_sub(info.rank).max(1);
+        let guesser = (info.base_score * places_left / guessers)
+            .saturating_sub(info.hints)
+            .saturating_mul(info.multipler);
+        GuessScores {
+            guesser,
+            drawer: if info.rank == 0 { guesser } else { 0 },
+        }
+    }
+}
+
+impl ScoringPolicyType {
+    pub fn policy(&self) -> &'static dyn ScoringPolicy {
+        match self {
+            ScoringPolicyType::Classic => &ClassicScoring,
+            ScoringPolicyType::LinearDecay => &LinearDecayScoring,
+            ScoringPolicyType::DrawerPerGuess => &DrawerPerGuessScoring,
+            ScoringPolicyType::Ranked => &RankedScoring,
+        }
+    }
+}
+
+impl Game {
+    pub fn scoring_policy(&self) -> &'static dyn ScoringPolicy {
+        self.settings().scoring_policy().policy()
+    }
+    // scores the guesser's correct guess, which should
+    // already be the last guess in the current round
+    pub fn score_correct_guess(
+        &mut self,
+        guesser_id: PlayerId,
+        send_buf: &mut Vec<ServerEvent>,
+        timer_buf: &mut Vec<TimedEvent>,
+        clock: &impl Clock,
+    ) {
+        let Some(round) = &self.round else {
+            return;
+        };
+        let Some(guess) = round.guesses.last() else {
+            return;
+        };
+        let drawer_ids = round.credited_drawer_ids(guess);
+        let settings = self.settings();
+        let multipler = settings.multipler(round.word_choice());
+        let phase_duration = settings.play_duration(round.word_choice());
+        let phase_started_at = round
+            .phase_ends_at
+            .saturating_sub(phase_duration.as_millis() as EpochMs);
+        // truncates u64
+        let secs_elapsed =
+            (clock.now().saturating_sub(phase_started_at) / 1000) as u32;
+        let correct_guesses = round
+            .guesses
+            .iter()
+            .filter(|guess| {
+                matches!(guess.guess_type, Some(GuessType::CorrectGuess(_)))
+            })
+            .count() as u32;
+        let info = CorrectGuessInfo {
+            rank: correct_guesses.saturating_sub(1),
+            guessers: self
+                .connected_players()
+                .saturating_sub(round.drawer_ids().count())
+                as u32,
+            secs_elapsed,
+            play_secs: phase_duration.as_secs() as u32,
+            hints: round.hints.len() as u32,
+            multipler,
+            base_score: settings.base_score,
+            first_correct_bonus: settings.first_correct_bonus,
+        };
+        let scores = self.scoring_policy().score_guess(&info);
+
+        let mut score_events = Vec::with_capacity(6);
+        if scores.guesser > 0 {
+            score_events
+                .extend(guesser_score_events(guesser_id, scores.guesser));
+        }
+        if scores.drawer > 0 {
+            // each drawer the guess credits scores in full
+            for drawer_id in drawer_ids {
+                score_events
+                    .extend(drawer_score_events(drawer_id, scores.drawer));
+            }
+        }
+        self.apply_scores(score_events, clock.now(), send_buf, timer_buf);
+    }
+    // apply scores thru the same events clients get, so both
+    // end up with the same game state, whatever handled the
+    // player's event should only send clients what it recorded,
+    // e.g. the judged guess, or they'd score it a 2nd time,
+    // scoring never reads the clock so scored at is enough
+    pub fn apply_scores(
+        &mut self,
+        score_events: impl IntoIterator<Item = SeType>,
+        scored_at: EpochMs,
+        send_buf: &mut Vec<ServerEvent>,
+        timer_buf: &mut Vec<TimedEvent>,
+    ) {
+        let clock = ManualClock::new(scored_at);
+        for se_type in score_events {
+            let event = ServerEvent {
+                se_type: Some(se_type),
+            };
+            self.advance(event, send_buf, timer_buf, &clock);
+        }
+    }
+}
+
+// the guesser's score for the current round
+pub fn guesser_score_events(
+    guesser_id: PlayerId,
+    inc_by: Score,
+) -> [SeType; 3] {
+    [
+        SeType::PlayerIncGuessScore(SePlayerIncGuessScore {
+            guesser_id,
+            inc_by,
+        }),
+        SeType::PlayerIncRoundScore(SePlayerIncRoundScore {
+            player_id: guesser_id,
+            inc_by,
+        }),
+        SeType::RoundIncGuessScore(SeRoundIncGuessScore { inc_by }),
+    ]
+}
+
+// the drawer's score for the current round
+pub fn drawer_score_events(drawer_id: PlayerId, inc_by: Score) -> [SeType; 3] {
+    [
+        SeType::PlayerIncDrawScore(SePlayerIncDrawScore { drawer_id, inc_by }),
+        SeType::PlayerIncRoundScore(SePlayerIncRoundScore {
+            player_id: drawer_id,
+            inc_by,
+        }),
+        SeType::RoundIncDrawScore(SeRoundIncDrawScore { inc_by }),
+    ]
+}
@@ -0,0 +1,111 @@
+use super::*;
+
+// settings every new room starts with
+pub const DEFAULT_SETTINGS: GameSettings = GameSettings {
+    scoring_policy: ScoringPolicyType::Classic as i32,
+    choose_word_secs: 10,
+    pre_play_secs: 5,
+    play_easy_secs: 45,
+    play_hard_secs: 60,
+    inactive_drawer_secs: 10,
+    post_play_secs: 5,
+    hint_interval_secs: 10,
+    rounds: 8,
+    max_players: 12,
+    base_score: 120,
+    first_correct_bonus: 10,
+    easy_multipler: 2,
+    hard_multipler: 3,
+    like_bonus: 5,
+    duel: false,
+    vote_secs: 10,
+    vote_bonus: 50,
+};
+
+// limits for settings the host picks
+pub const MAX_PHASE_SECS: u32 = 600;
+pub const MAX_ROUNDS: u32 = 100;
+pub const MAX_PLAYERS: u32 = 32;
+pub const MAX_BASE_SCORE: u32 = 1000;
+pub const MAX_MULTIPLER: u32 = 10;
+
+impl GameSettings {
+    pub fn choose_word_duration(&self) -> Duration {
+        Duration::from_secs(self.choose_word_secs as u64)
+    }
+    pub fn pre_play_duration(&self) -> Duration {
+        Duration::from_secs(self.pre_play_secs as u64)
+    }
+    pub fn play_duration(&self, word_choice: WordChoice) -> Duration {
+        let secs = if word_choice == WordChoice::Hard {
+            self.play_hard_secs
+        } else {
+            self.play_easy_secs
+        };
+        Duration::from_secs(secs as u64)
+    }
+    pub fn inactive_drawer_duration(&self) -> Duration {
+        Duration::from_secs(self.inactive_drawer_secs as u64)
+    }
+    pub fn vote_duration(&self) -> Duration {
+        Duration::from_secs(self.vote_secs as u64)
+    }
+    pub fn post_play_duration(&self) -> Duration {
+        Duration::from_secs(self.post_play_secs as u64)
+    }
+    // None if hints are off
+    pub fn hint_interval(&self) -> Option<Duration> {
+        if self.hint_interval_secs == 0 {
+            None
+        } else {
+            Some(Duration::from_secs(self.hint_interval_secs as u64))
+        }
+    }
+    pub fn multipler(&self, word_choice: WordChoice) -> u32 {
+        if word_choice == WordChoice::Hard {
+            self.hard_multipler
+        } else {
+            self.easy_multipler
+        }
+    }
+    // clamps everything into a playable range, since
+    // these come straight from the host's client
+    pub fn sanitized(mut self) -> Self {
+        if ScoringPolicyType::try_from(self.scoring_policy).is_err() {
+            self.scoring_policy = DEFAULT_SETTINGS.scoring_policy;
+        }
+        for secs in [
+            &mut self.choose_word_secs,
+            &mut self.pre_play_secs,
+            &mut self.play_easy_secs,
+            &mut self.play_hard_secs,
+            &mut self.inactive_drawer_secs,
+            &mut self.post_play_secs,
+            &mut self.vote_secs,
+        ] {
+            *secs = (*secs).clamp(1, MAX_PHASE_SECS);
+        }
+        self.hint_interval_secs = self.hint_interval_secs.min(MAX_PHASE_SECS);
+        self.rounds = self.rounds.clamp(1, MAX_ROUNDS);
+        self.max_players = self
+            .max_players
+            .clamp(self.min_players() as u32, MAX_PLAYERS);
+        self.base_score = self.base_score.clamp(1, MAX_BASE_SCORE);
+        self.first_correct_bonus = self.first_correct_bonus.min(MAX_BASE_SCORE);
+        self.easy_multipler = self.easy_multipler.clamp(1, MAX_MULTIPLER);
+        self.hard_multipler = self.hard_multipler.clamp(1, MAX_MULTIPLER);
+        self.like_bonus = self.like_bonus.min(MAX_BASE_SCORE);
+        self.vote_bonus = self.vote_bonus.min(MAX_BASE_SCORE);
+        self
+    }
+}
+
+impl Game {
+    pub fn settings(&self) -> &GameSettings {
+        self.settings.as_ref().unwrap_or(&DEFAULT_SETTINGS)
+    }
+    // settings can only change between matches
+    pub fn in_lobby(&self) -> bool {
+        self.round.is_none() || self.is_game_over()
+    }
+}
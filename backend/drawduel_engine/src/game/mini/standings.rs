@@ -0,0 +1,73 @@
+use std::cmp::Reverse;
+
+use super::*;
+
+// best player first, ranked by total score, ties are
+// broken by guess score since drawing well is partly
+// luck of the word, players still tied share a place
+pub fn standings(players: &HashMap<PlayerId, Player>) -> Vec<Standing> {
+    let mut standings: Vec<Standing> = players
+        .iter()
+        .map(|(&player_id, player)| Standing {
+            player_id,
+            place: 0,
+            score: player.draw_score + player.guess_score,
+            draw_score: player.draw_score,
+            guess_score: player.guess_score,
+        })
+        .collect();
+    // player id last so every engine orders ties the same
+    standings.sort_unstable_by_key(|standing| {
+        (
+            Reverse(standing.score),
+            Reverse(standing.guess_score),
+            standing.player_id,
+        )
+    });
+    for idx in 0..standings.len() {
+        let tied = idx > 0 && {
+            let (prev, curr) = (&standings[idx - 1], &standings[idx]);
+            prev.score == curr.score && prev.guess_score == curr.guess_score
+        };
+        standings[idx].place = if tied {
+            standings[idx - 1].place
+        } else {
+            idx as u32 + 1
+        };
+    }
+    standings
+}
+
+impl Game {
+    pub fn standings(&self) -> Vec<Standing> {
+        standings(&self.players)
+    }
+    pub fn is_game_over(&self) -> bool {
+        self.round
+            .as_ref()
+            .is_some_and(|round| round.phase() == Phase::GameOver)
+    }
+    // true once every connected player has
+    // drawn as many times as the settings say
+    pub fn match_over(&self) -> bool {
+        let rounds = self.settings().rounds;
+        self.connected_players() > 0
+            && self
+                .players
+                .values()
+                .filter(|player| player.connected)
+                .all(|player| player.times_drawn >= rounds)
+    }
+    // back to the lobby with everyone's scores
+    // wiped, players and settings stay the same
+    pub fn play_again(&mut self) {
+        self.round = None;
+        self.round_likes.clear();
+        for player in self.players.values_mut() {
+            player.draw_score = 0;
+            player.guess_score = 0;
+            player.round_score = 0;
+            player.times_drawn = 0;
+        }
+    }
+}
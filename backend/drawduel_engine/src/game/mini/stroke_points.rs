@@ -0,0 +1,75 @@
+use super::*;
+
+// normalized coords are quantized to this many steps per
+// canvas side, a quarter pixel on a 1000px wide canvas
+pub const QUANTIZED_MAX: u32 = 4095;
+// most points a single stroke points op can carry
+pub const MAX_POINTS_PER_OP: usize = 128;
+
+pub fn quantize(coord: f32) -> u32 {
+    (coord.clamp(0.0, 1.0) * QUANTIZED_MAX as f32).round() as u32
+}
+
+pub fn dequantize(coord: u32) -> f32 {
+    coord as f32 / QUANTIZED_MAX as f32
+}
+
+impl DoStrokePoints {
+    // packs normalized points into a batch, None if there
+    // are no points or more than fit into a single op
+    pub fn encode(
+        start: bool,
+        color_type: ColorType,
+        points: &[Point],
+    ) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        if points.len() > MAX_POINTS_PER_OP {
+            return None;
+        }
+        let (mut prev_x, mut prev_y) = (quantize(first.x), quantize(first.y));
+        let mut deltas = Vec::with_capacity(rest.len() * 2);
+        for point in rest {
+            let (x, y) = (quantize(point.x), quantize(point.y));
+            deltas.push(x as i32 - prev_x as i32);
+            deltas.push(y as i32 - prev_y as i32);
+            (prev_x, prev_y) = (x, y);
+        }
+        Some(Self {
+            start,
+            color_type: color_type.into(),
+            x: quantize(first.x),
+            y: quantize(first.y),
+            deltas,
+        })
+    }
+    // unpacks the batch into normalized points, None
+    // if any point falls outside of the canvas
+    pub fn decode(&self) -> Option<Vec<Point>> {
+        if !self.deltas.len().is_multiple_of(2)
+            || self.deltas.len() / 2 + 1 > MAX_POINTS_PER_OP
+        {
+            return None;
+        }
+        let (mut x, mut y) = (self.x, self.y);
+        if x > QUANTIZED_MAX || y > QUANTIZED_MAX {
+            return None;
+        }
+        let mut points = Vec::with_capacity(self.deltas.len() / 2 + 1);
+        points.push(Point {
+            x: dequantize(x),
+            y: dequantize(y),
+        });
+        for &[dx, dy] in self.deltas.as_chunks::<2>().0 {
+            x = x.checked_add_signed(dx)?;
+            y = y.checked_add_signed(dy)?;
+            if x > QUANTIZED_MAX || y > QUANTIZED_MAX {
+                return None;
+            }
+            points.push(Point {
+                x: dequantize(x),
+                y: dequantize(y),
+            });
+        }
+        Some(points)
+    }
+}
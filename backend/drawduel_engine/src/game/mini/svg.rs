@@ -0,0 +1,96 @@
+use std::fmt::Write;
+
+use super::*;
+
+// same 4:3 aspect ratio as the client's canvas
+pub const EXPORT_WIDTH: u32 = 640;
+pub const EXPORT_HEIGHT: u32 = 480;
+pub const EXPORT_STROKE_WIDTH: f32 = 5.0;
+
+// drawn between side by side canvases
+pub const EXPORT_DIVIDER_COLOR: &str = "#cccccc";
+
+impl Canvas {
+    pub fn to_svg(&self) -> String {
+        canvases_to_svg(std::slice::from_ref(self))
+    }
+    // one path per stroke, shifted right by x_offset px,
+    // colors are re-formatted rather than copied as is
+    // since they come straight from the drawer's client
+    fn write_svg_paths(&self, svg: &mut String, x_offset: f32) {
+        let width = EXPORT_WIDTH as f32;
+        let height = EXPORT_HEIGHT as f32;
+        for stroke in self.strokes() {
+            let [first, rest @ ..] = stroke.points.as_slice() else {
+                continue;
+            };
+            let [r, g, b] = parse_hex_color(&stroke.color).unwrap_or([0, 0, 0]);
+            // writing to a string can't fail
+            let _ = write!(
+                svg,
+                "<path fill=\"none\" stroke=\"#{r:02x}{g:02x}{b:02x}\" \
+                 stroke-width=\"{EXPORT_STROKE_WIDTH}\" \
+                 stroke-linecap=\"round\" stroke-linejoin=\"round\" \
+                 d=\"M{:.2} {:.2}",
+                x_offset + first.x * width,
+                first.y * height,
+            );
+            // a tap without a drag is a dot, which
+            // round caps draw for a zero length line
+            if rest.is_empty() {
+                svg.push_str("l0 0");
+            }
+            for point in rest {
+                let _ = write!(
+                    svg,
+                    "L{:.2} {:.2}",
+                    x_offset + point.x * width,
+                    point.y * height
+                );
+            }
+            svg.push_str("\"/>");
+        }
+    }
+}
+
+// vector export of the canvases side by side, e.g. both
+// drawings of a duel round, each one EXPORT_WIDTH wide
+pub fn canvases_to_svg(canvases: &[Canvas]) -> String {
+    let width = EXPORT_WIDTH * canvases.len().max(1) as u32;
+    let strokes: usize =
+        canvases.iter().map(|canvas| canvas.strokes().len()).sum();
+    let mut svg = String::with_capacity(256 + strokes * 128);
+    // writing to a string can't fail
+    let _ = write!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+         width=\"{width}\" height=\"{EXPORT_HEIGHT}\" \
+         viewBox=\"0 0 {width} {EXPORT_HEIGHT}\">\
+         <rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>",
+    );
+    for (idx, canvas) in canvases.iter().enumerate() {
+        let x_offset = (idx as u32 * EXPORT_WIDTH) as f32;
+        if idx > 0 {
+            let _ = write!(
+                svg,
+                "<line x1=\"{x_offset}\" y1=\"0\" \
+                 x2=\"{x_offset}\" y2=\"{EXPORT_HEIGHT}\" \
+                 stroke=\"{EXPORT_DIVIDER_COLOR}\" stroke-width=\"2\"/>",
+            );
+        }
+        canvas.write_svg_paths(&mut svg, x_offset);
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+pub fn draw_ops_to_svg(draw_ops: &[DrawOp]) -> String {
+    Canvas::from_draw_ops(draw_ops).to_svg()
+}
+
+impl Round {
+    // every drawer's drawing, the drawer's on the left
+    pub fn to_svg(&self) -> String {
+        canvases_to_svg(&self.canvases())
+    }
+}
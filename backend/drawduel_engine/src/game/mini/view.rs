@@ -0,0 +1,175 @@
+use super::*;
+
+// stands in for the easy & hard words
+// when they're hidden from a player
+pub const HIDDEN_WORD: WordIdx = WordIdx::MAX;
+
+// never a player's id, so spectators see the
+// game like a guesser who hasn't guessed yet
+pub const SPECTATOR_ID: PlayerId = PlayerId::MAX;
+
+impl Guess {
+    // true if only some players get to see this guess
+    pub fn is_private(&self) -> bool {
+        matches!(
+            &self.guess_type,
+            Some(GuessType::IncorrectGuess(incorrect))
+                if incorrect.close || incorrect.after_solving
+        )
+    }
+}
+
+impl Round {
+    // true if the player has guessed the word this round
+    pub fn guessed_correctly(&self, player_id: PlayerId) -> bool {
+        self.guesses.iter().any(|guess| {
+            guess.guesser_id == player_id
+                && matches!(guess.guess_type, Some(GuessType::CorrectGuess(_)))
+        })
+    }
+    // everyone gets to see the word once play is over
+    pub fn word_revealed(&self) -> bool {
+        matches!(
+            self.phase(),
+            Phase::Vote | Phase::PostPlay | Phase::GameOver
+        )
+    }
+    // near misses only go to the guesser, and guesses from
+    // players who solved only go to players who know the
+    // word, nothing's a spoiler once the word is revealed
+    pub fn guess_visible_to(&self, guess: &Guess, player_id: PlayerId) -> bool {
+        let Some(GuessType::IncorrectGuess(incorrect)) = &guess.guess_type
+        else {
+            return true;
+        };
+        if guess.guesser_id == player_id || self.word_revealed() {
+            return true;
+        }
+        if incorrect.after_solving {
+            return self.is_drawer(player_id)
+                || self.guessed_correctly(player_id);
+        }
+        !incorrect.close
+    }
+    // the drawer always knows the word, guessers only
+    // after guessing it or once the round is over
+    pub fn word_visible_to(&self, player_id: PlayerId) -> bool {
+        self.word_revealed()
+            || self.is_drawer(player_id)
+            || self.guessed_correctly(player_id)
+    }
+}
+
+impl Game {
+    // the game as the player is allowed to see it
+    pub fn view_for(&self, player_id: PlayerId) -> Game {
+        let mut game = self.clone();
+        if let (Some(round), Some(view)) = (&self.round, &mut game.round) {
+            view.guesses
+                .retain(|guess| round.guess_visible_to(guess, player_id));
+        }
+        if let Some(round) = &mut game.round {
+            if !round.word_visible_to(player_id) {
+                round.easy_word = HIDDEN_WORD;
+                round.hard_word = HIDDEN_WORD;
+            }
+        }
+        game
+    }
+    pub fn spectator_view(&self) -> Game {
+        self.view_for(SPECTATOR_ID)
+    }
+}
+
+impl ServerEvent {
+    // true if every player sees this event as is,
+    // so it can be serialized once and broadcast
+    pub fn is_public(&self) -> bool {
+        match &self.se_type {
+            Some(SeType::NewRound(_))
+            | Some(SeType::SetGame(_))
+            | Some(SeType::RoundRevealWords(_))
+            | Some(SeType::PlayerGuessClose(_))
+            | Some(SeType::PlayerDrawOpReject(_))
+            | Some(SeType::PlayerChatReject(_)) => false,
+            Some(SeType::RoundGuess(round_guess)) => {
+                !round_guess.guess.as_ref().is_some_and(Guess::is_private)
+            }
+            _ => true,
+        }
+    }
+    // the event as the player is allowed to see it, None
+    // if the player shouldn't receive it at all, expects
+    // the game to have already advanced past this event
+    pub fn view_for(
+        &self,
+        game: &Game,
+        player_id: PlayerId,
+    ) -> Option<ServerEvent> {
+        match self.se_type.as_ref()? {
+            SeType::NewRound(new_round) => {
+                if new_round.drawer_id == player_id
+                    || new_round.rival_id == Some(player_id)
+                {
+                    return Some(self.clone());
+                }
+                Some(ServerEvent {
+                    se_type: Some(SeType::NewRound(SeRoundNew {
+                        easy_word: HIDDEN_WORD,
+                        hard_word: HIDDEN_WORD,
+                        ..*new_round
+                    })),
+                })
+            }
+            SeType::SetGame(set_game) => Some(ServerEvent {
+                se_type: Some(SeType::SetGame(SeSetGame {
+                    player_id: set_game.player_id,
+                    spectating: set_game.spectating,
+                    game: set_game
+                        .game
+                        .as_ref()
+                        .map(|game| Box::new(game.view_for(player_id))),
+                })),
+            }),
+            SeType::RoundRevealWords(_) => {
+                let round = game.round.as_ref()?;
+                if round.word_visible_to(player_id) {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
+            SeType::PlayerGuessClose(guess_close) => {
+                if guess_close.guesser_id == player_id {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
+            SeType::PlayerDrawOpReject(draw_op_reject) => {
+                if draw_op_reject.drawer_id == player_id {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
+            SeType::RoundGuess(round_guess) => {
+                let round = game.round.as_ref()?;
+                let guess = round_guess.guess.as_ref()?;
+                if round.guess_visible_to(guess, player_id) {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
+            SeType::PlayerChatReject(chat_reject) => {
+                if chat_reject.player_id == player_id {
+                    Some(self.clone())
+                } else {
+                    None
+                }
+            }
+            _ => Some(self.clone()),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use super::*;
+
+impl Round {
+    // only duel rounds have drawings to choose between,
+    // there's no free draw mode and a solo round would
+    // leave voters just the one drawing to pick
+    pub fn phase_after_play(&self) -> Phase {
+        if self.is_duel() {
+            Phase::Vote
+        } else {
+            Phase::PostPlay
+        }
+    }
+    pub fn has_voted(&self, voter_id: PlayerId) -> bool {
+        self.votes.iter().any(|vote| vote.voter_id == voter_id)
+    }
+    // guessers get one vote each, for either drawer
+    pub fn can_vote(&self, voter_id: PlayerId, drawer_id: PlayerId) -> bool {
+        self.phase() == Phase::Vote
+            && !self.is_drawer(voter_id)
+            && self.is_drawer(drawer_id)
+            && !self.has_voted(voter_id)
+    }
+    pub fn votes_for(&self, drawer_id: PlayerId) -> u32 {
+        self.votes
+            .iter()
+            .filter(|vote| vote.drawer_id == drawer_id)
+            .count() as u32
+    }
+    // the drawers with the most votes, more than one
+    // if they tied, none if nobody voted
+    pub fn vote_winner_ids(&self) -> Vec<PlayerId> {
+        let most_votes = self
+            .drawer_ids()
+            .map(|drawer_id| self.votes_for(drawer_id))
+            .max()
+            .unwrap_or(0);
+        if most_votes == 0 {
+            return Vec::new();
+        }
+        self.drawer_ids()
+            .filter(|&drawer_id| self.votes_for(drawer_id) == most_votes)
+            .collect()
+    }
+}
+
+impl Game {
+    // true once every connected guesser has voted
+    pub fn all_voted(&self) -> bool {
+        let Some(round) = &self.round else {
+            return false;
+        };
+        self.players.iter().all(|(&player_id, player)| {
+            !player.connected
+                || round.is_drawer(player_id)
+                || round.has_voted(player_id)
+        })
+    }
+    // moves on from the play phase to the vote, or straight
+    // to post play, the word is revealed to everyone either way
+    pub fn end_play(
+        &mut self,
+        from: EpochMs,
+        send_buf: &mut Vec<ServerEvent>,
+        timer_buf: &mut Vec<TimedEvent>,
+    ) {
+        let settings = *self.settings();
+        let Some(round) = &mut self.round else {
+            return;
+        };
+        let phase = round.phase_after_play();
+        let (duration, timed_event_type) = if phase == Phase::Vote {
+            (settings.vote_duration(), TimedEventType::VotePhaseOver)
+        } else {
+            (
+                settings.post_play_duration(),
+                TimedEventType::PostPlayPhaseOver,
+            )
+        };
+        let phase_ends_at = from + duration.as_millis() as u64;
+        round.phase = phase.into();
+        round.phase_ends_at = phase_ends_at;
+        send_buf.push(ServerEvent {
+            se_type: Some(SeType::RoundChangePhase(SeRoundChangePhase {
+                phase: phase.into(),
+                phase_ends_at,
+            })),
+        });
+        send_buf.push(round.reveal_words_event());
+        timer_buf.push(TimedEvent {
+            target_round_id: round.round_id,
+            target_phase: phase,
+            timed_event_type,
+            times_out_at: phase_ends_at,
+        });
+    }
+    // tallies the votes and moves on to post play,
+    // tied drawers split the bonus
+    pub fn close_vote(
+        &mut self,
+        from: EpochMs,
+        send_buf: &mut Vec<ServerEvent>,
+        timer_buf: &mut Vec<TimedEvent>,
+    ) {
+        let settings = *self.settings();
+        let Some(round) = &mut self.round else {
+            return;
+        };
+        let winner_ids = round.vote_winner_ids();
+        let inc_by = settings
+            .vote_bonus
+            .checked_div(winner_ids.len() as u32)
+            .unwrap_or(0);
+        let score_events: Vec<SeType> = winner_ids
+            .iter()
+            .flat_map(|&winner_id| drawer_score_events(winner_id, inc_by))
+            .collect();
+        let vote_result = SeVoteResult { winner_ids, inc_by };
+        let phase_ends_at =
+            from + settings.post_play_duration().as_millis() as u64;
+        round.phase = Phase::PostPlay.into();
+        round.phase_ends_at = phase_ends_at;
+        timer_buf.push(TimedEvent {
+            target_round_id: round.round_id,
+            target_phase: Phase::PostPlay,
+            timed_event_type: TimedEventType::PostPlayPhaseOver,
+            times_out_at: phase_ends_at,
+        });
+        send_buf.push(ServerEvent {
+            se_type: Some(SeType::VoteResult(vote_result)),
+        });
+        if inc_by > 0 {
+            self.apply_scores(score_events, from, send_buf, timer_buf);
+        }
+        send_buf.push(ServerEvent {
+            se_type: Some(SeType::RoundChangePhase(SeRoundChangePhase {
+                phase: Phase::PostPlay.into(),
+                phase_ends_at,
+            })),
+        });
+    }
+}
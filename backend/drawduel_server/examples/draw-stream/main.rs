@@ -0,0 +1,111 @@
+use drawduel_engine::game::mini::*;
+use prost::Message;
+
+// points per stroke points op, at 120hz pointer
+// events that's a batch roughly every 66ms
+const POINTS_PER_BATCH: usize = 8;
+
+// a squiggly stroke across the canvas, like a
+// player drawing at ~120hz for a second
+fn example_stroke() -> Vec<Point> {
+    (0..120)
+        .map(|idx| {
+            let t = idx as f32 / 119.0;
+            Point {
+                x: 0.1 + 0.8 * t,
+                y: 0.5 + 0.2 * (t * 12.0).sin() * (1.0 - t),
+            }
+        })
+        .collect()
+}
+
+// every draw op goes out as its own msg, one ws frame each
+fn frame_bytes(drawer_id: PlayerId, draw_op: DrawOp) -> usize {
+    ServerEvents {
+        events: vec![ServerEvent {
+            se_type: Some(SeType::PlayerDrawOp(SePlayerDrawOp {
+                drawer_id,
+                draw_op: Some(draw_op),
+            })),
+        }],
+    }
+    .encoded_len()
+}
+
+fn per_point_frames(drawer_id: PlayerId, stroke: &[Point]) -> Vec<usize> {
+    stroke
+        .iter()
+        .enumerate()
+        .map(|(idx, point)| {
+            let do_type = if idx == 0 {
+                DoType::StartStroke(DoStartStroke {
+                    color_type: ColorType::Primary.into(),
+                    x: point.x,
+                    y: point.y,
+                })
+            } else {
+                DoType::ContinueStroke(DoContinueStroke {
+                    x: point.x,
+                    y: point.y,
+                })
+            };
+            frame_bytes(
+                drawer_id,
+                DrawOp {
+                    do_type: Some(do_type),
+                },
+            )
+        })
+        .collect()
+}
+
+fn batched_frames(drawer_id: PlayerId, stroke: &[Point]) -> Vec<usize> {
+    stroke
+        .chunks(POINTS_PER_BATCH)
+        .enumerate()
+        .map(|(idx, points)| {
+            let stroke_points =
+                DoStrokePoints::encode(idx == 0, ColorType::Primary, points)
+                    .expect("batch fits in one op");
+            frame_bytes(
+                drawer_id,
+                DrawOp {
+                    do_type: Some(DoType::StrokePoints(stroke_points)),
+                },
+            )
+        })
+        .collect()
+}
+
+fn report(name: &str, frames: &[usize], points: usize) {
+    // server to client ws frames have a 2 byte header for
+    // payloads under 126 bytes and 4 bytes for bigger ones
+    let payload: usize = frames.iter().sum();
+    let headers: usize = frames
+        .iter()
+        .map(|&len| if len < 126 { 2 } else { 4 })
+        .sum();
+    let total = payload + headers;
+    println!(
+        "{name:>10}: {:>3} frames, {total:>5} bytes per stroke, {:.2} bytes per point",
+        frames.len(),
+        total as f32 / points as f32,
+    );
+}
+
+fn main() {
+    let drawer_id = 3;
+    let stroke = example_stroke();
+    let per_point = per_point_frames(drawer_id, &stroke);
+    let batched = batched_frames(drawer_id, &stroke);
+    println!("{} point stroke", stroke.len());
+    report("per point", &per_point, stroke.len());
+    report("batched", &batched, stroke.len());
+    let per_point_total: usize = per_point.iter().sum();
+    let batched_total: usize = batched.iter().sum();
+    println!(
+        "{:>10}: {:.1}x fewer payload bytes",
+        "reduction",
+        per_point_total as f32 / batched_total as f32,
+    );
+}
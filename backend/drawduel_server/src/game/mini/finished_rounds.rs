@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use drawduel_engine::game::mini::*;
+
+// how many finished rounds to keep around
+const MAX_KEPT_ROUNDS: RoundId = 256;
+
+// finished rounds, written by the room and read by http
+// handlers, so drawings and replays can be fetched after
+// the room has moved on to the next round
+#[derive(Clone, Default)]
+pub struct FinishedRounds {
+    rounds: Arc<DashMap<RoundId, Round>>,
+}
+
+impl FinishedRounds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn get(&self, round_id: RoundId) -> Option<Round> {
+        self.rounds.get(&round_id).map(|round| round.clone())
+    }
+    // keeps the current round once it's over, safe to
+    // call after every event since it only copies once
+    pub fn keep_if_over(&self, game: &Game) {
+        let Some(round) = &game.round else {
+            return;
+        };
+        if !matches!(round.phase(), Phase::PostPlay | Phase::GameOver)
+            || self.rounds.contains_key(&round.round_id)
+        {
+            return;
+        }
+        self.rounds.insert(round.round_id, round.clone());
+        // round ids are handed out in order, so
+        // the oldest kept round is easy to find
+        if let Some(oldest) = round.round_id.checked_sub(MAX_KEPT_ROUNDS) {
+            self.rounds.remove(&oldest);
+        }
+    }
+}
@@ -1,3 +1,9 @@
+mod finished_rounds;
+pub use finished_rounds::*;
+
+mod render;
+pub use render::*;
+
 mod service;
 pub use service::*;
 
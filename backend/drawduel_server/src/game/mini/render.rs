@@ -0,0 +1,78 @@
+use drawduel_engine::game::mini::*;
+use tiny_skia::{
+    Color, FillRule, LineCap, LineJoin, Paint, PathBuilder, Pixmap, Rect,
+    Stroke, Transform,
+};
+
+fn paint(color: &str) -> Paint<'static> {
+    let [r, g, b] = parse_hex_color(color).unwrap_or([0, 0, 0]);
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(r, g, b, 255);
+    paint.anti_alias = true;
+    paint
+}
+
+// rasterizes the canvases side by side on the cpu, e.g. both
+// drawings of a duel round, draw op coords are normalized
+// so they're scaled up to the export size
+pub fn render_png(canvases: &[Canvas]) -> Option<Vec<u8>> {
+    let columns = canvases.len().max(1) as u32;
+    let mut pixmap = Pixmap::new(EXPORT_WIDTH * columns, EXPORT_HEIGHT)?;
+    pixmap.fill(Color::WHITE);
+    for (idx, canvas) in canvases.iter().enumerate() {
+        let x_offset = (idx as u32 * EXPORT_WIDTH) as f32;
+        let transform = Transform::from_translate(x_offset, 0.0);
+        if idx > 0 {
+            if let Some(divider) =
+                Rect::from_xywh(x_offset - 1.0, 0.0, 2.0, EXPORT_HEIGHT as f32)
+            {
+                pixmap.fill_rect(
+                    divider,
+                    &paint(EXPORT_DIVIDER_COLOR),
+                    Transform::identity(),
+                    None,
+                );
+            }
+        }
+        render_strokes(&mut pixmap, canvas, transform);
+    }
+    pixmap.encode_png().ok()
+}
+
+fn render_strokes(pixmap: &mut Pixmap, canvas: &Canvas, transform: Transform) {
+    let width = EXPORT_WIDTH as f32;
+    let height = EXPORT_HEIGHT as f32;
+    let line = Stroke {
+        width: EXPORT_STROKE_WIDTH,
+        line_cap: LineCap::Round,
+        line_join: LineJoin::Round,
+        ..Stroke::default()
+    };
+    for stroke in canvas.strokes() {
+        let paint = paint(&stroke.color);
+        let [first, rest @ ..] = stroke.points.as_slice() else {
+            continue;
+        };
+        // a tap without a drag is a dot
+        if rest.is_empty() {
+            let Some(dot) = PathBuilder::from_circle(
+                first.x * width,
+                first.y * height,
+                EXPORT_STROKE_WIDTH / 2.0,
+            ) else {
+                continue;
+            };
+            pixmap.fill_path(&dot, &paint, FillRule::Winding, transform, None);
+            continue;
+        }
+        let mut path = PathBuilder::new();
+        path.move_to(first.x * width, first.y * height);
+        for point in rest {
+            path.line_to(point.x * width, point.y * height);
+        }
+        let Some(path) = path.finish() else {
+            continue;
+        };
+        pixmap.stroke_path(&path, &paint, &line, transform, None);
+    }
+}
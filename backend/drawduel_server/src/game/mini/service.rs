@@ -5,8 +5,9 @@ use axum::{
     body::Bytes,
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
-        Query, State,
+        Path, Query, State,
     },
+    http::header,
     response::IntoResponse,
 };
 use drawduel_engine::game::mini::*;
@@ -20,9 +21,8 @@ use std::future::Future;
 use std::net::SocketAddr;
 use std::pin::{pin, Pin};
 use std::task::{Context, Poll};
-use std::time::SystemTime;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Debug, Display},
     sync::Arc,
     time::Duration,
@@ -33,13 +33,12 @@ use tokio::{
     time::{self},
 };
 
-use crate::game::mini::{random_easy_word, random_hard_word};
+use crate::game::mini::{
+    random_easy_word, random_hard_word, render_png, FinishedRounds,
+};
 
 fn sleep_until_epoch_ms(epoch_ms: u64) -> Result<Sleep, ()> {
-    let now = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .expect("Time went backwards")
-        .as_millis() as u64;
+    let now = SystemClock.now();
 
     if epoch_ms <= now {
         // target time is before now
@@ -97,6 +96,25 @@ impl TimedEventQueue {
         let sleep = Box::pin(sleep_until(instant));
         self.heap.push(TimedEventTimer { sleep, timed_event });
     }
+
+    /// Add a timed event to the queue, fires immediately
+    /// if it has already timed out.
+    fn add(&mut self, timed_event: TimedEvent) {
+        let sleep = sleep_until_epoch_ms(timed_event.times_out_at)
+            .unwrap_or_else(|()| sleep_until(Instant::now()));
+        self.heap.push(TimedEventTimer {
+            sleep: Box::pin(sleep),
+            timed_event,
+        });
+    }
+
+    /// Drain timed events out of the engine's timer buffer
+    /// into the queue.
+    fn add_all(&mut self, timer_buf: &mut Vec<TimedEvent>) {
+        for timed_event in timer_buf.drain(..) {
+            self.add(timed_event);
+        }
+    }
 }
 
 impl Future for TimedEventQueue {
@@ -123,35 +141,95 @@ impl Future for TimedEventQueue {
 type PlayerId = u32;
 type SerializedMsg = Bytes;
 type UniqueSerializedMsg = Vec<u8>;
-type GameTx = broadcast::Sender<SerializedMsg>;
-type GameRx = broadcast::Receiver<SerializedMsg>;
+// unicast msgs go thru the same broadcast channel
+// as everything else, so each player receives all
+// of their msgs in the order the room sent them
+type GameMsg = (Recipient, SerializedMsg);
+type GameTx = broadcast::Sender<GameMsg>;
+type GameRx = broadcast::Receiver<GameMsg>;
 type RoomTx = mpsc::Sender<RoomEvent>;
 type RoomRx = mpsc::Receiver<RoomEvent>;
-type RegisterTx =
-    oneshot::Sender<Result<(PlayerId, UniqueSerializedMsg, GameRx), SeError>>;
-type RegisterRx =
-    oneshot::Receiver<Result<(PlayerId, UniqueSerializedMsg, GameRx), SeError>>;
+// the bool is true for spectators
+type Registration = (PlayerId, bool, UniqueSerializedMsg, GameRx);
+type RegisterTx = oneshot::Sender<Result<Registration, SeError>>;
+type RegisterRx = oneshot::Receiver<Result<Registration, SeError>>;
+
+#[derive(Clone, Copy, Debug)]
+enum Recipient {
+    All,
+    Player(PlayerId),
+    // closes the player's connection, the msg is ignored
+    Kicked(PlayerId),
+    // every spectator gets the same view of the game
+    Spectators,
+}
+
+impl Recipient {
+    fn includes(&self, player_id: PlayerId, spectating: bool) -> bool {
+        match self {
+            Recipient::All => true,
+            Recipient::Player(recipient_id) => *recipient_id == player_id,
+            Recipient::Kicked(kicked_id) => *kicked_id == player_id,
+            Recipient::Spectators => spectating,
+        }
+    }
+}
 
 #[derive(Deserialize, Clone, Debug)]
 pub struct ClientInfo {
     name: Option<String>,
     pass: String,
+    // e.g. ?spectate=true, watches without joining the players
+    #[serde(default)]
+    spectate: bool,
 }
 
 #[derive(Clone)]
 pub struct SharedServiceState {
     room_tx: Arc<RoomTx>,
+    finished_rounds: FinishedRounds,
 }
 
 const CHANNEL_CAPACITY: usize = 2048;
 
+// path to a file of terms no player name can contain,
+// one per line, no names are blocked if it isn't set
+const NAME_BLOCKLIST_ENV: &str = "MINI_GAME_NAME_BLOCKLIST";
+
+fn load_name_blocklist() -> NameBlocklist {
+    let Ok(path) = std::env::var(NAME_BLOCKLIST_ENV) else {
+        return NameBlocklist::default();
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(list) => {
+            let name_blocklist = NameBlocklist::parse(&list);
+            tracing::debug!(
+                "loaded {} blocked name terms from {path}",
+                name_blocklist.len()
+            );
+            name_blocklist
+        }
+        Err(err) => {
+            tracing::error!("failed to read name blocklist {path}: {err}");
+            NameBlocklist::default()
+        }
+    }
+}
+
 impl SharedServiceState {
     pub fn new() -> Self {
         let (room_tx, room_rx) = mpsc::channel(CHANNEL_CAPACITY);
         let (game_tx, _) = broadcast::channel(CHANNEL_CAPACITY);
-        let _ = tokio::spawn(room_manager(game_tx, room_rx));
+        let finished_rounds = FinishedRounds::new();
+        let _ = tokio::spawn(room_manager(
+            game_tx,
+            room_rx,
+            finished_rounds.clone(),
+            load_name_blocklist(),
+        ));
         SharedServiceState {
             room_tx: Arc::new(room_tx),
+            finished_rounds,
         }
     }
 }
@@ -159,19 +237,48 @@ impl SharedServiceState {
 #[derive(Debug)]
 struct RoomState {
     passes: HashMap<String, u32>,
+    // passes of players kicked from the current match,
+    // they can't come back until it's over
+    kicked: HashSet<String>,
     disconnects: Vec<(PlayerId, Instant)>,
+    spectators: HashSet<PlayerId>,
 }
 
 impl RoomState {
     fn new() -> Self {
         Self {
             passes: HashMap::new(),
+            kicked: HashSet::new(),
             disconnects: Vec::new(),
+            spectators: HashSet::new(),
         }
     }
     fn reset(&mut self) {
         self.passes.clear();
+        self.kicked.clear();
         self.disconnects.clear();
+        self.spectators.clear();
+    }
+    fn kick(&mut self, player_id: PlayerId) {
+        let kicked_passes = self
+            .passes
+            .iter()
+            .filter(|(_, &id)| id == player_id)
+            .map(|(pass, _)| pass.clone())
+            .collect::<Vec<_>>();
+        for pass in kicked_passes {
+            self.passes.remove(&pass);
+            self.kicked.insert(pass);
+        }
+    }
+    // kicked players can join the next match
+    fn forget_kicks_if_over(&mut self, events: &[ServerEvent]) {
+        if events
+            .iter()
+            .any(|event| matches!(event.se_type, Some(SeType::GameOver(_))))
+        {
+            self.kicked.clear();
+        }
     }
 }
 
@@ -220,6 +327,34 @@ fn error_into_response(
             ),
         )
             .into_response(),
+        Ok(SeErrorType::Kicked) => (
+            StatusCode::FORBIDDEN,
+            format!(
+                "{:?} w/pass {} was kicked from mini game",
+                client_info.name, client_info.pass,
+            ),
+        )
+            .into_response(),
+        Ok(SeErrorType::FullGame) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!(
+                "{:?} w/pass {} can't join full mini game",
+                client_info.name, client_info.pass,
+            ),
+        )
+            .into_response(),
+        Ok(
+            SeErrorType::NameTooShort
+            | SeErrorType::NameTooLong
+            | SeErrorType::NameBlocked,
+        ) => (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "{:?} w/pass {} can't join mini game, {}",
+                client_info.name, client_info.pass, error.message,
+            ),
+        )
+            .into_response(),
         err => (
             StatusCode::INTERNAL_SERVER_ERROR,
             format!(
@@ -260,18 +395,18 @@ pub async fn ws_handler(
     let recv_result = register_rx.await;
     if let Ok(register_result) = recv_result {
         match register_result {
-            Ok((player_id, set_game_event, game_rx)) => {
-                ws.on_upgrade(move |socket| {
+            Ok((player_id, spectating, set_game_event, game_rx)) => ws
+                .on_upgrade(move |socket| {
                     player_manager(
                         socket,
                         addr,
                         player_id,
+                        spectating,
                         set_game_event,
                         game_rx,
                         (*room_tx).clone(),
                     )
-                })
-            }
+                }),
             Err(error) => error_into_response(error, client_info),
         }
     } else {
@@ -286,15 +421,25 @@ pub async fn ws_handler(
     }
 }
 
+// the set game event is unique to each player since
+// it's their view of the game, e.g. guessers don't get
+// to see the word
 fn serialize_set_game(
-    mut game: Game,
+    game: &Game,
     mut events: Vec<ServerEvent>,
     player_id: u32,
-) -> (Game, Vec<ServerEvent>, UniqueSerializedMsg) {
+    spectating: bool,
+) -> (Vec<ServerEvent>, UniqueSerializedMsg) {
+    let view = if spectating {
+        game.spectator_view()
+    } else {
+        game.view_for(player_id)
+    };
     events.push(ServerEvent {
         se_type: Some(SeType::SetGame(SeSetGame {
             player_id: player_id,
-            game: Some(game),
+            game: Some(Box::new(view)),
+            spectating,
         })),
     });
     let server_events = ServerEvents { events: events };
@@ -304,17 +449,8 @@ fn serialize_set_game(
         .expect("was able to encode set game state msg");
     // get reusable buffer back out
     events = server_events.events;
-    // get reusable game back out
-    game = {
-        let server_event = events.pop().unwrap();
-        if let Some(SeType::SetGame(server_game)) = server_event.se_type {
-            server_game.game.unwrap()
-        } else {
-            panic!("this should be impossible")
-        }
-    };
     events.clear();
-    (game, events, serialized)
+    (events, serialized)
 }
 
 fn serialize_server_events(
@@ -346,19 +482,179 @@ impl SerialIds {
     }
 }
 
-async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
+// builds the event which starts the next round, returns
+// None if there aren't enough connected players to play one
+fn new_round_event(
+    game: &Game,
+    round_ids: &mut SerialIds,
+    clock: &impl Clock,
+) -> Option<ServerEvent> {
+    if game.connected_players() < game.min_players() {
+        return None;
+    }
+    let drawer_id = game.next_drawer_id()?;
+    let rival_id = if game.settings().duel {
+        Some(game.next_rival_id(drawer_id)?)
+    } else {
+        None
+    };
+    Some(ServerEvent {
+        se_type: Some(SeType::NewRound(SeRoundNew {
+            round_id: round_ids.get_id(),
+            drawer_id,
+            rival_id,
+            easy_word: random_easy_word(),
+            hard_word: random_hard_word(),
+            starting_phase: Phase::ChooseWord.into(),
+            phase_ends_at: clock
+                .ms_from_now(game.settings().choose_word_duration()),
+        })),
+    })
+}
+
+// starts a new round if there isn't one in progress,
+// e.g. when enough players have finally joined
+fn start_round_if_ready(
+    game: &mut Game,
+    round_ids: &mut SerialIds,
+    events: Vec<ServerEvent>,
+    timers: &mut Vec<TimedEvent>,
+    game_tx: &GameTx,
+    clock: &impl Clock,
+) -> Vec<ServerEvent> {
+    // matches only start when the host or every player says so
+    if game.in_lobby() || !game.round_over(clock.now()) {
+        return events;
+    }
+    // the post play timer ends the match, it may
+    // just not have fired yet
+    if game.match_over() {
+        return events;
+    }
+    start_next_round(game, round_ids, events, timers, game_tx, clock)
+}
+
+fn start_next_round(
+    game: &mut Game,
+    round_ids: &mut SerialIds,
+    mut events: Vec<ServerEvent>,
+    timers: &mut Vec<TimedEvent>,
+    game_tx: &GameTx,
+    clock: &impl Clock,
+) -> Vec<ServerEvent> {
+    let Some(new_round) = new_round_event(game, round_ids, clock) else {
+        return events;
+    };
+    game.advance(new_round, &mut events, timers, clock);
+    send_events(game, events, game_tx)
+}
+
+// sends events to every connected player, if any of them
+// are secret to some players then every player gets their
+// own view of the events instead of a single broadcast
+fn send_events(
+    game: &Game,
+    mut events: Vec<ServerEvent>,
+    game_tx: &GameTx,
+) -> Vec<ServerEvent> {
+    if events.is_empty() {
+        return events;
+    }
+    if events.iter().all(ServerEvent::is_public) {
+        let (reused_events, serialized_msg) = serialize_server_events(events);
+        if let Err(err) = game_tx.send((Recipient::All, serialized_msg)) {
+            tracing::error!("sent game message to empty game: {err}");
+        }
+        return reused_events;
+    }
+    let mut view = Vec::with_capacity(events.len());
+    for (&player_id, player) in game.players.iter() {
+        if !player.connected {
+            continue;
+        }
+        view.extend(
+            events
+                .iter()
+                .filter_map(|event| event.view_for(game, player_id)),
+        );
+        if view.is_empty() {
+            continue;
+        }
+        let (reused_view, serialized_msg) = serialize_server_events(view);
+        view = reused_view;
+        if let Err(err) =
+            game_tx.send((Recipient::Player(player_id), serialized_msg))
+        {
+            tracing::error!("sent game message to empty game: {err}");
+        }
+    }
+    if game.spectators > 0 {
+        view.extend(
+            events
+                .iter()
+                .filter_map(|event| event.view_for(game, SPECTATOR_ID)),
+        );
+        if !view.is_empty() {
+            let (_, serialized_msg) = serialize_server_events(view);
+            if let Err(err) =
+                game_tx.send((Recipient::Spectators, serialized_msg))
+            {
+                tracing::error!("sent game message to empty game: {err}");
+            }
+        }
+    }
+    events.clear();
+    events
+}
+
+async fn room_manager(
+    game_tx: GameTx,
+    mut room_rx: RoomRx,
+    finished_rounds: FinishedRounds,
+    name_blocklist: NameBlocklist,
+) {
     let mut player_ids = SerialIds::new();
     let mut round_ids = SerialIds::new();
     let mut game = Game::new();
     let mut room_state = RoomState::new();
     let mut events: Vec<ServerEvent> = Vec::with_capacity(4);
+    let mut timers: Vec<TimedEvent> = Vec::with_capacity(4);
+    let mut timed_events = TimedEventQueue::new();
+    let clock = SystemClock;
     loop {
-        let event = match room_rx.recv().await {
-            None => {
-                tracing::error!("DESTROYING ONLY MINIGAME ROOM NOOOOO!!!");
-                break;
-            }
-            Some(event) => event,
+        let event = tokio::select! {
+            maybe_event = room_rx.recv() => match maybe_event {
+                None => {
+                    tracing::error!("DESTROYING ONLY MINIGAME ROOM NOOOOO!!!");
+                    break;
+                }
+                Some(event) => event,
+            },
+            timed_event = &mut timed_events => {
+                let round_over = game.is_timely(&timed_event)
+                    && matches!(
+                        timed_event.timed_event_type,
+                        TimedEventType::PostPlayPhaseOver
+                    );
+                game.timed_advance(timed_event, &mut events, &mut timers);
+                room_state.forget_kicks_if_over(&events);
+                events = send_events(&game, events, &game_tx);
+                finished_rounds.keep_if_over(&game);
+                // after the match's last round the game sits
+                // in game over until someone plays again
+                if round_over && !game.is_game_over() {
+                    events = start_next_round(
+                        &mut game,
+                        &mut round_ids,
+                        events,
+                        &mut timers,
+                        &game_tx,
+                        &clock,
+                    );
+                }
+                timed_events.add_all(&mut timers);
+                continue;
+            },
         };
 
         tracing::trace!("mini game state {game:?}");
@@ -368,10 +664,59 @@ async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
                 register_tx,
                 client_info,
             } => {
-                let ClientInfo { name, pass } = client_info;
+                let ClientInfo {
+                    name,
+                    pass,
+                    spectate,
+                } = client_info;
+                // reconnecting players keep the name they had
+                let validated_name = name
+                    .as_deref()
+                    .map(|name| validate_name(name, &name_blocklist))
+                    .transpose();
+
+                // spectators are only counted, they never
+                // join the players so can't score or play
+                if spectate {
+                    let spectator_id = player_ids.get_id();
+                    room_state.spectators.insert(spectator_id);
+                    let spectators = ServerEvent {
+                        se_type: Some(SeType::Spectators(SeSpectators {
+                            spectators: room_state.spectators.len() as u32,
+                        })),
+                    };
+                    game.advance(spectators, &mut events, &mut timers, &clock);
+                    // nobody to tell if the room is empty
+                    if game_tx.receiver_count() > 0 {
+                        events = send_events(&game, events, &game_tx);
+                    } else {
+                        events.clear();
+                    }
+
+                    let (reused_events, serialized_msg) =
+                        serialize_set_game(&game, events, spectator_id, true);
+                    events = reused_events;
+                    register_tx
+                        .send(Ok((
+                            spectator_id,
+                            true,
+                            serialized_msg,
+                            game_tx.subscribe(),
+                        )))
+                        .expect("sent init msg to spectator");
+
+                // kicked players can still watch, but
+                // can't play again until the match is over
+                } else if room_state.kicked.contains(&pass) {
+                    register_tx
+                        .send(Err(SeError {
+                            se_error_type: SeErrorType::Kicked.into(),
+                            message: String::from("kicked from this match"),
+                        }))
+                        .expect("sent kicked error to kicked player");
 
                 // check if this an existing player reconnecting
-                if let Some(&player_id) = room_state.passes.get(&pass) {
+                } else if let Some(&player_id) = room_state.passes.get(&pass) {
                     // let other players know this player has reconnected
                     let player_connect = ServerEvent {
                         se_type: Some(SeType::PlayerConnect(SePlayerConnect {
@@ -381,7 +726,12 @@ async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
 
                     // not sure when this would ever be false, maybe if player
                     // disconnected due to stale connection earlier?
-                    game.advance(player_connect, &mut events);
+                    game.advance(
+                        player_connect,
+                        &mut events,
+                        &mut timers,
+                        &clock,
+                    );
                     let player_connected = !events.is_empty();
                     debug_assert!(
                         player_connected,
@@ -392,28 +742,44 @@ async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
                             serialize_server_events(events);
                         events = reused_events;
                         game_tx
-                            .send(serialized_msg)
+                            .send((Recipient::All, serialized_msg))
                             .expect("sent player connect msg");
                     }
 
                     // send connected player current game state
-                    let (reused_game, reused_events, serialized_msg) =
-                        serialize_set_game(game, events, player_id);
-                    game = reused_game;
+                    let (reused_events, serialized_msg) =
+                        serialize_set_game(&game, events, player_id, false);
                     events = reused_events;
                     register_tx
                         .send(Ok((
                             player_id,
+                            false,
                             serialized_msg,
                             game_tx.subscribe(),
                         )))
                         .expect("sent init msg to reconnecting player");
 
-                // otherwise this is a new player connecting
+                // otherwise this is a new player connecting, if
+                // there's room for them
+                } else if game.connected_players()
+                    >= game.settings().max_players as usize
+                {
+                    register_tx
+                        .send(Err(SeError {
+                            se_error_type: SeErrorType::FullGame.into(),
+                            message: String::from("game is full"),
+                        }))
+                        .expect("sent full game error to new player");
+                } else if let Err(error) = &validated_name {
+                    register_tx
+                        .send(Err(error.clone()))
+                        .expect("sent name error to new player");
                 } else {
                     // let other players know this player has joined
                     let new_player_id = player_ids.get_id();
-                    let player_name = name
+                    let player_name = validated_name
+                        .ok()
+                        .flatten()
                         .unwrap_or_else(|| format!("player{new_player_id:02}"));
                     let player_join = ServerEvent {
                         se_type: Some(SeType::PlayerJoin(SePlayerJoin {
@@ -424,7 +790,7 @@ async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
 
                     // not sure when this would ever be false, something very wrong
                     // must have occurred for this to somehow be false
-                    game.advance(player_join, &mut events);
+                    game.advance(player_join, &mut events, &mut timers, &clock);
                     let player_joined = !events.is_empty();
                     debug_assert!(player_joined, "new player {new_player_id} connected but was already present in game state");
                     if player_joined {
@@ -434,12 +800,14 @@ async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
                         tracing::trace!("multiple players {multiple_players}");
 
                         // only send msg if there are other players
-                        // to receive it
-                        if multiple_players {
+                        // or spectators to receive it
+                        if multiple_players || game.spectators > 0 {
                             let (reused_events, serialized_msg) =
                                 serialize_server_events(events);
                             events = reused_events;
-                            if let Err(err) = game_tx.send(serialized_msg) {
+                            if let Err(err) =
+                                game_tx.send((Recipient::All, serialized_msg))
+                            {
                                 tracing::error!(
                                     "sent game message to empty game: {err}"
                                 );
@@ -450,36 +818,107 @@ async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
                     }
 
                     // sent joined player current game state
-                    let (reused_game, reused_events, serialized_msg) =
-                        serialize_set_game(game, events, new_player_id);
-                    game = reused_game;
+                    let (reused_events, serialized_msg) =
+                        serialize_set_game(&game, events, new_player_id, false);
                     events = reused_events;
                     register_tx
                         .send(Ok((
                             new_player_id,
+                            false,
                             serialized_msg,
                             game_tx.subscribe(),
                         )))
                         .expect("sent init msg to reconnecting player");
                 }
             }
+            // names are checked before reaching the game,
+            // only the player hears why one is turned away
+            RoomEvent::ClientEvent {
+                player_id,
+                client_event:
+                    ClientEvent {
+                        ce_type: Some(CeType::Rename(CeRename { name })),
+                    },
+            } => match validate_name(&name, &name_blocklist) {
+                Err(error) => {
+                    events.push(ServerEvent {
+                        se_type: Some(SeType::Error(error)),
+                    });
+                    let (reused_events, serialized_msg) =
+                        serialize_server_events(events);
+                    events = reused_events;
+                    if let Err(err) = game_tx
+                        .send((Recipient::Player(player_id), serialized_msg))
+                    {
+                        tracing::error!(
+                            "sent game message to empty game: {err}"
+                        );
+                    }
+                }
+                // renaming never starts, ends or kicks anything
+                Ok(name) => {
+                    let server_event = ServerEvent {
+                        se_type: Some(SeType::PlayerRename(SePlayerRename {
+                            player_id,
+                            name,
+                        })),
+                    };
+                    game.advance(
+                        server_event,
+                        &mut events,
+                        &mut timers,
+                        &clock,
+                    );
+                    events = send_events(&game, events, &game_tx);
+                }
+            },
             RoomEvent::ClientEvent {
                 player_id,
                 client_event,
             } => {
                 let server_event =
                     ServerEvent::from_client(player_id, client_event);
-                game.advance(server_event, &mut events);
-                let advanced = !events.is_empty();
-                if advanced {
-                    let (reused_events, serialized_msg) =
-                        serialize_server_events(events);
-                    events = reused_events;
-                    game_tx
-                        .send(serialized_msg)
-                        .expect("sent server event to all players");
+                game.advance(server_event, &mut events, &mut timers, &clock);
+                let match_started = events.iter().any(|event| {
+                    matches!(event.se_type, Some(SeType::PlayerStartMatch(_)))
+                }) || game.should_auto_start();
+                let kicked_ids: Vec<PlayerId> = events
+                    .iter()
+                    .filter_map(|event| match &event.se_type {
+                        Some(SeType::PlayerKick(kick)) => Some(kick.player_id),
+                        _ => None,
+                    })
+                    .collect();
+                room_state.forget_kicks_if_over(&events);
+                events = send_events(&game, events, &game_tx);
+                for kicked_id in kicked_ids {
+                    room_state.kick(kicked_id);
+                    let _ = game_tx
+                        .send((Recipient::Kicked(kicked_id), Bytes::new()));
+                }
+                if match_started {
+                    events = start_next_round(
+                        &mut game,
+                        &mut round_ids,
+                        events,
+                        &mut timers,
+                        &game_tx,
+                        &clock,
+                    );
                 }
             }
+            RoomEvent::ClientDisconnect { player_id }
+                if room_state.spectators.contains(&player_id) =>
+            {
+                room_state.spectators.remove(&player_id);
+                let spectators = ServerEvent {
+                    se_type: Some(SeType::Spectators(SeSpectators {
+                        spectators: room_state.spectators.len() as u32,
+                    })),
+                };
+                game.advance(spectators, &mut events, &mut timers, &clock);
+                events = send_events(&game, events, &game_tx);
+            }
             RoomEvent::ClientDisconnect { player_id } => {
                 let server_event = ServerEvent {
                     se_type: Some(SeType::PlayerDisconnect(
@@ -488,27 +927,158 @@ async fn room_manager(game_tx: GameTx, mut room_rx: RoomRx) {
                         },
                     )),
                 };
-                game.advance(server_event, &mut events);
+                game.advance(server_event, &mut events, &mut timers, &clock);
                 let advanced = !events.is_empty();
                 if advanced {
                     let (reused_events, serialized_msg) =
                         serialize_server_events(events);
                     events = reused_events;
-                    if let Err(err) = game_tx.send(serialized_msg) {
+                    if let Err(err) =
+                        game_tx.send((Recipient::All, serialized_msg))
+                    {
                         // if we're here it means all players have disconnected
                         game.reset();
                         room_state.reset();
                     }
                 }
+                // whoever left may have been the last one not ready
+                if game.should_auto_start() {
+                    events = start_next_round(
+                        &mut game,
+                        &mut round_ids,
+                        events,
+                        &mut timers,
+                        &game_tx,
+                        &clock,
+                    );
+                }
             }
         }
+
+        // a guess may have ended the round early
+        finished_rounds.keep_if_over(&game);
+
+        // a player joining or reconnecting may have
+        // brought the room up to enough players to play
+        events = start_round_if_ready(
+            &mut game,
+            &mut round_ids,
+            events,
+            &mut timers,
+            &game_tx,
+            &clock,
+        );
+        timed_events.add_all(&mut timers);
     }
 }
 
+// serves finished rounds as images or replays, e.g.
+// /mini-game/rounds/12.png, /mini-game/rounds/12.svg
+// or /mini-game/rounds/12.replay for round 12, images
+// of duel rounds show both drawings side by side
+pub async fn round_export_handler(
+    Path(file): Path<String>,
+    State(shared_service_state): State<SharedServiceState>,
+) -> axum::response::Response {
+    let Some((round_id, extension)) =
+        file.rsplit_once('.').and_then(|(round_id, extension)| {
+            Some((round_id.parse::<RoundId>().ok()?, extension))
+        })
+    else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    if !matches!(extension, "png" | "svg" | "replay") {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let Some(round) = shared_service_state.finished_rounds.get(round_id) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    // finished rounds never change but round ids start over
+    // when the server restarts, so the same url can end up
+    // serving another round
+    let cache_control = (header::CACHE_CONTROL, "no-cache");
+    match extension {
+        "svg" => (
+            [(header::CONTENT_TYPE, "image/svg+xml"), cache_control],
+            round.to_svg(),
+        )
+            .into_response(),
+        // encoded ServerEvents, same as what the ws sends
+        "replay" => (
+            [
+                (header::CONTENT_TYPE, "application/x-protobuf"),
+                cache_control,
+            ],
+            round.replay_events().encode_to_vec(),
+        )
+            .into_response(),
+        _ => {
+            // rasterizing is cpu bound, keep it off the async workers
+            let rendered = tokio::task::spawn_blocking(move || {
+                render_png(&round.canvases())
+            })
+            .await;
+            match rendered {
+                Ok(Some(png)) => {
+                    ([(header::CONTENT_TYPE, "image/png"), cache_control], png)
+                        .into_response()
+                }
+                _ => {
+                    tracing::error!("failed to render round {round_id} as png");
+                    StatusCode::INTERNAL_SERVER_ERROR.into_response()
+                }
+            }
+        }
+    }
+}
+
+// most draw ops a player can send per second, generous
+// enough for high refresh rate pointers but not for floods
+const MAX_DRAW_OPS_PER_SEC: u32 = 240;
+
+struct DrawOpRateLimit {
+    window_start: Instant,
+    ops: u32,
+}
+
+impl DrawOpRateLimit {
+    fn new() -> Self {
+        Self {
+            window_start: Instant::now(),
+            ops: 0,
+        }
+    }
+    // true if the player can send another draw op right now
+    fn allow(&mut self, now: Instant) -> bool {
+        if now.duration_since(self.window_start) >= Duration::from_secs(1) {
+            self.window_start = now;
+            self.ops = 0;
+        }
+        self.ops += 1;
+        self.ops <= MAX_DRAW_OPS_PER_SEC
+    }
+}
+
+fn serialize_draw_op_reject(
+    drawer_id: PlayerId,
+    reason: DrawOpRejectReason,
+) -> Bytes {
+    let server_events = ServerEvents {
+        events: vec![ServerEvent {
+            se_type: Some(SeType::PlayerDrawOpReject(SePlayerDrawOpReject {
+                drawer_id,
+                reason: reason.into(),
+            })),
+        }],
+    };
+    Bytes::from(server_events.encode_to_vec())
+}
+
 async fn player_manager(
     mut socket: WebSocket,
     addr: SocketAddr,
     player_id: u32,
+    spectating: bool,
     set_game_event: UniqueSerializedMsg,
     mut game_rx: GameRx,
     room_tx: RoomTx,
@@ -520,6 +1090,7 @@ async fn player_manager(
     let alive_duration = Duration::from_millis(5000);
     let mut alive_interval = time::interval(alive_duration);
     let mut last_client_event = Instant::now();
+    let mut draw_op_rate_limit = DrawOpRateLimit::new();
 
     socket
         .send(Message::Binary(Bytes::from(set_game_event)))
@@ -570,6 +1141,22 @@ async fn player_manager(
                         match msg {
                             Message::Binary(bytes) => {
                                 match ClientEvent::decode(bytes) {
+                                    // spectators only watch
+                                    Ok(_) if spectating => {
+                                        tracing::trace!("ignoring event from spectator {player_id}");
+                                    },
+                                    // rate limited ops never reach the room
+                                    Ok(ClientEvent {
+                                        ce_type: Some(CeType::DrawOp(_)),
+                                    }) if !draw_op_rate_limit.allow(last_client_event) => {
+                                        let reject = serialize_draw_op_reject(
+                                            player_id,
+                                            DrawOpRejectReason::TooFast,
+                                        );
+                                        if socket.send(Message::Binary(reject)).await.is_err() {
+                                            tracing::trace!("player {player_id} @ {addr} failed to send draw op reject");
+                                        }
+                                    },
                                     Ok(client_event) => {
                                         room_tx
                                             .send(RoomEvent::ClientEvent {
@@ -637,7 +1224,17 @@ async fn player_manager(
                 }
             },
             recv_result = game_rx.recv() => {
-                if let Ok(serialized_msg) = recv_result {
+                if let Ok((recipient, serialized_msg)) = recv_result {
+                    if !recipient.includes(player_id, spectating) {
+                        continue;
+                    }
+                    if let Recipient::Kicked(_) = recipient {
+                        tracing::trace!("player {player_id} @ {addr} kicked by host");
+                        // already removed from the game,
+                        // so no need to tell the room
+                        let _ = socket.send(Message::Close(None)).await;
+                        break;
+                    }
                     let send_result = socket.send(Message::Binary(serialized_msg)).await;
                     if let Err(_) = send_result {
                         tracing::trace!("player {player_id} @ {addr} failed to send game event, breaking");
@@ -1,9 +1,9 @@
-use drawduel_engine::game::mini::{EASY_WORDS, HARD_WORDS};
+use drawduel_engine::game::mini::{WordIdx, EASY_WORDS, HARD_WORDS};
 
-pub fn random_easy_word() -> &'static str {
-    fastrand::choice(EASY_WORDS).unwrap()
+pub fn random_easy_word() -> WordIdx {
+    fastrand::usize(..EASY_WORDS.len()) as WordIdx
 }
 
-pub fn random_hard_word() -> &'static str {
-    fastrand::choice(HARD_WORDS).unwrap()
+pub fn random_hard_word() -> WordIdx {
+    fastrand::usize(..HARD_WORDS.len()) as WordIdx
 }
@@ -49,6 +49,7 @@ async fn main() {
                     .append_index_html_on_directories(true),
             )
             .route("/mini-game-ws", get(mini::ws_handler))
+            .route("/mini-game/rounds/{file}", get(mini::round_export_handler))
             .with_state(SharedGlobalState::new())
             // logging so we can see whats going on
             .layer(TraceLayer::new_for_http().make_span_with(
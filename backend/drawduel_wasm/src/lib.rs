@@ -1,5 +1,6 @@
 use drawduel_engine::game::mini::{
-    ClientEvent, Game, ServerEvent, ServerEvents,
+    ClientEvent, ColorType, DoStrokePoints, DoType, DrawOp, EpochMs, Game,
+    ManualClock, Point, Round, ServerEvent, ServerEvents,
 };
 use prost::Message as ProstMessage;
 
@@ -25,28 +26,33 @@ pub fn client_advance(
     player_id: u32,
     client_msg: &[u8],
     current_game: &[u8],
+    now: f64,
 ) -> Result<JsValue, JsError> {
     let client_event = ClientEvent::decode(client_msg)?;
     let server_event = ServerEvent::from_client(player_id, client_event);
-    wasm_advance(server_event, current_game)
+    wasm_advance(server_event, current_game, now)
 }
 
 #[wasm_bindgen]
 pub fn server_advance(
     server_msg: &[u8],
     current_game: &[u8],
+    now: f64,
 ) -> Result<JsValue, JsError> {
     let server_event = ServerEvent::decode(server_msg)?;
-    wasm_advance(server_event, current_game)
+    wasm_advance(server_event, current_game, now)
 }
 
+// now is epoch ms, callers pass Date.now() and
+// tests pass fixed times so scores come out the same
 fn wasm_advance(
     server_event: ServerEvent,
     current_game: &[u8],
+    now: f64,
 ) -> Result<JsValue, JsError> {
     let game = Game::decode(current_game)?;
     // println!("{:?}", game); // doesn't work?
-    match advance(server_event, game)? {
+    match advance(server_event, game, now as EpochMs)? {
         Some(next_state) => {
             let mut serialized_events = Vec::new();
             next_state.apply_events.encode(&mut serialized_events)?;
@@ -65,9 +71,13 @@ fn wasm_advance(
 fn advance(
     server_event: ServerEvent,
     mut game: Game,
+    now: EpochMs,
 ) -> Result<Option<NextState>, JsError> {
     let mut events = Vec::new();
-    game.advance(server_event, &mut events);
+    // timers are only ever driven by the server
+    let mut timers = Vec::new();
+    let clock = ManualClock::new(now);
+    game.advance(server_event, &mut events, &mut timers, &clock);
     if events.is_empty() {
         return Ok(None);
     }
@@ -78,5 +88,109 @@ fn advance(
     }))
 }
 
+// strokes on the drawer's screen for the current round,
+// undefined if there's no round yet or they aren't drawing
+#[wasm_bindgen]
+pub fn round_strokes(
+    current_game: &[u8],
+    drawer_id: u32,
+) -> Result<JsValue, JsError> {
+    let game = Game::decode(current_game)?;
+    match game
+        .round
+        .as_ref()
+        .and_then(|round| round.canvas_of(drawer_id))
+    {
+        Some(canvas) => Ok(serde_wasm_bindgen::to_value(canvas.strokes())?),
+        None => Ok(JsValue::undefined()),
+    }
+}
+
+// svg of the current round's drawings, side by side in
+// duels, undefined if there's no round yet
+#[wasm_bindgen]
+pub fn round_svg(current_game: &[u8]) -> Result<Option<String>, JsError> {
+    let game = Game::decode(current_game)?;
+    Ok(game.round.as_ref().map(Round::to_svg))
+}
+
+// the hint the server gives next in the current round as
+// an encoded Hint, undefined if there's no round yet or
+// no hints are left to give
+#[wasm_bindgen]
+pub fn round_next_hint(
+    current_game: &[u8],
+) -> Result<Option<Vec<u8>>, JsError> {
+    let game = Game::decode(current_game)?;
+    Ok(game
+        .round
+        .as_ref()
+        .and_then(Round::next_hint)
+        .map(|hint| hint.encode_to_vec()))
+}
+
+// the current round's draw ops, guesses and hints
+// as encoded ServerEvents in the order they happened,
+// step thru them or apply a prefix of them to scrub
+#[wasm_bindgen]
+pub fn round_replay(current_game: &[u8]) -> Result<Option<Vec<u8>>, JsError> {
+    let game = Game::decode(current_game)?;
+    Ok(game
+        .round
+        .as_ref()
+        .map(|round| round.replay_events().encode_to_vec()))
+}
+
+// what's left of the drawer's replay of the current round
+// after skipping to just past their given number of draw
+// ops, as encoded ServerEvents, undefined if there's no
+// round yet or they aren't drawing
+#[wasm_bindgen]
+pub fn round_replay_from(
+    current_game: &[u8],
+    drawer_id: u32,
+    draw_ops: u32,
+) -> Result<Option<Vec<u8>>, JsError> {
+    let game = Game::decode(current_game)?;
+    let Some(mut replay) = game
+        .round
+        .as_ref()
+        .and_then(|round| round.replay_of(drawer_id))
+    else {
+        return Ok(None);
+    };
+    replay.seek(draw_ops as usize);
+    let events = ServerEvents {
+        events: replay.map(|step| step.to_server_event(drawer_id)).collect(),
+    };
+    Ok(Some(events.encode_to_vec()))
+}
+
+// packs normalized points, given as x, y pairs, into an
+// encoded stroke points DrawOp, undefined if there are no
+// points or more than fit into a single op
+#[wasm_bindgen]
+pub fn encode_stroke_points(
+    start: bool,
+    color_type: i32,
+    points: &[f32],
+) -> Result<Option<Vec<u8>>, JsError> {
+    let color_type = ColorType::try_from(color_type)?;
+    let (points, []) = points.as_chunks::<2>() else {
+        return Err(JsError::new("points should be x, y pairs"));
+    };
+    let points: Vec<Point> =
+        points.iter().map(|&[x, y]| Point { x, y }).collect();
+    let Some(stroke_points) =
+        DoStrokePoints::encode(start, color_type, &points)
+    else {
+        return Ok(None);
+    };
+    let draw_op = DrawOp {
+        do_type: Some(DoType::StrokePoints(stroke_points)),
+    };
+    Ok(Some(draw_op.encode_to_vec()))
+}
+
 // Do not put tests here, put them in
 // frontend/tests/mini-game/quick/engine.test.ts
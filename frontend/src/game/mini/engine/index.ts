@@ -1,8 +1,12 @@
 import {
+    DrawOp,
     Game,
-    Guess,
+    GameSettings,
     Phase,
     Player,
+    Round,
+    RoundLikes,
+    ScoringPolicyType,
     ServerEvent,
     ServerEvents,
     WordChoice,
@@ -10,6 +14,42 @@ import {
 
 export * from './mini_game';
 
+// same as the server, settings every new room starts with
+export const DEFAULT_SETTINGS: GameSettings = {
+    scoringPolicy: ScoringPolicyType.CLASSIC,
+    chooseWordSecs: 10,
+    prePlaySecs: 5,
+    playEasySecs: 45,
+    playHardSecs: 60,
+    inactiveDrawerSecs: 10,
+    postPlaySecs: 5,
+    hintIntervalSecs: 10,
+    rounds: 8,
+    maxPlayers: 12,
+    baseScore: 120,
+    firstCorrectBonus: 10,
+    easyMultipler: 2,
+    hardMultipler: 3,
+    likeBonus: 5,
+    duel: false,
+    voteSecs: 10,
+    voteBonus: 50,
+};
+
+// same as the server, older messages are dropped
+export const MAX_CHAT_HISTORY = 100;
+
+// same as the server, a round needs a drawer and
+// at least one guesser, duels need a rival too
+export const MIN_PLAYERS = 2;
+export const MIN_DUEL_PLAYERS = 3;
+
+// the room's settings, or the defaults if the host
+// hasn't changed any yet
+export function gameSettings(game: Game): GameSettings {
+    return game.settings ?? DEFAULT_SETTINGS;
+}
+
 export function newGame(): Game {
     return Game.create();
 }
@@ -21,84 +61,290 @@ export function advanceAllGame(serverEvents: ServerEvents, game: Game): Game {
     return game;
 }
 
+function isHost(game: Game, playerId: number): boolean {
+    return game.hostId === playerId;
+}
+
+function isGameOver(game: Game): boolean {
+    return game.round?.phase === Phase.GAME_OVER;
+}
+
+function inLobby(game: Game): boolean {
+    return !game.round || isGameOver(game);
+}
+
+// same as the server, duel drawers each have their own stream
+function drawOpsOf(round: Round, drawerId: number): DrawOp[] | undefined {
+    if (round.drawerId === drawerId) {
+        return round.drawOps;
+    } else if (round.rivalId === drawerId) {
+        return round.rivalDrawOps;
+    }
+    return undefined;
+}
+
+function minPlayers(game: Game): number {
+    return gameSettings(game).duel ? MIN_DUEL_PLAYERS : MIN_PLAYERS;
+}
+
+// same as the server, disconnected players
+// don't hold the rest of the lobby up
+function allReady(game: Game): boolean {
+    let connected = Object.values(game.players).filter(
+        (player) => player.connected,
+    );
+    return (
+        connected.length >= minPlayers(game) &&
+        connected.every((player) => player.ready)
+    );
+}
+
+// same as the server, everyone has to ready
+// up again for the next match
+function unreadyAll(game: Game) {
+    for (let player of Object.values(game.players)) {
+        player.ready = false;
+    }
+}
+
+// same as the server, back to the lobby with everyone's
+// scores wiped, players and settings stay the same
+function playAgain(game: Game) {
+    game.round = undefined;
+    game.roundLikes = [];
+    for (let player of Object.values(game.players)) {
+        player.drawScore = 0;
+        player.guessScore = 0;
+        player.roundScore = 0;
+        player.timesDrawn = 0;
+    }
+}
+
+// same as the server, if the host leaves or disconnects
+// the connected player who's been around the longest
+// takes over
+function handOffHost(game: Game) {
+    if (game.hostId !== undefined && game.players[game.hostId]?.connected) {
+        return;
+    }
+    let connectedIds = Object.keys(game.players)
+        .map(Number)
+        .filter((playerId) => game.players[playerId].connected);
+    game.hostId =
+        connectedIds.length > 0 ? Math.min(...connectedIds) : undefined;
+}
+
+// the server already settled names & sanitized settings,
+// so the events it sends are applied as they are
 export function advanceGame(serverEvent: ServerEvent, game: Game): Game {
     if (serverEvent.setGame) {
         return serverEvent.setGame.game!;
     } else if (serverEvent.playerJoin) {
         let playerJoin = serverEvent.playerJoin;
-        game.players[playerJoin.id] = Player.fromPartial({
+        game.players[playerJoin.playerId] = Player.fromPartial({
             name: playerJoin.name,
-            score: 0,
             connected: true,
-            drawerScore: 0,
-            guesserScore: 0,
         });
+        handOffHost(game);
     } else if (serverEvent.playerLeave) {
         let playerLeave = serverEvent.playerLeave;
-        delete game.players[playerLeave.id];
+        if (game.players[playerLeave.playerId]) {
+            delete game.players[playerLeave.playerId];
+            handOffHost(game);
+        }
     } else if (serverEvent.playerConnect) {
         let playerConnect = serverEvent.playerConnect;
-        if (game.players[playerConnect.id]) {
-            game.players[playerConnect.id].connected = true;
+        if (game.players[playerConnect.playerId]) {
+            game.players[playerConnect.playerId].connected = true;
+            handOffHost(game);
         }
     } else if (serverEvent.playerDisconnect) {
         let playerDisconnect = serverEvent.playerDisconnect;
-        if (game.players[playerDisconnect.id]) {
-            game.players[playerDisconnect.id].connected = false;
+        if (game.players[playerDisconnect.playerId]) {
+            game.players[playerDisconnect.playerId].connected = false;
+            handOffHost(game);
         }
     } else if (serverEvent.playerRename) {
         let playerRename = serverEvent.playerRename;
-        if (game.players[playerRename.id]) {
-            game.players[playerRename.id].name = playerRename.name;
-        }
-    } else if (serverEvent.playerIncreaseScore) {
-        let increaseScore = serverEvent.playerIncreaseScore;
-        if (game.players[increaseScore.id]) {
-            game.players[increaseScore.id].score += increaseScore.increaseBy;
-        }
-    } else if (serverEvent.playerIncreaseGuesserScore) {
-        let increaseScore = serverEvent.playerIncreaseGuesserScore;
-        if (game.players[increaseScore.id]) {
-            game.players[increaseScore.id].guesserScore +=
-                increaseScore.increaseBy;
-        }
-    } else if (serverEvent.playerIncreaseDrawerScore) {
-        let increaseScore = serverEvent.playerIncreaseDrawerScore;
-        if (game.players[increaseScore.id]) {
-            game.players[increaseScore.id].drawerScore +=
-                increaseScore.increaseBy;
+        if (game.players[playerRename.playerId]) {
+            game.players[playerRename.playerId].name = playerRename.name;
         }
     } else if (serverEvent.playerDrawOp) {
-        let drawOperation = serverEvent.playerDrawOp;
-        if (game.round?.drawer == drawOperation.id) {
-            game.round?.drawing.push(drawOperation.drawOp!);
+        let playerDrawOp = serverEvent.playerDrawOp;
+        if (game.round?.phase === Phase.PLAY && playerDrawOp.drawOp) {
+            drawOpsOf(game.round, playerDrawOp.drawerId)?.push(
+                playerDrawOp.drawOp,
+            );
         }
     } else if (serverEvent.playerChooseWord) {
+        // the phase change follows in its own event
         let playerChooseWord = serverEvent.playerChooseWord;
-        if (game.round?.drawer == playerChooseWord.drawer) {
-            game.round!.wordChoice = playerChooseWord.choice;
-        }
-    } else if (serverEvent.playerGuessWord) {
-        let playerGuessWord = serverEvent.playerGuessWord;
-        if (game.round?.drawer != playerGuessWord.guesser) {
-            game.round!.guesses.push(playerGuessWord);
+        if (
+            game.round?.phase === Phase.CHOOSE_WORD &&
+            game.round.drawerId === playerChooseWord.drawerId
+        ) {
+            game.round.wordChoice = playerChooseWord.choice;
         }
     } else if (serverEvent.newRound) {
         let newRound = serverEvent.newRound;
-        if (newRound.roundId != game.round?.roundId) {
-            let round = game.round!;
-            round.roundId = newRound.roundId;
-            round.drawer = newRound.drawer;
-            round.easyWord = newRound.easyWord;
-            round.hardWord = newRound.hardWord;
-            round.phase = Phase.CHOOSE_WORD;
-            round.wordChoice = WordChoice.EASY;
-            round.drawing = [];
-            round.drawingScore = 0;
-            round.guessingScore = 0;
-            round.guesses = [];
-            round.hints = [];
+        if (newRound.roundId !== game.round?.roundId) {
+            game.round = Round.fromPartial({
+                roundId: newRound.roundId,
+                phase: newRound.startingPhase,
+                phaseEndsAt: newRound.phaseEndsAt,
+                drawerId: newRound.drawerId,
+                easyWord: newRound.easyWord,
+                hardWord: newRound.hardWord,
+                wordChoice: WordChoice.EASY,
+                rivalId: newRound.rivalId,
+            });
+            game.roundLikes.push(
+                RoundLikes.fromPartial({
+                    roundId: newRound.roundId,
+                    drawerId: newRound.drawerId,
+                    rivalId: newRound.rivalId,
+                }),
+            );
+            for (let player of Object.values(game.players)) {
+                player.roundScore = 0;
+            }
+            for (let drawerId of [newRound.drawerId, newRound.rivalId]) {
+                if (drawerId !== undefined && game.players[drawerId]) {
+                    game.players[drawerId].timesDrawn += 1;
+                }
+            }
+        }
+    } else if (serverEvent.playerKick) {
+        let playerKick = serverEvent.playerKick;
+        if (
+            isHost(game, playerKick.hostId) &&
+            playerKick.hostId !== playerKick.playerId
+        ) {
+            delete game.players[playerKick.playerId];
+        }
+    } else if (serverEvent.playerReady) {
+        // the server starts the first round once
+        // this leaves every connected player ready
+        let playerReady = serverEvent.playerReady;
+        let player = game.players[playerReady.playerId];
+        if (inLobby(game) && player && player.ready !== playerReady.ready) {
+            player.ready = playerReady.ready;
+            if (isGameOver(game) && allReady(game)) {
+                playAgain(game);
+            }
+        }
+    } else if (serverEvent.playerChangeSettings) {
+        let changeSettings = serverEvent.playerChangeSettings;
+        if (isHost(game, changeSettings.playerId) && inLobby(game)) {
+            game.settings = GameSettings.fromPartial(
+                changeSettings.settings ?? {},
+            );
+        }
+    } else if (serverEvent.spectators) {
+        game.spectators = serverEvent.spectators.spectators;
+    } else if (serverEvent.playerIncRoundScore) {
+        let incScore = serverEvent.playerIncRoundScore;
+        if (game.players[incScore.playerId]) {
+            game.players[incScore.playerId].roundScore += incScore.incBy;
+        }
+    } else if (serverEvent.playerIncDrawScore) {
+        let incScore = serverEvent.playerIncDrawScore;
+        if (game.players[incScore.drawerId]) {
+            game.players[incScore.drawerId].drawScore += incScore.incBy;
+        }
+    } else if (serverEvent.playerIncGuessScore) {
+        let incScore = serverEvent.playerIncGuessScore;
+        if (game.players[incScore.guesserId]) {
+            game.players[incScore.guesserId].guessScore += incScore.incBy;
+        }
+    } else if (serverEvent.roundIncDrawScore) {
+        if (game.round) {
+            game.round.drawScore += serverEvent.roundIncDrawScore.incBy;
+        }
+    } else if (serverEvent.roundIncGuessScore) {
+        if (game.round) {
+            game.round.guessScore += serverEvent.roundIncGuessScore.incBy;
+        }
+    } else if (serverEvent.roundChangePhase) {
+        let roundChangePhase = serverEvent.roundChangePhase;
+        if (game.round) {
+            game.round.phase = roundChangePhase.phase;
+            game.round.phaseEndsAt = roundChangePhase.phaseEndsAt;
+        }
+    } else if (serverEvent.roundSkip) {
+        if (game.round) {
+            game.round.phase = Phase.POST_PLAY;
+            game.round.phaseEndsAt = serverEvent.roundSkip.phaseEndsAt;
+        }
+    } else if (serverEvent.roundHint) {
+        if (game.round && serverEvent.roundHint.hint) {
+            game.round.hints.push(serverEvent.roundHint.hint);
+        }
+    } else if (serverEvent.roundGuess) {
+        if (game.round && serverEvent.roundGuess.guess) {
+            game.round.guesses.push(serverEvent.roundGuess.guess);
+        }
+    } else if (serverEvent.roundRevealWords) {
+        let revealWords = serverEvent.roundRevealWords;
+        if (game.round) {
+            game.round.easyWord = revealWords.easyWord;
+            game.round.hardWord = revealWords.hardWord;
+        }
+    } else if (serverEvent.roundVote) {
+        if (game.round && serverEvent.roundVote.vote) {
+            game.round.votes.push(serverEvent.roundVote.vote);
+        }
+    } else if (serverEvent.roundLike) {
+        // the bonus follows in its own score events
+        let roundLike = serverEvent.roundLike;
+        let roundLikes = game.roundLikes.find(
+            (roundLikes) => roundLikes.roundId === roundLike.roundId,
+        );
+        roundLikes?.likerIds.push(roundLike.playerId);
+    } else if (serverEvent.roomChat) {
+        if (serverEvent.roomChat.message) {
+            let overflow = game.chat.length + 1 - MAX_CHAT_HISTORY;
+            if (overflow > 0) {
+                game.chat.splice(0, overflow);
+            }
+            game.chat.push(serverEvent.roomChat.message);
+        }
+    } else if (serverEvent.gameOver) {
+        if (game.round) {
+            game.round.phase = Phase.GAME_OVER;
+            unreadyAll(game);
+        }
+    } else if (serverEvent.playerPlayAgain) {
+        let playerPlayAgain = serverEvent.playerPlayAgain;
+        if (isHost(game, playerPlayAgain.playerId) && isGameOver(game)) {
+            playAgain(game);
+        }
+    } else if (serverEvent.playerStartMatch) {
+        // the server starts the first round right after this
+        let playerStartMatch = serverEvent.playerStartMatch;
+        if (isHost(game, playerStartMatch.playerId) && isGameOver(game)) {
+            playAgain(game);
+        }
+    } else if (serverEvent.playerEndGame) {
+        // the server sends game over instead, but
+        // this ends up the same if it's applied
+        let playerEndGame = serverEvent.playerEndGame;
+        if (
+            isHost(game, playerEndGame.playerId) &&
+            game.round &&
+            !isGameOver(game)
+        ) {
+            game.round.phase = Phase.GAME_OVER;
+            unreadyAll(game);
         }
+    } else if (
+        serverEvent.error ||
+        serverEvent.playerDrawOpReject ||
+        serverEvent.playerGuessClose ||
+        serverEvent.voteResult ||
+        serverEvent.playerChatReject
+    ) {
+        // no-op, only the ui cares about these
     } else {
         throw new Error('unimplemented server event type');
     }
@@ -9,17 +9,67 @@ import { BinaryReader, BinaryWriter } from "@bufbuild/protobuf/wire";
 
 export const protobufPackage = "generated";
 
+export enum ScoringPolicyType {
+  CLASSIC = 0,
+  LINEAR_DECAY = 1,
+  DRAWER_PER_GUESS = 2,
+  RANKED = 3,
+  UNRECOGNIZED = -1,
+}
+
+export function scoringPolicyTypeFromJSON(object: any): ScoringPolicyType {
+  switch (object) {
+    case 0:
+    case "CLASSIC":
+      return ScoringPolicyType.CLASSIC;
+    case 1:
+    case "LINEAR_DECAY":
+      return ScoringPolicyType.LINEAR_DECAY;
+    case 2:
+    case "DRAWER_PER_GUESS":
+      return ScoringPolicyType.DRAWER_PER_GUESS;
+    case 3:
+    case "RANKED":
+      return ScoringPolicyType.RANKED;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ScoringPolicyType.UNRECOGNIZED;
+  }
+}
+
+export function scoringPolicyTypeToJSON(object: ScoringPolicyType): string {
+  switch (object) {
+    case ScoringPolicyType.CLASSIC:
+      return "CLASSIC";
+    case ScoringPolicyType.LINEAR_DECAY:
+      return "LINEAR_DECAY";
+    case ScoringPolicyType.DRAWER_PER_GUESS:
+      return "DRAWER_PER_GUESS";
+    case ScoringPolicyType.RANKED:
+      return "RANKED";
+    case ScoringPolicyType.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 /**
  * choose word 10 secs
  * pre play 5 secs
  * play 45 - 60 secs
+ * vote 10 secs, only after duel rounds since solo
+ * rounds have just the one drawing to vote for
  * post play 5 secs
+ * game over until someone plays again
  */
 export enum Phase {
   CHOOSE_WORD = 0,
   PRE_PLAY = 1,
   PLAY = 2,
   POST_PLAY = 3,
+  GAME_OVER = 4,
+  VOTE = 5,
   UNRECOGNIZED = -1,
 }
 
@@ -37,6 +87,12 @@ export function phaseFromJSON(object: any): Phase {
     case 3:
     case "POST_PLAY":
       return Phase.POST_PLAY;
+    case 4:
+    case "GAME_OVER":
+      return Phase.GAME_OVER;
+    case 5:
+    case "VOTE":
+      return Phase.VOTE;
     case -1:
     case "UNRECOGNIZED":
     default:
@@ -54,6 +110,10 @@ export function phaseToJSON(object: Phase): string {
       return "PLAY";
     case Phase.POST_PLAY:
       return "POST_PLAY";
+    case Phase.GAME_OVER:
+      return "GAME_OVER";
+    case Phase.VOTE:
+      return "VOTE";
     case Phase.UNRECOGNIZED:
     default:
       return "UNRECOGNIZED";
@@ -130,6 +190,10 @@ export enum SeErrorType {
   UNKNOWN = 0,
   ALREADY_CONNECTED = 1,
   FULL_GAME = 2,
+  NAME_TOO_SHORT = 3,
+  NAME_TOO_LONG = 4,
+  NAME_BLOCKED = 5,
+  KICKED = 6,
   UNRECOGNIZED = -1,
 }
 
@@ -144,6 +208,18 @@ export function seErrorTypeFromJSON(object: any): SeErrorType {
     case 2:
     case "FULL_GAME":
       return SeErrorType.FULL_GAME;
+    case 3:
+    case "NAME_TOO_SHORT":
+      return SeErrorType.NAME_TOO_SHORT;
+    case 4:
+    case "NAME_TOO_LONG":
+      return SeErrorType.NAME_TOO_LONG;
+    case 5:
+    case "NAME_BLOCKED":
+      return SeErrorType.NAME_BLOCKED;
+    case 6:
+    case "KICKED":
+      return SeErrorType.KICKED;
     case -1:
     case "UNRECOGNIZED":
     default:
@@ -159,15 +235,145 @@ export function seErrorTypeToJSON(object: SeErrorType): string {
       return "ALREADY_CONNECTED";
     case SeErrorType.FULL_GAME:
       return "FULL_GAME";
+    case SeErrorType.NAME_TOO_SHORT:
+      return "NAME_TOO_SHORT";
+    case SeErrorType.NAME_TOO_LONG:
+      return "NAME_TOO_LONG";
+    case SeErrorType.NAME_BLOCKED:
+      return "NAME_BLOCKED";
+    case SeErrorType.KICKED:
+      return "KICKED";
     case SeErrorType.UNRECOGNIZED:
     default:
       return "UNRECOGNIZED";
   }
 }
 
+export enum SkipReason {
+  INACTIVE_DRAWER = 0,
+  UNRECOGNIZED = -1,
+}
+
+export function skipReasonFromJSON(object: any): SkipReason {
+  switch (object) {
+    case 0:
+    case "INACTIVE_DRAWER":
+      return SkipReason.INACTIVE_DRAWER;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return SkipReason.UNRECOGNIZED;
+  }
+}
+
+export function skipReasonToJSON(object: SkipReason): string {
+  switch (object) {
+    case SkipReason.INACTIVE_DRAWER:
+      return "INACTIVE_DRAWER";
+    case SkipReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export enum ChatRejectReason {
+  ANSWER_IN_CHAT = 0,
+  DRAWER_TYPED_WORD = 1,
+  UNRECOGNIZED = -1,
+}
+
+export function chatRejectReasonFromJSON(object: any): ChatRejectReason {
+  switch (object) {
+    case 0:
+    case "ANSWER_IN_CHAT":
+      return ChatRejectReason.ANSWER_IN_CHAT;
+    case 1:
+    case "DRAWER_TYPED_WORD":
+      return ChatRejectReason.DRAWER_TYPED_WORD;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ChatRejectReason.UNRECOGNIZED;
+  }
+}
+
+export function chatRejectReasonToJSON(object: ChatRejectReason): string {
+  switch (object) {
+    case ChatRejectReason.ANSWER_IN_CHAT:
+      return "ANSWER_IN_CHAT";
+    case ChatRejectReason.DRAWER_TYPED_WORD:
+      return "DRAWER_TYPED_WORD";
+    case ChatRejectReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
+export enum DrawOpRejectReason {
+  INVALID_POINT = 0,
+  INVALID_COLOR = 1,
+  EMPTY_OP = 2,
+  TOO_MANY_OPS = 3,
+  TOO_FAST = 4,
+  TOO_MANY_POINTS = 5,
+  UNRECOGNIZED = -1,
+}
+
+export function drawOpRejectReasonFromJSON(object: any): DrawOpRejectReason {
+  switch (object) {
+    case 0:
+    case "INVALID_POINT":
+      return DrawOpRejectReason.INVALID_POINT;
+    case 1:
+    case "INVALID_COLOR":
+      return DrawOpRejectReason.INVALID_COLOR;
+    case 2:
+    case "EMPTY_OP":
+      return DrawOpRejectReason.EMPTY_OP;
+    case 3:
+    case "TOO_MANY_OPS":
+      return DrawOpRejectReason.TOO_MANY_OPS;
+    case 4:
+    case "TOO_FAST":
+      return DrawOpRejectReason.TOO_FAST;
+    case 5:
+    case "TOO_MANY_POINTS":
+      return DrawOpRejectReason.TOO_MANY_POINTS;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return DrawOpRejectReason.UNRECOGNIZED;
+  }
+}
+
+export function drawOpRejectReasonToJSON(object: DrawOpRejectReason): string {
+  switch (object) {
+    case DrawOpRejectReason.INVALID_POINT:
+      return "INVALID_POINT";
+    case DrawOpRejectReason.INVALID_COLOR:
+      return "INVALID_COLOR";
+    case DrawOpRejectReason.EMPTY_OP:
+      return "EMPTY_OP";
+    case DrawOpRejectReason.TOO_MANY_OPS:
+      return "TOO_MANY_OPS";
+    case DrawOpRejectReason.TOO_FAST:
+      return "TOO_FAST";
+    case DrawOpRejectReason.TOO_MANY_POINTS:
+      return "TOO_MANY_POINTS";
+    case DrawOpRejectReason.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 export interface Game {
   players: { [key: number]: Player };
   round?: Round | undefined;
+  settings: GameSettings | undefined;
+  roundLikes: RoundLikes[];
+  hostId?: number | undefined;
+  spectators: number;
+  chat: ChatMessage[];
 }
 
 export interface Game_PlayersEntry {
@@ -175,14 +381,70 @@ export interface Game_PlayersEntry {
   value: Player | undefined;
 }
 
+/**
+ * separate from guesses, never scored and
+ * open in every phase, oldest first
+ */
+export interface ChatMessage {
+  playerId: number;
+  text: string;
+}
+
+/**
+ * per room, the host can change these in the lobby,
+ * durations are in secs, a hint interval of 0 turns
+ * hints off, rounds is how many times every player
+ * draws before the match is over, and like bonus is
+ * what the drawer scores per like, 0 turns it off,
+ * duel gives every round a 2nd drawer, the rival, who
+ * draws the same word on their own canvas, vote bonus
+ * is what the drawer with the best drawing scores
+ */
+export interface GameSettings {
+  scoringPolicy: ScoringPolicyType;
+  chooseWordSecs: number;
+  prePlaySecs: number;
+  playEasySecs: number;
+  playHardSecs: number;
+  inactiveDrawerSecs: number;
+  postPlaySecs: number;
+  hintIntervalSecs: number;
+  rounds: number;
+  maxPlayers: number;
+  baseScore: number;
+  firstCorrectBonus: number;
+  easyMultipler: number;
+  hardMultipler: number;
+  likeBonus: number;
+  duel: boolean;
+  voteSecs: number;
+  voteBonus: number;
+}
+
+/**
+ * one per round in the current match
+ */
+export interface RoundLikes {
+  roundId: number;
+  drawerId: number;
+  likerIds: number[];
+  rivalId?: number | undefined;
+}
+
 export interface Player {
   name: string;
   drawScore: number;
   guessScore: number;
   roundScore: number;
   connected: boolean;
+  timesDrawn: number;
+  ready: boolean;
 }
 
+/**
+ * in duel rounds the rival's draw ops go in their
+ * own stream, the drawer still picks the word
+ */
 export interface Round {
   roundId: number;
   phase: Phase;
@@ -196,17 +458,52 @@ export interface Round {
   guessScore: number;
   guesses: Guess[];
   hints: Hint[];
+  rivalId?: number | undefined;
+  rivalDrawOps: DrawOp[];
+  votes: Vote[];
+}
+
+/**
+ * guessers pick the drawer with the best drawing
+ */
+export interface Vote {
+  voterId: number;
+  drawerId: number;
+}
+
+/**
+ * players with the same place tied
+ */
+export interface Standing {
+  playerId: number;
+  place: number;
+  score: number;
+  drawScore: number;
+  guessScore: number;
 }
 
+/**
+ * in duel rounds watching is the drawer whose canvas
+ * the guesser had up, their draw ops are the ones
+ * after draw ops counts, unset means both canvases
+ */
 export interface Guess {
   guesserId: number;
   incorrectGuess?: IncorrectGuess | undefined;
   correctGuess?: CorrectGuess | undefined;
   afterDrawOps: number;
+  watchingId?: number | undefined;
 }
 
+/**
+ * close guesses were a typo or two from the word, and
+ * guesses after solving come from players who already
+ * know it, neither is shown to players still guessing
+ */
 export interface IncorrectGuess {
   guess: string;
+  close: boolean;
+  afterSolving: boolean;
 }
 
 export interface CorrectGuess {
@@ -218,13 +515,24 @@ export interface Hint {
   afterDrawOps: number;
 }
 
+/**
+ * hints carry everything needed to render
+ * them since guessers never see the word
+ */
 export interface RevealLength {
+  wordLengths: number[];
 }
 
 export interface RevealLetter {
   index: number;
+  letter: string;
 }
 
+/**
+ * x & y are normalized, 0 is the left or top
+ * edge of the canvas and 1 is the right or bottom,
+ * colors are hex like #rrggbb
+ */
 export interface DrawOp {
   setColor?: DoSetColor | undefined;
   startStroke?: DoStartStroke | undefined;
@@ -232,6 +540,7 @@ export interface DrawOp {
   clearScreen?: DoClearScreen | undefined;
   undo?: DoUndo | undefined;
   redo?: DoRedo | undefined;
+  strokePoints?: DoStrokePoints | undefined;
 }
 
 export interface DoSetColor {
@@ -250,6 +559,21 @@ export interface DoContinueStroke {
   y: number;
 }
 
+/**
+ * a batch of stroke points quantized to a grid, the
+ * first point is absolute and every point after it is
+ * a dx, dy pair of deltas from the point before it,
+ * starts a new stroke if start is set, otherwise it
+ * continues the current stroke
+ */
+export interface DoStrokePoints {
+  start: boolean;
+  colorType: ColorType;
+  x: number;
+  y: number;
+  deltas: number[];
+}
+
 export interface DoClearScreen {
 }
 
@@ -259,9 +583,14 @@ export interface DoUndo {
 export interface DoRedo {
 }
 
+/**
+ * spectators get an id that isn't in the game's
+ * players, and a view with the words hidden
+ */
 export interface SeSetGame {
   playerId: number;
   game: Game | undefined;
+  spectating: boolean;
 }
 
 export interface SeError {
@@ -334,6 +663,7 @@ export interface SeRoundNew {
   hardWord: number;
   startingPhase: Phase;
   phaseEndsAt: number;
+  rivalId?: number | undefined;
 }
 
 export interface SeRoundChangePhase {
@@ -341,6 +671,40 @@ export interface SeRoundChangePhase {
   phaseEndsAt: number;
 }
 
+/**
+ * round_id not necessary as it always
+ * refers to the current round
+ */
+export interface SeRoundHint {
+  hint: Hint | undefined;
+}
+
+/**
+ * round_id not necessary as it always
+ * refers to the current round
+ */
+export interface SeRoundGuess {
+  guess: Guess | undefined;
+}
+
+/**
+ * sent to guessers once they're allowed to
+ * know the word, e.g. after guessing it
+ */
+export interface SeRoundRevealWords {
+  easyWord: number;
+  hardWord: number;
+}
+
+/**
+ * ends the round early, moving it
+ * straight to the post play phase
+ */
+export interface SeRoundSkip {
+  reason: SkipReason;
+  phaseEndsAt: number;
+}
+
 export interface SePlayerChooseWord {
   drawerId: number;
   choice: WordChoice;
@@ -350,6 +714,120 @@ export interface SePlayerGuessWord {
   guesserId: number;
   guess: string;
   afterDrawOps: number;
+  watchingId?: number | undefined;
+}
+
+/**
+ * only sent to the guesser, lets them know
+ * they're a typo or two away from the word
+ */
+export interface SePlayerGuessClose {
+  guesserId: number;
+}
+
+/**
+ * a duel's like goes to both drawers, so
+ * the rival is set if it was a duel round
+ */
+export interface MostLiked {
+  roundId: number;
+  drawerId: number;
+  likes: number;
+  rivalId?: number | undefined;
+}
+
+/**
+ * last round of the match is over, best player first,
+ * most liked is unset if nobody liked any drawing
+ */
+export interface SeGameOver {
+  standings: Standing[];
+  mostLiked?: MostLiked | undefined;
+}
+
+export interface SePlayerPlayAgain {
+  playerId: number;
+}
+
+export interface SePlayerStartMatch {
+  playerId: number;
+}
+
+/**
+ * players ready up in the lobby, the match starts
+ * on its own once every connected player is ready
+ */
+export interface SePlayerReady {
+  playerId: number;
+  ready: boolean;
+}
+
+/**
+ * spectators watch without joining the players,
+ * only how many of them there are is shared
+ */
+export interface SeSpectators {
+  spectators: number;
+}
+
+export interface SePlayerVote {
+  voterId: number;
+  drawerId: number;
+}
+
+export interface SeRoundVote {
+  vote: Vote | undefined;
+}
+
+/**
+ * the drawers with the most votes split the vote
+ * bonus, nobody wins if nobody voted, the bonus
+ * follows in its own score events
+ */
+export interface SeVoteResult {
+  winnerIds: number[];
+  incBy: number;
+}
+
+export interface SePlayerChat {
+  playerId: number;
+  text: string;
+}
+
+export interface SeRoomChat {
+  message: ChatMessage | undefined;
+}
+
+/**
+ * only sent to the player, nobody else saw
+ * their message since it gave away the word
+ */
+export interface SePlayerChatReject {
+  playerId: number;
+  reason: ChatRejectReason;
+}
+
+export interface SePlayerEndGame {
+  playerId: number;
+}
+
+export interface SePlayerKick {
+  hostId: number;
+  playerId: number;
+}
+
+export interface SePlayerChangeSettings {
+  playerId: number;
+  settings: GameSettings | undefined;
+}
+
+/**
+ * only sent to the drawer, their draw op
+ * wasn't applied and nobody else saw it
+ */
+export interface SePlayerDrawOpReject {
+  drawerId: number;
+  reason: DrawOpRejectReason;
 }
 
 export interface SePlayerLikeRound {
@@ -357,6 +835,15 @@ export interface SePlayerLikeRound {
   roundId: number;
 }
 
+/**
+ * the like as the server recorded it, the drawers'
+ * bonus follows in its own score events
+ */
+export interface SeRoundLike {
+  playerId: number;
+  roundId: number;
+}
+
 export interface ServerEvent {
   playerJoin?: SePlayerJoin | undefined;
   playerLeave?: SePlayerLeave | undefined;
@@ -376,6 +863,27 @@ export interface ServerEvent {
   roundIncDrawScore?: SeRoundIncDrawScore | undefined;
   roundIncGuessScore?: SeRoundIncGuessScore | undefined;
   roundChangePhase?: SeRoundChangePhase | undefined;
+  roundSkip?: SeRoundSkip | undefined;
+  roundHint?: SeRoundHint | undefined;
+  roundGuess?: SeRoundGuess | undefined;
+  roundRevealWords?: SeRoundRevealWords | undefined;
+  playerGuessClose?: SePlayerGuessClose | undefined;
+  playerChangeSettings?: SePlayerChangeSettings | undefined;
+  gameOver?: SeGameOver | undefined;
+  playerPlayAgain?: SePlayerPlayAgain | undefined;
+  playerDrawOpReject?: SePlayerDrawOpReject | undefined;
+  playerStartMatch?: SePlayerStartMatch | undefined;
+  playerEndGame?: SePlayerEndGame | undefined;
+  playerKick?: SePlayerKick | undefined;
+  playerReady?: SePlayerReady | undefined;
+  spectators?: SeSpectators | undefined;
+  playerVote?: SePlayerVote | undefined;
+  roundVote?: SeRoundVote | undefined;
+  voteResult?: SeVoteResult | undefined;
+  playerChat?: SePlayerChat | undefined;
+  roomChat?: SeRoomChat | undefined;
+  playerChatReject?: SePlayerChatReject | undefined;
+  roundLike?: SeRoundLike | undefined;
 }
 
 export interface ServerEvents {
@@ -393,6 +901,7 @@ export interface CeChooseWord {
 export interface CeGuessWord {
   guess: string;
   afterDrawOps: number;
+  watchingId?: number | undefined;
 }
 
 export interface CeLikeRound {
@@ -403,16 +912,61 @@ export interface CeDrawOp {
   drawOp: DrawOp | undefined;
 }
 
+export interface CeChangeSettings {
+  settings: GameSettings | undefined;
+}
+
+export interface CePlayAgain {
+}
+
+export interface CeStartMatch {
+}
+
+export interface CeEndGame {
+}
+
+export interface CeKickPlayer {
+  playerId: number;
+}
+
+export interface CeReady {
+  ready: boolean;
+}
+
+export interface CeVote {
+  drawerId: number;
+}
+
+export interface CeChat {
+  text: string;
+}
+
 export interface ClientEvent {
   rename?: CeRename | undefined;
   chooseWord?: CeChooseWord | undefined;
   guessWord?: CeGuessWord | undefined;
   likeRound?: CeLikeRound | undefined;
   drawOp?: CeDrawOp | undefined;
+  changeSettings?: CeChangeSettings | undefined;
+  playAgain?: CePlayAgain | undefined;
+  startMatch?: CeStartMatch | undefined;
+  endGame?: CeEndGame | undefined;
+  kickPlayer?: CeKickPlayer | undefined;
+  ready?: CeReady | undefined;
+  vote?: CeVote | undefined;
+  chat?: CeChat | undefined;
 }
 
 function createBaseGame(): Game {
-  return { players: {}, round: undefined };
+  return {
+    players: {},
+    round: undefined,
+    settings: undefined,
+    roundLikes: [],
+    hostId: undefined,
+    spectators: 0,
+    chat: [],
+  };
 }
 
 export const Game: MessageFns<Game> = {
@@ -423,6 +977,21 @@ export const Game: MessageFns<Game> = {
     if (message.round !== undefined) {
       Round.encode(message.round, writer.uint32(18).fork()).join();
     }
+    if (message.settings !== undefined) {
+      GameSettings.encode(message.settings, writer.uint32(26).fork()).join();
+    }
+    for (const v of message.roundLikes) {
+      RoundLikes.encode(v!, writer.uint32(34).fork()).join();
+    }
+    if (message.hostId !== undefined) {
+      writer.uint32(40).uint32(message.hostId);
+    }
+    if (message.spectators !== 0) {
+      writer.uint32(48).uint32(message.spectators);
+    }
+    for (const v of message.chat) {
+      ChatMessage.encode(v!, writer.uint32(58).fork()).join();
+    }
     return writer;
   },
 
@@ -452,24 +1021,71 @@ export const Game: MessageFns<Game> = {
           message.round = Round.decode(reader, reader.uint32());
           continue;
         }
-      }
-      if ((tag & 7) === 4 || tag === 0) {
-        break;
-      }
-      reader.skip(tag & 7);
-    }
-    return message;
-  },
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
 
-  fromJSON(object: any): Game {
-    return {
-      players: isObject(object.players)
-        ? Object.entries(object.players).reduce<{ [key: number]: Player }>((acc, [key, value]) => {
+          message.settings = GameSettings.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.roundLikes.push(RoundLikes.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 5: {
+          if (tag !== 40) {
+            break;
+          }
+
+          message.hostId = reader.uint32();
+          continue;
+        }
+        case 6: {
+          if (tag !== 48) {
+            break;
+          }
+
+          message.spectators = reader.uint32();
+          continue;
+        }
+        case 7: {
+          if (tag !== 58) {
+            break;
+          }
+
+          message.chat.push(ChatMessage.decode(reader, reader.uint32()));
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): Game {
+    return {
+      players: isObject(object.players)
+        ? Object.entries(object.players).reduce<{ [key: number]: Player }>((acc, [key, value]) => {
           acc[globalThis.Number(key)] = Player.fromJSON(value);
           return acc;
         }, {})
         : {},
       round: isSet(object.round) ? Round.fromJSON(object.round) : undefined,
+      settings: isSet(object.settings) ? GameSettings.fromJSON(object.settings) : undefined,
+      roundLikes: globalThis.Array.isArray(object?.roundLikes)
+        ? object.roundLikes.map((e: any) => RoundLikes.fromJSON(e))
+        : [],
+      hostId: isSet(object.hostId) ? globalThis.Number(object.hostId) : undefined,
+      spectators: isSet(object.spectators) ? globalThis.Number(object.spectators) : 0,
+      chat: globalThis.Array.isArray(object?.chat) ? object.chat.map((e: any) => ChatMessage.fromJSON(e)) : [],
     };
   },
 
@@ -487,6 +1103,21 @@ export const Game: MessageFns<Game> = {
     if (message.round !== undefined) {
       obj.round = Round.toJSON(message.round);
     }
+    if (message.settings !== undefined) {
+      obj.settings = GameSettings.toJSON(message.settings);
+    }
+    if (message.roundLikes?.length) {
+      obj.roundLikes = message.roundLikes.map((e) => RoundLikes.toJSON(e));
+    }
+    if (message.hostId !== undefined) {
+      obj.hostId = Math.round(message.hostId);
+    }
+    if (message.spectators !== 0) {
+      obj.spectators = Math.round(message.spectators);
+    }
+    if (message.chat?.length) {
+      obj.chat = message.chat.map((e) => ChatMessage.toJSON(e));
+    }
     return obj;
   },
 
@@ -502,6 +1133,13 @@ export const Game: MessageFns<Game> = {
       return acc;
     }, {});
     message.round = (object.round !== undefined && object.round !== null) ? Round.fromPartial(object.round) : undefined;
+    message.settings = (object.settings !== undefined && object.settings !== null)
+      ? GameSettings.fromPartial(object.settings)
+      : undefined;
+    message.roundLikes = object.roundLikes?.map((e) => RoundLikes.fromPartial(e)) || [];
+    message.hostId = object.hostId ?? undefined;
+    message.spectators = object.spectators ?? 0;
+    message.chat = object.chat?.map((e) => ChatMessage.fromPartial(e)) || [];
     return message;
   },
 };
@@ -584,75 +1222,42 @@ export const Game_PlayersEntry: MessageFns<Game_PlayersEntry> = {
   },
 };
 
-function createBasePlayer(): Player {
-  return { name: "", drawScore: 0, guessScore: 0, roundScore: 0, connected: false };
+function createBaseChatMessage(): ChatMessage {
+  return { playerId: 0, text: "" };
 }
 
-export const Player: MessageFns<Player> = {
-  encode(message: Player, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.name !== "") {
-      writer.uint32(10).string(message.name);
-    }
-    if (message.drawScore !== 0) {
-      writer.uint32(24).uint32(message.drawScore);
-    }
-    if (message.guessScore !== 0) {
-      writer.uint32(32).uint32(message.guessScore);
-    }
-    if (message.roundScore !== 0) {
-      writer.uint32(40).uint32(message.roundScore);
+export const ChatMessage: MessageFns<ChatMessage> = {
+  encode(message: ChatMessage, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
-    if (message.connected !== false) {
-      writer.uint32(48).bool(message.connected);
+    if (message.text !== "") {
+      writer.uint32(18).string(message.text);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): Player {
+  decode(input: BinaryReader | Uint8Array, length?: number): ChatMessage {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBasePlayer();
+    const message = createBaseChatMessage();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 10) {
-            break;
-          }
-
-          message.name = reader.string();
-          continue;
-        }
-        case 3: {
-          if (tag !== 24) {
-            break;
-          }
-
-          message.drawScore = reader.uint32();
-          continue;
-        }
-        case 4: {
-          if (tag !== 32) {
-            break;
-          }
-
-          message.guessScore = reader.uint32();
-          continue;
-        }
-        case 5: {
-          if (tag !== 40) {
+          if (tag !== 8) {
             break;
           }
 
-          message.roundScore = reader.uint32();
+          message.playerId = reader.uint32();
           continue;
         }
-        case 6: {
-          if (tag !== 48) {
+        case 2: {
+          if (tag !== 18) {
             break;
           }
 
-          message.connected = reader.bool();
+          message.text = reader.string();
           continue;
         }
       }
@@ -664,112 +1269,121 @@ export const Player: MessageFns<Player> = {
     return message;
   },
 
-  fromJSON(object: any): Player {
+  fromJSON(object: any): ChatMessage {
     return {
-      name: isSet(object.name) ? globalThis.String(object.name) : "",
-      drawScore: isSet(object.drawScore) ? globalThis.Number(object.drawScore) : 0,
-      guessScore: isSet(object.guessScore) ? globalThis.Number(object.guessScore) : 0,
-      roundScore: isSet(object.roundScore) ? globalThis.Number(object.roundScore) : 0,
-      connected: isSet(object.connected) ? globalThis.Boolean(object.connected) : false,
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      text: isSet(object.text) ? globalThis.String(object.text) : "",
     };
   },
 
-  toJSON(message: Player): unknown {
+  toJSON(message: ChatMessage): unknown {
     const obj: any = {};
-    if (message.name !== "") {
-      obj.name = message.name;
-    }
-    if (message.drawScore !== 0) {
-      obj.drawScore = Math.round(message.drawScore);
-    }
-    if (message.guessScore !== 0) {
-      obj.guessScore = Math.round(message.guessScore);
-    }
-    if (message.roundScore !== 0) {
-      obj.roundScore = Math.round(message.roundScore);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
-    if (message.connected !== false) {
-      obj.connected = message.connected;
+    if (message.text !== "") {
+      obj.text = message.text;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<Player>, I>>(base?: I): Player {
-    return Player.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<ChatMessage>, I>>(base?: I): ChatMessage {
+    return ChatMessage.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<Player>, I>>(object: I): Player {
-    const message = createBasePlayer();
-    message.name = object.name ?? "";
-    message.drawScore = object.drawScore ?? 0;
-    message.guessScore = object.guessScore ?? 0;
-    message.roundScore = object.roundScore ?? 0;
-    message.connected = object.connected ?? false;
+  fromPartial<I extends Exact<DeepPartial<ChatMessage>, I>>(object: I): ChatMessage {
+    const message = createBaseChatMessage();
+    message.playerId = object.playerId ?? 0;
+    message.text = object.text ?? "";
     return message;
   },
 };
 
-function createBaseRound(): Round {
+function createBaseGameSettings(): GameSettings {
   return {
-    roundId: 0,
-    phase: 0,
-    phaseEndsAt: 0,
-    drawerId: 0,
-    drawOps: [],
-    easyWord: 0,
-    hardWord: 0,
-    wordChoice: 0,
-    drawScore: 0,
-    guessScore: 0,
-    guesses: [],
-    hints: [],
+    scoringPolicy: 0,
+    chooseWordSecs: 0,
+    prePlaySecs: 0,
+    playEasySecs: 0,
+    playHardSecs: 0,
+    inactiveDrawerSecs: 0,
+    postPlaySecs: 0,
+    hintIntervalSecs: 0,
+    rounds: 0,
+    maxPlayers: 0,
+    baseScore: 0,
+    firstCorrectBonus: 0,
+    easyMultipler: 0,
+    hardMultipler: 0,
+    likeBonus: 0,
+    duel: false,
+    voteSecs: 0,
+    voteBonus: 0,
   };
 }
 
-export const Round: MessageFns<Round> = {
-  encode(message: Round, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.roundId !== 0) {
-      writer.uint32(8).uint32(message.roundId);
+export const GameSettings: MessageFns<GameSettings> = {
+  encode(message: GameSettings, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.scoringPolicy !== 0) {
+      writer.uint32(8).int32(message.scoringPolicy);
     }
-    if (message.phase !== 0) {
-      writer.uint32(80).int32(message.phase);
+    if (message.chooseWordSecs !== 0) {
+      writer.uint32(16).uint32(message.chooseWordSecs);
     }
-    if (message.phaseEndsAt !== 0) {
-      writer.uint32(96).uint64(message.phaseEndsAt);
+    if (message.prePlaySecs !== 0) {
+      writer.uint32(24).uint32(message.prePlaySecs);
     }
-    if (message.drawerId !== 0) {
-      writer.uint32(16).uint32(message.drawerId);
+    if (message.playEasySecs !== 0) {
+      writer.uint32(32).uint32(message.playEasySecs);
     }
-    for (const v of message.drawOps) {
-      DrawOp.encode(v!, writer.uint32(26).fork()).join();
+    if (message.playHardSecs !== 0) {
+      writer.uint32(40).uint32(message.playHardSecs);
     }
-    if (message.easyWord !== 0) {
-      writer.uint32(32).uint32(message.easyWord);
+    if (message.inactiveDrawerSecs !== 0) {
+      writer.uint32(48).uint32(message.inactiveDrawerSecs);
     }
-    if (message.hardWord !== 0) {
-      writer.uint32(40).uint32(message.hardWord);
+    if (message.postPlaySecs !== 0) {
+      writer.uint32(56).uint32(message.postPlaySecs);
     }
-    if (message.wordChoice !== 0) {
-      writer.uint32(88).int32(message.wordChoice);
+    if (message.hintIntervalSecs !== 0) {
+      writer.uint32(64).uint32(message.hintIntervalSecs);
     }
-    if (message.drawScore !== 0) {
-      writer.uint32(48).uint32(message.drawScore);
+    if (message.rounds !== 0) {
+      writer.uint32(72).uint32(message.rounds);
     }
-    if (message.guessScore !== 0) {
-      writer.uint32(56).uint32(message.guessScore);
+    if (message.maxPlayers !== 0) {
+      writer.uint32(80).uint32(message.maxPlayers);
     }
-    for (const v of message.guesses) {
-      Guess.encode(v!, writer.uint32(66).fork()).join();
+    if (message.baseScore !== 0) {
+      writer.uint32(88).uint32(message.baseScore);
     }
-    for (const v of message.hints) {
-      Hint.encode(v!, writer.uint32(74).fork()).join();
+    if (message.firstCorrectBonus !== 0) {
+      writer.uint32(96).uint32(message.firstCorrectBonus);
+    }
+    if (message.easyMultipler !== 0) {
+      writer.uint32(104).uint32(message.easyMultipler);
+    }
+    if (message.hardMultipler !== 0) {
+      writer.uint32(112).uint32(message.hardMultipler);
+    }
+    if (message.likeBonus !== 0) {
+      writer.uint32(120).uint32(message.likeBonus);
+    }
+    if (message.duel !== false) {
+      writer.uint32(128).bool(message.duel);
+    }
+    if (message.voteSecs !== 0) {
+      writer.uint32(136).uint32(message.voteSecs);
+    }
+    if (message.voteBonus !== 0) {
+      writer.uint32(144).uint32(message.voteBonus);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): Round {
+  decode(input: BinaryReader | Uint8Array, length?: number): GameSettings {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseRound();
+    const message = createBaseGameSettings();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -778,55 +1392,79 @@ export const Round: MessageFns<Round> = {
             break;
           }
 
-          message.roundId = reader.uint32();
+          message.scoringPolicy = reader.int32() as any;
           continue;
         }
-        case 10: {
-          if (tag !== 80) {
+        case 2: {
+          if (tag !== 16) {
             break;
           }
 
-          message.phase = reader.int32() as any;
+          message.chooseWordSecs = reader.uint32();
           continue;
         }
-        case 12: {
-          if (tag !== 96) {
+        case 3: {
+          if (tag !== 24) {
             break;
           }
 
-          message.phaseEndsAt = longToNumber(reader.uint64());
+          message.prePlaySecs = reader.uint32();
           continue;
         }
-        case 2: {
-          if (tag !== 16) {
+        case 4: {
+          if (tag !== 32) {
             break;
           }
 
-          message.drawerId = reader.uint32();
+          message.playEasySecs = reader.uint32();
           continue;
         }
-        case 3: {
-          if (tag !== 26) {
+        case 5: {
+          if (tag !== 40) {
             break;
           }
 
-          message.drawOps.push(DrawOp.decode(reader, reader.uint32()));
+          message.playHardSecs = reader.uint32();
           continue;
         }
-        case 4: {
-          if (tag !== 32) {
+        case 6: {
+          if (tag !== 48) {
             break;
           }
 
-          message.easyWord = reader.uint32();
+          message.inactiveDrawerSecs = reader.uint32();
           continue;
         }
-        case 5: {
-          if (tag !== 40) {
+        case 7: {
+          if (tag !== 56) {
             break;
           }
 
-          message.hardWord = reader.uint32();
+          message.postPlaySecs = reader.uint32();
+          continue;
+        }
+        case 8: {
+          if (tag !== 64) {
+            break;
+          }
+
+          message.hintIntervalSecs = reader.uint32();
+          continue;
+        }
+        case 9: {
+          if (tag !== 72) {
+            break;
+          }
+
+          message.rounds = reader.uint32();
+          continue;
+        }
+        case 10: {
+          if (tag !== 80) {
+            break;
+          }
+
+          message.maxPlayers = reader.uint32();
           continue;
         }
         case 11: {
@@ -834,154 +1472,210 @@ export const Round: MessageFns<Round> = {
             break;
           }
 
-          message.wordChoice = reader.int32() as any;
+          message.baseScore = reader.uint32();
           continue;
         }
-        case 6: {
-          if (tag !== 48) {
+        case 12: {
+          if (tag !== 96) {
             break;
           }
 
-          message.drawScore = reader.uint32();
+          message.firstCorrectBonus = reader.uint32();
           continue;
         }
-        case 7: {
-          if (tag !== 56) {
+        case 13: {
+          if (tag !== 104) {
             break;
           }
 
-          message.guessScore = reader.uint32();
+          message.easyMultipler = reader.uint32();
           continue;
         }
-        case 8: {
-          if (tag !== 66) {
+        case 14: {
+          if (tag !== 112) {
             break;
           }
 
-          message.guesses.push(Guess.decode(reader, reader.uint32()));
+          message.hardMultipler = reader.uint32();
           continue;
         }
-        case 9: {
-          if (tag !== 74) {
+        case 15: {
+          if (tag !== 120) {
             break;
           }
 
-          message.hints.push(Hint.decode(reader, reader.uint32()));
+          message.likeBonus = reader.uint32();
           continue;
         }
-      }
-      if ((tag & 7) === 4 || tag === 0) {
-        break;
-      }
-      reader.skip(tag & 7);
-    }
+        case 16: {
+          if (tag !== 128) {
+            break;
+          }
+
+          message.duel = reader.bool();
+          continue;
+        }
+        case 17: {
+          if (tag !== 136) {
+            break;
+          }
+
+          message.voteSecs = reader.uint32();
+          continue;
+        }
+        case 18: {
+          if (tag !== 144) {
+            break;
+          }
+
+          message.voteBonus = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
     return message;
   },
 
-  fromJSON(object: any): Round {
+  fromJSON(object: any): GameSettings {
     return {
-      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
-      phase: isSet(object.phase) ? phaseFromJSON(object.phase) : 0,
-      phaseEndsAt: isSet(object.phaseEndsAt) ? globalThis.Number(object.phaseEndsAt) : 0,
-      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
-      drawOps: globalThis.Array.isArray(object?.drawOps) ? object.drawOps.map((e: any) => DrawOp.fromJSON(e)) : [],
-      easyWord: isSet(object.easyWord) ? globalThis.Number(object.easyWord) : 0,
-      hardWord: isSet(object.hardWord) ? globalThis.Number(object.hardWord) : 0,
-      wordChoice: isSet(object.wordChoice) ? wordChoiceFromJSON(object.wordChoice) : 0,
-      drawScore: isSet(object.drawScore) ? globalThis.Number(object.drawScore) : 0,
-      guessScore: isSet(object.guessScore) ? globalThis.Number(object.guessScore) : 0,
-      guesses: globalThis.Array.isArray(object?.guesses) ? object.guesses.map((e: any) => Guess.fromJSON(e)) : [],
-      hints: globalThis.Array.isArray(object?.hints) ? object.hints.map((e: any) => Hint.fromJSON(e)) : [],
+      scoringPolicy: isSet(object.scoringPolicy) ? scoringPolicyTypeFromJSON(object.scoringPolicy) : 0,
+      chooseWordSecs: isSet(object.chooseWordSecs) ? globalThis.Number(object.chooseWordSecs) : 0,
+      prePlaySecs: isSet(object.prePlaySecs) ? globalThis.Number(object.prePlaySecs) : 0,
+      playEasySecs: isSet(object.playEasySecs) ? globalThis.Number(object.playEasySecs) : 0,
+      playHardSecs: isSet(object.playHardSecs) ? globalThis.Number(object.playHardSecs) : 0,
+      inactiveDrawerSecs: isSet(object.inactiveDrawerSecs) ? globalThis.Number(object.inactiveDrawerSecs) : 0,
+      postPlaySecs: isSet(object.postPlaySecs) ? globalThis.Number(object.postPlaySecs) : 0,
+      hintIntervalSecs: isSet(object.hintIntervalSecs) ? globalThis.Number(object.hintIntervalSecs) : 0,
+      rounds: isSet(object.rounds) ? globalThis.Number(object.rounds) : 0,
+      maxPlayers: isSet(object.maxPlayers) ? globalThis.Number(object.maxPlayers) : 0,
+      baseScore: isSet(object.baseScore) ? globalThis.Number(object.baseScore) : 0,
+      firstCorrectBonus: isSet(object.firstCorrectBonus) ? globalThis.Number(object.firstCorrectBonus) : 0,
+      easyMultipler: isSet(object.easyMultipler) ? globalThis.Number(object.easyMultipler) : 0,
+      hardMultipler: isSet(object.hardMultipler) ? globalThis.Number(object.hardMultipler) : 0,
+      likeBonus: isSet(object.likeBonus) ? globalThis.Number(object.likeBonus) : 0,
+      duel: isSet(object.duel) ? globalThis.Boolean(object.duel) : false,
+      voteSecs: isSet(object.voteSecs) ? globalThis.Number(object.voteSecs) : 0,
+      voteBonus: isSet(object.voteBonus) ? globalThis.Number(object.voteBonus) : 0,
     };
   },
 
-  toJSON(message: Round): unknown {
+  toJSON(message: GameSettings): unknown {
     const obj: any = {};
-    if (message.roundId !== 0) {
-      obj.roundId = Math.round(message.roundId);
+    if (message.scoringPolicy !== 0) {
+      obj.scoringPolicy = scoringPolicyTypeToJSON(message.scoringPolicy);
     }
-    if (message.phase !== 0) {
-      obj.phase = phaseToJSON(message.phase);
+    if (message.chooseWordSecs !== 0) {
+      obj.chooseWordSecs = Math.round(message.chooseWordSecs);
     }
-    if (message.phaseEndsAt !== 0) {
-      obj.phaseEndsAt = Math.round(message.phaseEndsAt);
+    if (message.prePlaySecs !== 0) {
+      obj.prePlaySecs = Math.round(message.prePlaySecs);
     }
-    if (message.drawerId !== 0) {
-      obj.drawerId = Math.round(message.drawerId);
+    if (message.playEasySecs !== 0) {
+      obj.playEasySecs = Math.round(message.playEasySecs);
     }
-    if (message.drawOps?.length) {
-      obj.drawOps = message.drawOps.map((e) => DrawOp.toJSON(e));
+    if (message.playHardSecs !== 0) {
+      obj.playHardSecs = Math.round(message.playHardSecs);
     }
-    if (message.easyWord !== 0) {
-      obj.easyWord = Math.round(message.easyWord);
+    if (message.inactiveDrawerSecs !== 0) {
+      obj.inactiveDrawerSecs = Math.round(message.inactiveDrawerSecs);
     }
-    if (message.hardWord !== 0) {
-      obj.hardWord = Math.round(message.hardWord);
+    if (message.postPlaySecs !== 0) {
+      obj.postPlaySecs = Math.round(message.postPlaySecs);
     }
-    if (message.wordChoice !== 0) {
-      obj.wordChoice = wordChoiceToJSON(message.wordChoice);
+    if (message.hintIntervalSecs !== 0) {
+      obj.hintIntervalSecs = Math.round(message.hintIntervalSecs);
     }
-    if (message.drawScore !== 0) {
-      obj.drawScore = Math.round(message.drawScore);
+    if (message.rounds !== 0) {
+      obj.rounds = Math.round(message.rounds);
     }
-    if (message.guessScore !== 0) {
-      obj.guessScore = Math.round(message.guessScore);
+    if (message.maxPlayers !== 0) {
+      obj.maxPlayers = Math.round(message.maxPlayers);
     }
-    if (message.guesses?.length) {
-      obj.guesses = message.guesses.map((e) => Guess.toJSON(e));
+    if (message.baseScore !== 0) {
+      obj.baseScore = Math.round(message.baseScore);
     }
-    if (message.hints?.length) {
-      obj.hints = message.hints.map((e) => Hint.toJSON(e));
+    if (message.firstCorrectBonus !== 0) {
+      obj.firstCorrectBonus = Math.round(message.firstCorrectBonus);
+    }
+    if (message.easyMultipler !== 0) {
+      obj.easyMultipler = Math.round(message.easyMultipler);
+    }
+    if (message.hardMultipler !== 0) {
+      obj.hardMultipler = Math.round(message.hardMultipler);
+    }
+    if (message.likeBonus !== 0) {
+      obj.likeBonus = Math.round(message.likeBonus);
+    }
+    if (message.duel !== false) {
+      obj.duel = message.duel;
+    }
+    if (message.voteSecs !== 0) {
+      obj.voteSecs = Math.round(message.voteSecs);
+    }
+    if (message.voteBonus !== 0) {
+      obj.voteBonus = Math.round(message.voteBonus);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<Round>, I>>(base?: I): Round {
-    return Round.fromPartial(base ?? ({} as any));
-  },
-  fromPartial<I extends Exact<DeepPartial<Round>, I>>(object: I): Round {
-    const message = createBaseRound();
-    message.roundId = object.roundId ?? 0;
-    message.phase = object.phase ?? 0;
-    message.phaseEndsAt = object.phaseEndsAt ?? 0;
-    message.drawerId = object.drawerId ?? 0;
-    message.drawOps = object.drawOps?.map((e) => DrawOp.fromPartial(e)) || [];
-    message.easyWord = object.easyWord ?? 0;
-    message.hardWord = object.hardWord ?? 0;
-    message.wordChoice = object.wordChoice ?? 0;
-    message.drawScore = object.drawScore ?? 0;
-    message.guessScore = object.guessScore ?? 0;
-    message.guesses = object.guesses?.map((e) => Guess.fromPartial(e)) || [];
-    message.hints = object.hints?.map((e) => Hint.fromPartial(e)) || [];
+  create<I extends Exact<DeepPartial<GameSettings>, I>>(base?: I): GameSettings {
+    return GameSettings.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<GameSettings>, I>>(object: I): GameSettings {
+    const message = createBaseGameSettings();
+    message.scoringPolicy = object.scoringPolicy ?? 0;
+    message.chooseWordSecs = object.chooseWordSecs ?? 0;
+    message.prePlaySecs = object.prePlaySecs ?? 0;
+    message.playEasySecs = object.playEasySecs ?? 0;
+    message.playHardSecs = object.playHardSecs ?? 0;
+    message.inactiveDrawerSecs = object.inactiveDrawerSecs ?? 0;
+    message.postPlaySecs = object.postPlaySecs ?? 0;
+    message.hintIntervalSecs = object.hintIntervalSecs ?? 0;
+    message.rounds = object.rounds ?? 0;
+    message.maxPlayers = object.maxPlayers ?? 0;
+    message.baseScore = object.baseScore ?? 0;
+    message.firstCorrectBonus = object.firstCorrectBonus ?? 0;
+    message.easyMultipler = object.easyMultipler ?? 0;
+    message.hardMultipler = object.hardMultipler ?? 0;
+    message.likeBonus = object.likeBonus ?? 0;
+    message.duel = object.duel ?? false;
+    message.voteSecs = object.voteSecs ?? 0;
+    message.voteBonus = object.voteBonus ?? 0;
     return message;
   },
 };
 
-function createBaseGuess(): Guess {
-  return { guesserId: 0, incorrectGuess: undefined, correctGuess: undefined, afterDrawOps: 0 };
+function createBaseRoundLikes(): RoundLikes {
+  return { roundId: 0, drawerId: 0, likerIds: [], rivalId: undefined };
 }
 
-export const Guess: MessageFns<Guess> = {
-  encode(message: Guess, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.guesserId !== 0) {
-      writer.uint32(8).uint32(message.guesserId);
+export const RoundLikes: MessageFns<RoundLikes> = {
+  encode(message: RoundLikes, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.roundId !== 0) {
+      writer.uint32(8).uint32(message.roundId);
     }
-    if (message.incorrectGuess !== undefined) {
-      IncorrectGuess.encode(message.incorrectGuess, writer.uint32(18).fork()).join();
+    if (message.drawerId !== 0) {
+      writer.uint32(16).uint32(message.drawerId);
     }
-    if (message.correctGuess !== undefined) {
-      CorrectGuess.encode(message.correctGuess, writer.uint32(26).fork()).join();
+    writer.uint32(26).fork();
+    for (const v of message.likerIds) {
+      writer.uint32(v);
     }
-    if (message.afterDrawOps !== 0) {
-      writer.uint32(32).uint32(message.afterDrawOps);
+    writer.join();
+    if (message.rivalId !== undefined) {
+      writer.uint32(32).uint32(message.rivalId);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): Guess {
+  decode(input: BinaryReader | Uint8Array, length?: number): RoundLikes {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseGuess();
+    const message = createBaseRoundLikes();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -990,31 +1684,41 @@ export const Guess: MessageFns<Guess> = {
             break;
           }
 
-          message.guesserId = reader.uint32();
+          message.roundId = reader.uint32();
           continue;
         }
         case 2: {
-          if (tag !== 18) {
+          if (tag !== 16) {
             break;
           }
 
-          message.incorrectGuess = IncorrectGuess.decode(reader, reader.uint32());
+          message.drawerId = reader.uint32();
           continue;
         }
         case 3: {
-          if (tag !== 26) {
-            break;
+          if (tag === 24) {
+            message.likerIds.push(reader.uint32());
+
+            continue;
           }
 
-          message.correctGuess = CorrectGuess.decode(reader, reader.uint32());
-          continue;
+          if (tag === 26) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.likerIds.push(reader.uint32());
+            }
+
+            continue;
+          }
+
+          break;
         }
         case 4: {
           if (tag !== 32) {
             break;
           }
 
-          message.afterDrawOps = reader.uint32();
+          message.rivalId = reader.uint32();
           continue;
         }
       }
@@ -1026,65 +1730,79 @@ export const Guess: MessageFns<Guess> = {
     return message;
   },
 
-  fromJSON(object: any): Guess {
+  fromJSON(object: any): RoundLikes {
     return {
-      guesserId: isSet(object.guesserId) ? globalThis.Number(object.guesserId) : 0,
-      incorrectGuess: isSet(object.incorrectGuess) ? IncorrectGuess.fromJSON(object.incorrectGuess) : undefined,
-      correctGuess: isSet(object.correctGuess) ? CorrectGuess.fromJSON(object.correctGuess) : undefined,
-      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
+      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+      likerIds: globalThis.Array.isArray(object?.likerIds) ? object.likerIds.map((e: any) => globalThis.Number(e)) : [],
+      rivalId: isSet(object.rivalId) ? globalThis.Number(object.rivalId) : undefined,
     };
   },
 
-  toJSON(message: Guess): unknown {
+  toJSON(message: RoundLikes): unknown {
     const obj: any = {};
-    if (message.guesserId !== 0) {
-      obj.guesserId = Math.round(message.guesserId);
+    if (message.roundId !== 0) {
+      obj.roundId = Math.round(message.roundId);
     }
-    if (message.incorrectGuess !== undefined) {
-      obj.incorrectGuess = IncorrectGuess.toJSON(message.incorrectGuess);
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
     }
-    if (message.correctGuess !== undefined) {
-      obj.correctGuess = CorrectGuess.toJSON(message.correctGuess);
+    if (message.likerIds?.length) {
+      obj.likerIds = message.likerIds.map((e) => Math.round(e));
     }
-    if (message.afterDrawOps !== 0) {
-      obj.afterDrawOps = Math.round(message.afterDrawOps);
+    if (message.rivalId !== undefined) {
+      obj.rivalId = Math.round(message.rivalId);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<Guess>, I>>(base?: I): Guess {
-    return Guess.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<RoundLikes>, I>>(base?: I): RoundLikes {
+    return RoundLikes.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<Guess>, I>>(object: I): Guess {
-    const message = createBaseGuess();
-    message.guesserId = object.guesserId ?? 0;
-    message.incorrectGuess = (object.incorrectGuess !== undefined && object.incorrectGuess !== null)
-      ? IncorrectGuess.fromPartial(object.incorrectGuess)
-      : undefined;
-    message.correctGuess = (object.correctGuess !== undefined && object.correctGuess !== null)
-      ? CorrectGuess.fromPartial(object.correctGuess)
-      : undefined;
-    message.afterDrawOps = object.afterDrawOps ?? 0;
+  fromPartial<I extends Exact<DeepPartial<RoundLikes>, I>>(object: I): RoundLikes {
+    const message = createBaseRoundLikes();
+    message.roundId = object.roundId ?? 0;
+    message.drawerId = object.drawerId ?? 0;
+    message.likerIds = object.likerIds?.map((e) => e) || [];
+    message.rivalId = object.rivalId ?? undefined;
     return message;
   },
 };
 
-function createBaseIncorrectGuess(): IncorrectGuess {
-  return { guess: "" };
+function createBasePlayer(): Player {
+  return { name: "", drawScore: 0, guessScore: 0, roundScore: 0, connected: false, timesDrawn: 0, ready: false };
 }
 
-export const IncorrectGuess: MessageFns<IncorrectGuess> = {
-  encode(message: IncorrectGuess, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.guess !== "") {
-      writer.uint32(10).string(message.guess);
+export const Player: MessageFns<Player> = {
+  encode(message: Player, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.name !== "") {
+      writer.uint32(10).string(message.name);
+    }
+    if (message.drawScore !== 0) {
+      writer.uint32(24).uint32(message.drawScore);
+    }
+    if (message.guessScore !== 0) {
+      writer.uint32(32).uint32(message.guessScore);
+    }
+    if (message.roundScore !== 0) {
+      writer.uint32(40).uint32(message.roundScore);
+    }
+    if (message.connected !== false) {
+      writer.uint32(48).bool(message.connected);
+    }
+    if (message.timesDrawn !== 0) {
+      writer.uint32(56).uint32(message.timesDrawn);
+    }
+    if (message.ready !== false) {
+      writer.uint32(64).bool(message.ready);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): IncorrectGuess {
+  decode(input: BinaryReader | Uint8Array, length?: number): Player {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseIncorrectGuess();
+    const message = createBasePlayer();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -1093,7 +1811,55 @@ export const IncorrectGuess: MessageFns<IncorrectGuess> = {
             break;
           }
 
-          message.guess = reader.string();
+          message.name = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.drawScore = reader.uint32();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.guessScore = reader.uint32();
+          continue;
+        }
+        case 5: {
+          if (tag !== 40) {
+            break;
+          }
+
+          message.roundScore = reader.uint32();
+          continue;
+        }
+        case 6: {
+          if (tag !== 48) {
+            break;
+          }
+
+          message.connected = reader.bool();
+          continue;
+        }
+        case 7: {
+          if (tag !== 56) {
+            break;
+          }
+
+          message.timesDrawn = reader.uint32();
+          continue;
+        }
+        case 8: {
+          if (tag !== 64) {
+            break;
+          }
+
+          message.ready = reader.bool();
           continue;
         }
       }
@@ -1105,118 +1871,255 @@ export const IncorrectGuess: MessageFns<IncorrectGuess> = {
     return message;
   },
 
-  fromJSON(object: any): IncorrectGuess {
-    return { guess: isSet(object.guess) ? globalThis.String(object.guess) : "" };
+  fromJSON(object: any): Player {
+    return {
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
+      drawScore: isSet(object.drawScore) ? globalThis.Number(object.drawScore) : 0,
+      guessScore: isSet(object.guessScore) ? globalThis.Number(object.guessScore) : 0,
+      roundScore: isSet(object.roundScore) ? globalThis.Number(object.roundScore) : 0,
+      connected: isSet(object.connected) ? globalThis.Boolean(object.connected) : false,
+      timesDrawn: isSet(object.timesDrawn) ? globalThis.Number(object.timesDrawn) : 0,
+      ready: isSet(object.ready) ? globalThis.Boolean(object.ready) : false,
+    };
   },
 
-  toJSON(message: IncorrectGuess): unknown {
+  toJSON(message: Player): unknown {
     const obj: any = {};
-    if (message.guess !== "") {
-      obj.guess = message.guess;
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.drawScore !== 0) {
+      obj.drawScore = Math.round(message.drawScore);
+    }
+    if (message.guessScore !== 0) {
+      obj.guessScore = Math.round(message.guessScore);
+    }
+    if (message.roundScore !== 0) {
+      obj.roundScore = Math.round(message.roundScore);
+    }
+    if (message.connected !== false) {
+      obj.connected = message.connected;
+    }
+    if (message.timesDrawn !== 0) {
+      obj.timesDrawn = Math.round(message.timesDrawn);
+    }
+    if (message.ready !== false) {
+      obj.ready = message.ready;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<IncorrectGuess>, I>>(base?: I): IncorrectGuess {
-    return IncorrectGuess.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<Player>, I>>(base?: I): Player {
+    return Player.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<IncorrectGuess>, I>>(object: I): IncorrectGuess {
-    const message = createBaseIncorrectGuess();
-    message.guess = object.guess ?? "";
+  fromPartial<I extends Exact<DeepPartial<Player>, I>>(object: I): Player {
+    const message = createBasePlayer();
+    message.name = object.name ?? "";
+    message.drawScore = object.drawScore ?? 0;
+    message.guessScore = object.guessScore ?? 0;
+    message.roundScore = object.roundScore ?? 0;
+    message.connected = object.connected ?? false;
+    message.timesDrawn = object.timesDrawn ?? 0;
+    message.ready = object.ready ?? false;
     return message;
   },
 };
 
-function createBaseCorrectGuess(): CorrectGuess {
-  return {};
+function createBaseRound(): Round {
+  return {
+    roundId: 0,
+    phase: 0,
+    phaseEndsAt: 0,
+    drawerId: 0,
+    drawOps: [],
+    easyWord: 0,
+    hardWord: 0,
+    wordChoice: 0,
+    drawScore: 0,
+    guessScore: 0,
+    guesses: [],
+    hints: [],
+    rivalId: undefined,
+    rivalDrawOps: [],
+    votes: [],
+  };
 }
 
-export const CorrectGuess: MessageFns<CorrectGuess> = {
-  encode(_: CorrectGuess, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    return writer;
-  },
-
-  decode(input: BinaryReader | Uint8Array, length?: number): CorrectGuess {
-    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
-    let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCorrectGuess();
-    while (reader.pos < end) {
-      const tag = reader.uint32();
-      switch (tag >>> 3) {
-      }
-      if ((tag & 7) === 4 || tag === 0) {
-        break;
-      }
-      reader.skip(tag & 7);
+export const Round: MessageFns<Round> = {
+  encode(message: Round, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.roundId !== 0) {
+      writer.uint32(8).uint32(message.roundId);
     }
-    return message;
-  },
-
-  fromJSON(_: any): CorrectGuess {
-    return {};
-  },
-
-  toJSON(_: CorrectGuess): unknown {
-    const obj: any = {};
-    return obj;
-  },
-
-  create<I extends Exact<DeepPartial<CorrectGuess>, I>>(base?: I): CorrectGuess {
-    return CorrectGuess.fromPartial(base ?? ({} as any));
-  },
-  fromPartial<I extends Exact<DeepPartial<CorrectGuess>, I>>(_: I): CorrectGuess {
-    const message = createBaseCorrectGuess();
-    return message;
-  },
-};
-
-function createBaseHint(): Hint {
-  return { revealLength: undefined, revealLetter: undefined, afterDrawOps: 0 };
-}
-
-export const Hint: MessageFns<Hint> = {
-  encode(message: Hint, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.revealLength !== undefined) {
-      RevealLength.encode(message.revealLength, writer.uint32(10).fork()).join();
+    if (message.phase !== 0) {
+      writer.uint32(80).int32(message.phase);
     }
-    if (message.revealLetter !== undefined) {
-      RevealLetter.encode(message.revealLetter, writer.uint32(18).fork()).join();
+    if (message.phaseEndsAt !== 0) {
+      writer.uint32(96).uint64(message.phaseEndsAt);
     }
-    if (message.afterDrawOps !== 0) {
-      writer.uint32(24).uint32(message.afterDrawOps);
+    if (message.drawerId !== 0) {
+      writer.uint32(16).uint32(message.drawerId);
+    }
+    for (const v of message.drawOps) {
+      DrawOp.encode(v!, writer.uint32(26).fork()).join();
+    }
+    if (message.easyWord !== 0) {
+      writer.uint32(32).uint32(message.easyWord);
+    }
+    if (message.hardWord !== 0) {
+      writer.uint32(40).uint32(message.hardWord);
+    }
+    if (message.wordChoice !== 0) {
+      writer.uint32(88).int32(message.wordChoice);
+    }
+    if (message.drawScore !== 0) {
+      writer.uint32(48).uint32(message.drawScore);
+    }
+    if (message.guessScore !== 0) {
+      writer.uint32(56).uint32(message.guessScore);
+    }
+    for (const v of message.guesses) {
+      Guess.encode(v!, writer.uint32(66).fork()).join();
+    }
+    for (const v of message.hints) {
+      Hint.encode(v!, writer.uint32(74).fork()).join();
+    }
+    if (message.rivalId !== undefined) {
+      writer.uint32(104).uint32(message.rivalId);
+    }
+    for (const v of message.rivalDrawOps) {
+      DrawOp.encode(v!, writer.uint32(114).fork()).join();
+    }
+    for (const v of message.votes) {
+      Vote.encode(v!, writer.uint32(122).fork()).join();
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): Hint {
+  decode(input: BinaryReader | Uint8Array, length?: number): Round {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseHint();
+    const message = createBaseRound();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 10) {
+          if (tag !== 8) {
             break;
           }
 
-          message.revealLength = RevealLength.decode(reader, reader.uint32());
+          message.roundId = reader.uint32();
+          continue;
+        }
+        case 10: {
+          if (tag !== 80) {
+            break;
+          }
+
+          message.phase = reader.int32() as any;
+          continue;
+        }
+        case 12: {
+          if (tag !== 96) {
+            break;
+          }
+
+          message.phaseEndsAt = longToNumber(reader.uint64());
           continue;
         }
         case 2: {
-          if (tag !== 18) {
+          if (tag !== 16) {
             break;
           }
 
-          message.revealLetter = RevealLetter.decode(reader, reader.uint32());
+          message.drawerId = reader.uint32();
           continue;
         }
         case 3: {
-          if (tag !== 24) {
+          if (tag !== 26) {
             break;
           }
 
-          message.afterDrawOps = reader.uint32();
+          message.drawOps.push(DrawOp.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.easyWord = reader.uint32();
+          continue;
+        }
+        case 5: {
+          if (tag !== 40) {
+            break;
+          }
+
+          message.hardWord = reader.uint32();
+          continue;
+        }
+        case 11: {
+          if (tag !== 88) {
+            break;
+          }
+
+          message.wordChoice = reader.int32() as any;
+          continue;
+        }
+        case 6: {
+          if (tag !== 48) {
+            break;
+          }
+
+          message.drawScore = reader.uint32();
+          continue;
+        }
+        case 7: {
+          if (tag !== 56) {
+            break;
+          }
+
+          message.guessScore = reader.uint32();
+          continue;
+        }
+        case 8: {
+          if (tag !== 66) {
+            break;
+          }
+
+          message.guesses.push(Guess.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 9: {
+          if (tag !== 74) {
+            break;
+          }
+
+          message.hints.push(Hint.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 13: {
+          if (tag !== 104) {
+            break;
+          }
+
+          message.rivalId = reader.uint32();
+          continue;
+        }
+        case 14: {
+          if (tag !== 114) {
+            break;
+          }
+
+          message.rivalDrawOps.push(DrawOp.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 15: {
+          if (tag !== 122) {
+            break;
+          }
+
+          message.votes.push(Vote.decode(reader, reader.uint32()));
           continue;
         }
       }
@@ -1228,60 +2131,140 @@ export const Hint: MessageFns<Hint> = {
     return message;
   },
 
-  fromJSON(object: any): Hint {
+  fromJSON(object: any): Round {
     return {
-      revealLength: isSet(object.revealLength) ? RevealLength.fromJSON(object.revealLength) : undefined,
-      revealLetter: isSet(object.revealLetter) ? RevealLetter.fromJSON(object.revealLetter) : undefined,
-      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
+      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
+      phase: isSet(object.phase) ? phaseFromJSON(object.phase) : 0,
+      phaseEndsAt: isSet(object.phaseEndsAt) ? globalThis.Number(object.phaseEndsAt) : 0,
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+      drawOps: globalThis.Array.isArray(object?.drawOps) ? object.drawOps.map((e: any) => DrawOp.fromJSON(e)) : [],
+      easyWord: isSet(object.easyWord) ? globalThis.Number(object.easyWord) : 0,
+      hardWord: isSet(object.hardWord) ? globalThis.Number(object.hardWord) : 0,
+      wordChoice: isSet(object.wordChoice) ? wordChoiceFromJSON(object.wordChoice) : 0,
+      drawScore: isSet(object.drawScore) ? globalThis.Number(object.drawScore) : 0,
+      guessScore: isSet(object.guessScore) ? globalThis.Number(object.guessScore) : 0,
+      guesses: globalThis.Array.isArray(object?.guesses) ? object.guesses.map((e: any) => Guess.fromJSON(e)) : [],
+      hints: globalThis.Array.isArray(object?.hints) ? object.hints.map((e: any) => Hint.fromJSON(e)) : [],
+      rivalId: isSet(object.rivalId) ? globalThis.Number(object.rivalId) : undefined,
+      rivalDrawOps: globalThis.Array.isArray(object?.rivalDrawOps)
+        ? object.rivalDrawOps.map((e: any) => DrawOp.fromJSON(e))
+        : [],
+      votes: globalThis.Array.isArray(object?.votes) ? object.votes.map((e: any) => Vote.fromJSON(e)) : [],
     };
   },
 
-  toJSON(message: Hint): unknown {
+  toJSON(message: Round): unknown {
     const obj: any = {};
-    if (message.revealLength !== undefined) {
-      obj.revealLength = RevealLength.toJSON(message.revealLength);
+    if (message.roundId !== 0) {
+      obj.roundId = Math.round(message.roundId);
     }
-    if (message.revealLetter !== undefined) {
-      obj.revealLetter = RevealLetter.toJSON(message.revealLetter);
+    if (message.phase !== 0) {
+      obj.phase = phaseToJSON(message.phase);
     }
-    if (message.afterDrawOps !== 0) {
-      obj.afterDrawOps = Math.round(message.afterDrawOps);
+    if (message.phaseEndsAt !== 0) {
+      obj.phaseEndsAt = Math.round(message.phaseEndsAt);
+    }
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
+    if (message.drawOps?.length) {
+      obj.drawOps = message.drawOps.map((e) => DrawOp.toJSON(e));
+    }
+    if (message.easyWord !== 0) {
+      obj.easyWord = Math.round(message.easyWord);
+    }
+    if (message.hardWord !== 0) {
+      obj.hardWord = Math.round(message.hardWord);
+    }
+    if (message.wordChoice !== 0) {
+      obj.wordChoice = wordChoiceToJSON(message.wordChoice);
+    }
+    if (message.drawScore !== 0) {
+      obj.drawScore = Math.round(message.drawScore);
+    }
+    if (message.guessScore !== 0) {
+      obj.guessScore = Math.round(message.guessScore);
+    }
+    if (message.guesses?.length) {
+      obj.guesses = message.guesses.map((e) => Guess.toJSON(e));
+    }
+    if (message.hints?.length) {
+      obj.hints = message.hints.map((e) => Hint.toJSON(e));
+    }
+    if (message.rivalId !== undefined) {
+      obj.rivalId = Math.round(message.rivalId);
+    }
+    if (message.rivalDrawOps?.length) {
+      obj.rivalDrawOps = message.rivalDrawOps.map((e) => DrawOp.toJSON(e));
+    }
+    if (message.votes?.length) {
+      obj.votes = message.votes.map((e) => Vote.toJSON(e));
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<Hint>, I>>(base?: I): Hint {
-    return Hint.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<Round>, I>>(base?: I): Round {
+    return Round.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<Hint>, I>>(object: I): Hint {
-    const message = createBaseHint();
-    message.revealLength = (object.revealLength !== undefined && object.revealLength !== null)
-      ? RevealLength.fromPartial(object.revealLength)
-      : undefined;
-    message.revealLetter = (object.revealLetter !== undefined && object.revealLetter !== null)
-      ? RevealLetter.fromPartial(object.revealLetter)
-      : undefined;
-    message.afterDrawOps = object.afterDrawOps ?? 0;
+  fromPartial<I extends Exact<DeepPartial<Round>, I>>(object: I): Round {
+    const message = createBaseRound();
+    message.roundId = object.roundId ?? 0;
+    message.phase = object.phase ?? 0;
+    message.phaseEndsAt = object.phaseEndsAt ?? 0;
+    message.drawerId = object.drawerId ?? 0;
+    message.drawOps = object.drawOps?.map((e) => DrawOp.fromPartial(e)) || [];
+    message.easyWord = object.easyWord ?? 0;
+    message.hardWord = object.hardWord ?? 0;
+    message.wordChoice = object.wordChoice ?? 0;
+    message.drawScore = object.drawScore ?? 0;
+    message.guessScore = object.guessScore ?? 0;
+    message.guesses = object.guesses?.map((e) => Guess.fromPartial(e)) || [];
+    message.hints = object.hints?.map((e) => Hint.fromPartial(e)) || [];
+    message.rivalId = object.rivalId ?? undefined;
+    message.rivalDrawOps = object.rivalDrawOps?.map((e) => DrawOp.fromPartial(e)) || [];
+    message.votes = object.votes?.map((e) => Vote.fromPartial(e)) || [];
     return message;
   },
 };
 
-function createBaseRevealLength(): RevealLength {
-  return {};
+function createBaseVote(): Vote {
+  return { voterId: 0, drawerId: 0 };
 }
 
-export const RevealLength: MessageFns<RevealLength> = {
-  encode(_: RevealLength, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+export const Vote: MessageFns<Vote> = {
+  encode(message: Vote, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.voterId !== 0) {
+      writer.uint32(8).uint32(message.voterId);
+    }
+    if (message.drawerId !== 0) {
+      writer.uint32(16).uint32(message.drawerId);
+    }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): RevealLength {
+  decode(input: BinaryReader | Uint8Array, length?: number): Vote {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseRevealLength();
+    const message = createBaseVote();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.voterId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.drawerId = reader.uint32();
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1291,40 +2274,63 @@ export const RevealLength: MessageFns<RevealLength> = {
     return message;
   },
 
-  fromJSON(_: any): RevealLength {
-    return {};
+  fromJSON(object: any): Vote {
+    return {
+      voterId: isSet(object.voterId) ? globalThis.Number(object.voterId) : 0,
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+    };
   },
 
-  toJSON(_: RevealLength): unknown {
+  toJSON(message: Vote): unknown {
     const obj: any = {};
+    if (message.voterId !== 0) {
+      obj.voterId = Math.round(message.voterId);
+    }
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<RevealLength>, I>>(base?: I): RevealLength {
-    return RevealLength.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<Vote>, I>>(base?: I): Vote {
+    return Vote.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<RevealLength>, I>>(_: I): RevealLength {
-    const message = createBaseRevealLength();
+  fromPartial<I extends Exact<DeepPartial<Vote>, I>>(object: I): Vote {
+    const message = createBaseVote();
+    message.voterId = object.voterId ?? 0;
+    message.drawerId = object.drawerId ?? 0;
     return message;
   },
 };
 
-function createBaseRevealLetter(): RevealLetter {
-  return { index: 0 };
+function createBaseStanding(): Standing {
+  return { playerId: 0, place: 0, score: 0, drawScore: 0, guessScore: 0 };
 }
 
-export const RevealLetter: MessageFns<RevealLetter> = {
-  encode(message: RevealLetter, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.index !== 0) {
-      writer.uint32(8).uint32(message.index);
+export const Standing: MessageFns<Standing> = {
+  encode(message: Standing, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    if (message.place !== 0) {
+      writer.uint32(16).uint32(message.place);
+    }
+    if (message.score !== 0) {
+      writer.uint32(24).uint32(message.score);
+    }
+    if (message.drawScore !== 0) {
+      writer.uint32(32).uint32(message.drawScore);
+    }
+    if (message.guessScore !== 0) {
+      writer.uint32(40).uint32(message.guessScore);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): RevealLetter {
+  decode(input: BinaryReader | Uint8Array, length?: number): Standing {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseRevealLetter();
+    const message = createBaseStanding();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -1333,7 +2339,39 @@ export const RevealLetter: MessageFns<RevealLetter> = {
             break;
           }
 
-          message.index = reader.uint32();
+          message.playerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.place = reader.uint32();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.score = reader.uint32();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.drawScore = reader.uint32();
+          continue;
+        }
+        case 5: {
+          if (tag !== 40) {
+            break;
+          }
+
+          message.guessScore = reader.uint32();
           continue;
         }
       }
@@ -1345,75 +2383,87 @@ export const RevealLetter: MessageFns<RevealLetter> = {
     return message;
   },
 
-  fromJSON(object: any): RevealLetter {
-    return { index: isSet(object.index) ? globalThis.Number(object.index) : 0 };
+  fromJSON(object: any): Standing {
+    return {
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      place: isSet(object.place) ? globalThis.Number(object.place) : 0,
+      score: isSet(object.score) ? globalThis.Number(object.score) : 0,
+      drawScore: isSet(object.drawScore) ? globalThis.Number(object.drawScore) : 0,
+      guessScore: isSet(object.guessScore) ? globalThis.Number(object.guessScore) : 0,
+    };
   },
 
-  toJSON(message: RevealLetter): unknown {
+  toJSON(message: Standing): unknown {
     const obj: any = {};
-    if (message.index !== 0) {
-      obj.index = Math.round(message.index);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    if (message.place !== 0) {
+      obj.place = Math.round(message.place);
+    }
+    if (message.score !== 0) {
+      obj.score = Math.round(message.score);
+    }
+    if (message.drawScore !== 0) {
+      obj.drawScore = Math.round(message.drawScore);
+    }
+    if (message.guessScore !== 0) {
+      obj.guessScore = Math.round(message.guessScore);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<RevealLetter>, I>>(base?: I): RevealLetter {
-    return RevealLetter.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<Standing>, I>>(base?: I): Standing {
+    return Standing.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<RevealLetter>, I>>(object: I): RevealLetter {
-    const message = createBaseRevealLetter();
-    message.index = object.index ?? 0;
+  fromPartial<I extends Exact<DeepPartial<Standing>, I>>(object: I): Standing {
+    const message = createBaseStanding();
+    message.playerId = object.playerId ?? 0;
+    message.place = object.place ?? 0;
+    message.score = object.score ?? 0;
+    message.drawScore = object.drawScore ?? 0;
+    message.guessScore = object.guessScore ?? 0;
     return message;
   },
 };
 
-function createBaseDrawOp(): DrawOp {
-  return {
-    setColor: undefined,
-    startStroke: undefined,
-    continueStroke: undefined,
-    clearScreen: undefined,
-    undo: undefined,
-    redo: undefined,
-  };
+function createBaseGuess(): Guess {
+  return { guesserId: 0, incorrectGuess: undefined, correctGuess: undefined, afterDrawOps: 0, watchingId: undefined };
 }
 
-export const DrawOp: MessageFns<DrawOp> = {
-  encode(message: DrawOp, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.setColor !== undefined) {
-      DoSetColor.encode(message.setColor, writer.uint32(10).fork()).join();
-    }
-    if (message.startStroke !== undefined) {
-      DoStartStroke.encode(message.startStroke, writer.uint32(18).fork()).join();
+export const Guess: MessageFns<Guess> = {
+  encode(message: Guess, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.guesserId !== 0) {
+      writer.uint32(8).uint32(message.guesserId);
     }
-    if (message.continueStroke !== undefined) {
-      DoContinueStroke.encode(message.continueStroke, writer.uint32(26).fork()).join();
+    if (message.incorrectGuess !== undefined) {
+      IncorrectGuess.encode(message.incorrectGuess, writer.uint32(18).fork()).join();
     }
-    if (message.clearScreen !== undefined) {
-      DoClearScreen.encode(message.clearScreen, writer.uint32(34).fork()).join();
+    if (message.correctGuess !== undefined) {
+      CorrectGuess.encode(message.correctGuess, writer.uint32(26).fork()).join();
     }
-    if (message.undo !== undefined) {
-      DoUndo.encode(message.undo, writer.uint32(42).fork()).join();
+    if (message.afterDrawOps !== 0) {
+      writer.uint32(32).uint32(message.afterDrawOps);
     }
-    if (message.redo !== undefined) {
-      DoRedo.encode(message.redo, writer.uint32(50).fork()).join();
+    if (message.watchingId !== undefined) {
+      writer.uint32(40).uint32(message.watchingId);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): DrawOp {
+  decode(input: BinaryReader | Uint8Array, length?: number): Guess {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseDrawOp();
+    const message = createBaseGuess();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 10) {
+          if (tag !== 8) {
             break;
           }
 
-          message.setColor = DoSetColor.decode(reader, reader.uint32());
+          message.guesserId = reader.uint32();
           continue;
         }
         case 2: {
@@ -1421,7 +2471,7 @@ export const DrawOp: MessageFns<DrawOp> = {
             break;
           }
 
-          message.startStroke = DoStartStroke.decode(reader, reader.uint32());
+          message.incorrectGuess = IncorrectGuess.decode(reader, reader.uint32());
           continue;
         }
         case 3: {
@@ -1429,31 +2479,23 @@ export const DrawOp: MessageFns<DrawOp> = {
             break;
           }
 
-          message.continueStroke = DoContinueStroke.decode(reader, reader.uint32());
+          message.correctGuess = CorrectGuess.decode(reader, reader.uint32());
           continue;
         }
         case 4: {
-          if (tag !== 34) {
+          if (tag !== 32) {
             break;
           }
 
-          message.clearScreen = DoClearScreen.decode(reader, reader.uint32());
+          message.afterDrawOps = reader.uint32();
           continue;
         }
         case 5: {
-          if (tag !== 42) {
-            break;
-          }
-
-          message.undo = DoUndo.decode(reader, reader.uint32());
-          continue;
-        }
-        case 6: {
-          if (tag !== 50) {
+          if (tag !== 40) {
             break;
           }
 
-          message.redo = DoRedo.decode(reader, reader.uint32());
+          message.watchingId = reader.uint32();
           continue;
         }
       }
@@ -1465,99 +2507,101 @@ export const DrawOp: MessageFns<DrawOp> = {
     return message;
   },
 
-  fromJSON(object: any): DrawOp {
+  fromJSON(object: any): Guess {
     return {
-      setColor: isSet(object.setColor) ? DoSetColor.fromJSON(object.setColor) : undefined,
-      startStroke: isSet(object.startStroke) ? DoStartStroke.fromJSON(object.startStroke) : undefined,
-      continueStroke: isSet(object.continueStroke) ? DoContinueStroke.fromJSON(object.continueStroke) : undefined,
-      clearScreen: isSet(object.clearScreen) ? DoClearScreen.fromJSON(object.clearScreen) : undefined,
-      undo: isSet(object.undo) ? DoUndo.fromJSON(object.undo) : undefined,
-      redo: isSet(object.redo) ? DoRedo.fromJSON(object.redo) : undefined,
+      guesserId: isSet(object.guesserId) ? globalThis.Number(object.guesserId) : 0,
+      incorrectGuess: isSet(object.incorrectGuess) ? IncorrectGuess.fromJSON(object.incorrectGuess) : undefined,
+      correctGuess: isSet(object.correctGuess) ? CorrectGuess.fromJSON(object.correctGuess) : undefined,
+      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
+      watchingId: isSet(object.watchingId) ? globalThis.Number(object.watchingId) : undefined,
     };
   },
 
-  toJSON(message: DrawOp): unknown {
+  toJSON(message: Guess): unknown {
     const obj: any = {};
-    if (message.setColor !== undefined) {
-      obj.setColor = DoSetColor.toJSON(message.setColor);
+    if (message.guesserId !== 0) {
+      obj.guesserId = Math.round(message.guesserId);
     }
-    if (message.startStroke !== undefined) {
-      obj.startStroke = DoStartStroke.toJSON(message.startStroke);
+    if (message.incorrectGuess !== undefined) {
+      obj.incorrectGuess = IncorrectGuess.toJSON(message.incorrectGuess);
     }
-    if (message.continueStroke !== undefined) {
-      obj.continueStroke = DoContinueStroke.toJSON(message.continueStroke);
+    if (message.correctGuess !== undefined) {
+      obj.correctGuess = CorrectGuess.toJSON(message.correctGuess);
     }
-    if (message.clearScreen !== undefined) {
-      obj.clearScreen = DoClearScreen.toJSON(message.clearScreen);
-    }
-    if (message.undo !== undefined) {
-      obj.undo = DoUndo.toJSON(message.undo);
+    if (message.afterDrawOps !== 0) {
+      obj.afterDrawOps = Math.round(message.afterDrawOps);
     }
-    if (message.redo !== undefined) {
-      obj.redo = DoRedo.toJSON(message.redo);
+    if (message.watchingId !== undefined) {
+      obj.watchingId = Math.round(message.watchingId);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<DrawOp>, I>>(base?: I): DrawOp {
-    return DrawOp.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<Guess>, I>>(base?: I): Guess {
+    return Guess.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<DrawOp>, I>>(object: I): DrawOp {
-    const message = createBaseDrawOp();
-    message.setColor = (object.setColor !== undefined && object.setColor !== null)
-      ? DoSetColor.fromPartial(object.setColor)
-      : undefined;
-    message.startStroke = (object.startStroke !== undefined && object.startStroke !== null)
-      ? DoStartStroke.fromPartial(object.startStroke)
-      : undefined;
-    message.continueStroke = (object.continueStroke !== undefined && object.continueStroke !== null)
-      ? DoContinueStroke.fromPartial(object.continueStroke)
+  fromPartial<I extends Exact<DeepPartial<Guess>, I>>(object: I): Guess {
+    const message = createBaseGuess();
+    message.guesserId = object.guesserId ?? 0;
+    message.incorrectGuess = (object.incorrectGuess !== undefined && object.incorrectGuess !== null)
+      ? IncorrectGuess.fromPartial(object.incorrectGuess)
       : undefined;
-    message.clearScreen = (object.clearScreen !== undefined && object.clearScreen !== null)
-      ? DoClearScreen.fromPartial(object.clearScreen)
+    message.correctGuess = (object.correctGuess !== undefined && object.correctGuess !== null)
+      ? CorrectGuess.fromPartial(object.correctGuess)
       : undefined;
-    message.undo = (object.undo !== undefined && object.undo !== null) ? DoUndo.fromPartial(object.undo) : undefined;
-    message.redo = (object.redo !== undefined && object.redo !== null) ? DoRedo.fromPartial(object.redo) : undefined;
+    message.afterDrawOps = object.afterDrawOps ?? 0;
+    message.watchingId = object.watchingId ?? undefined;
     return message;
   },
 };
 
-function createBaseDoSetColor(): DoSetColor {
-  return { colorType: 0, color: "" };
+function createBaseIncorrectGuess(): IncorrectGuess {
+  return { guess: "", close: false, afterSolving: false };
 }
 
-export const DoSetColor: MessageFns<DoSetColor> = {
-  encode(message: DoSetColor, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.colorType !== 0) {
-      writer.uint32(8).int32(message.colorType);
+export const IncorrectGuess: MessageFns<IncorrectGuess> = {
+  encode(message: IncorrectGuess, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.guess !== "") {
+      writer.uint32(10).string(message.guess);
     }
-    if (message.color !== "") {
-      writer.uint32(18).string(message.color);
+    if (message.close !== false) {
+      writer.uint32(16).bool(message.close);
+    }
+    if (message.afterSolving !== false) {
+      writer.uint32(24).bool(message.afterSolving);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): DoSetColor {
+  decode(input: BinaryReader | Uint8Array, length?: number): IncorrectGuess {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseDoSetColor();
+    const message = createBaseIncorrectGuess();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 8) {
+          if (tag !== 10) {
             break;
           }
 
-          message.colorType = reader.int32() as any;
+          message.guess = reader.string();
           continue;
         }
         case 2: {
-          if (tag !== 18) {
+          if (tag !== 16) {
             break;
           }
 
-          message.color = reader.string();
+          message.close = reader.bool();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.afterSolving = reader.bool();
           continue;
         }
       }
@@ -1569,82 +2613,130 @@ export const DoSetColor: MessageFns<DoSetColor> = {
     return message;
   },
 
-  fromJSON(object: any): DoSetColor {
+  fromJSON(object: any): IncorrectGuess {
     return {
-      colorType: isSet(object.colorType) ? colorTypeFromJSON(object.colorType) : 0,
-      color: isSet(object.color) ? globalThis.String(object.color) : "",
+      guess: isSet(object.guess) ? globalThis.String(object.guess) : "",
+      close: isSet(object.close) ? globalThis.Boolean(object.close) : false,
+      afterSolving: isSet(object.afterSolving) ? globalThis.Boolean(object.afterSolving) : false,
     };
   },
 
-  toJSON(message: DoSetColor): unknown {
+  toJSON(message: IncorrectGuess): unknown {
     const obj: any = {};
-    if (message.colorType !== 0) {
-      obj.colorType = colorTypeToJSON(message.colorType);
+    if (message.guess !== "") {
+      obj.guess = message.guess;
     }
-    if (message.color !== "") {
-      obj.color = message.color;
+    if (message.close !== false) {
+      obj.close = message.close;
+    }
+    if (message.afterSolving !== false) {
+      obj.afterSolving = message.afterSolving;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<DoSetColor>, I>>(base?: I): DoSetColor {
-    return DoSetColor.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<IncorrectGuess>, I>>(base?: I): IncorrectGuess {
+    return IncorrectGuess.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<DoSetColor>, I>>(object: I): DoSetColor {
-    const message = createBaseDoSetColor();
-    message.colorType = object.colorType ?? 0;
-    message.color = object.color ?? "";
+  fromPartial<I extends Exact<DeepPartial<IncorrectGuess>, I>>(object: I): IncorrectGuess {
+    const message = createBaseIncorrectGuess();
+    message.guess = object.guess ?? "";
+    message.close = object.close ?? false;
+    message.afterSolving = object.afterSolving ?? false;
     return message;
   },
 };
 
-function createBaseDoStartStroke(): DoStartStroke {
-  return { colorType: 0, x: 0, y: 0 };
+function createBaseCorrectGuess(): CorrectGuess {
+  return {};
 }
 
-export const DoStartStroke: MessageFns<DoStartStroke> = {
-  encode(message: DoStartStroke, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.colorType !== 0) {
-      writer.uint32(8).int32(message.colorType);
+export const CorrectGuess: MessageFns<CorrectGuess> = {
+  encode(_: CorrectGuess, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CorrectGuess {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCorrectGuess();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
     }
-    if (message.x !== 0) {
-      writer.uint32(21).float(message.x);
+    return message;
+  },
+
+  fromJSON(_: any): CorrectGuess {
+    return {};
+  },
+
+  toJSON(_: CorrectGuess): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CorrectGuess>, I>>(base?: I): CorrectGuess {
+    return CorrectGuess.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CorrectGuess>, I>>(_: I): CorrectGuess {
+    const message = createBaseCorrectGuess();
+    return message;
+  },
+};
+
+function createBaseHint(): Hint {
+  return { revealLength: undefined, revealLetter: undefined, afterDrawOps: 0 };
+}
+
+export const Hint: MessageFns<Hint> = {
+  encode(message: Hint, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.revealLength !== undefined) {
+      RevealLength.encode(message.revealLength, writer.uint32(10).fork()).join();
     }
-    if (message.y !== 0) {
-      writer.uint32(29).float(message.y);
+    if (message.revealLetter !== undefined) {
+      RevealLetter.encode(message.revealLetter, writer.uint32(18).fork()).join();
+    }
+    if (message.afterDrawOps !== 0) {
+      writer.uint32(24).uint32(message.afterDrawOps);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): DoStartStroke {
+  decode(input: BinaryReader | Uint8Array, length?: number): Hint {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseDoStartStroke();
+    const message = createBaseHint();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 8) {
+          if (tag !== 10) {
             break;
           }
 
-          message.colorType = reader.int32() as any;
+          message.revealLength = RevealLength.decode(reader, reader.uint32());
           continue;
         }
         case 2: {
-          if (tag !== 21) {
+          if (tag !== 18) {
             break;
           }
 
-          message.x = reader.float();
+          message.revealLetter = RevealLetter.decode(reader, reader.uint32());
           continue;
         }
         case 3: {
-          if (tag !== 29) {
+          if (tag !== 24) {
             break;
           }
 
-          message.y = reader.float();
+          message.afterDrawOps = reader.uint32();
           continue;
         }
       }
@@ -1656,77 +2748,82 @@ export const DoStartStroke: MessageFns<DoStartStroke> = {
     return message;
   },
 
-  fromJSON(object: any): DoStartStroke {
+  fromJSON(object: any): Hint {
     return {
-      colorType: isSet(object.colorType) ? colorTypeFromJSON(object.colorType) : 0,
-      x: isSet(object.x) ? globalThis.Number(object.x) : 0,
-      y: isSet(object.y) ? globalThis.Number(object.y) : 0,
+      revealLength: isSet(object.revealLength) ? RevealLength.fromJSON(object.revealLength) : undefined,
+      revealLetter: isSet(object.revealLetter) ? RevealLetter.fromJSON(object.revealLetter) : undefined,
+      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
     };
   },
 
-  toJSON(message: DoStartStroke): unknown {
+  toJSON(message: Hint): unknown {
     const obj: any = {};
-    if (message.colorType !== 0) {
-      obj.colorType = colorTypeToJSON(message.colorType);
+    if (message.revealLength !== undefined) {
+      obj.revealLength = RevealLength.toJSON(message.revealLength);
     }
-    if (message.x !== 0) {
-      obj.x = message.x;
+    if (message.revealLetter !== undefined) {
+      obj.revealLetter = RevealLetter.toJSON(message.revealLetter);
     }
-    if (message.y !== 0) {
-      obj.y = message.y;
+    if (message.afterDrawOps !== 0) {
+      obj.afterDrawOps = Math.round(message.afterDrawOps);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<DoStartStroke>, I>>(base?: I): DoStartStroke {
-    return DoStartStroke.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<Hint>, I>>(base?: I): Hint {
+    return Hint.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<DoStartStroke>, I>>(object: I): DoStartStroke {
-    const message = createBaseDoStartStroke();
-    message.colorType = object.colorType ?? 0;
-    message.x = object.x ?? 0;
-    message.y = object.y ?? 0;
+  fromPartial<I extends Exact<DeepPartial<Hint>, I>>(object: I): Hint {
+    const message = createBaseHint();
+    message.revealLength = (object.revealLength !== undefined && object.revealLength !== null)
+      ? RevealLength.fromPartial(object.revealLength)
+      : undefined;
+    message.revealLetter = (object.revealLetter !== undefined && object.revealLetter !== null)
+      ? RevealLetter.fromPartial(object.revealLetter)
+      : undefined;
+    message.afterDrawOps = object.afterDrawOps ?? 0;
     return message;
   },
 };
 
-function createBaseDoContinueStroke(): DoContinueStroke {
-  return { x: 0, y: 0 };
+function createBaseRevealLength(): RevealLength {
+  return { wordLengths: [] };
 }
 
-export const DoContinueStroke: MessageFns<DoContinueStroke> = {
-  encode(message: DoContinueStroke, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.x !== 0) {
-      writer.uint32(13).float(message.x);
-    }
-    if (message.y !== 0) {
-      writer.uint32(21).float(message.y);
+export const RevealLength: MessageFns<RevealLength> = {
+  encode(message: RevealLength, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    writer.uint32(10).fork();
+    for (const v of message.wordLengths) {
+      writer.uint32(v);
     }
+    writer.join();
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): DoContinueStroke {
+  decode(input: BinaryReader | Uint8Array, length?: number): RevealLength {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseDoContinueStroke();
+    const message = createBaseRevealLength();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 13) {
-            break;
+          if (tag === 8) {
+            message.wordLengths.push(reader.uint32());
+
+            continue;
           }
 
-          message.x = reader.float();
-          continue;
-        }
-        case 2: {
-          if (tag !== 21) {
-            break;
+          if (tag === 10) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.wordLengths.push(reader.uint32());
+            }
+
+            continue;
           }
 
-          message.y = reader.float();
-          continue;
+          break;
         }
       }
       if ((tag & 7) === 4 || tag === 0) {
@@ -1737,51 +2834,70 @@ export const DoContinueStroke: MessageFns<DoContinueStroke> = {
     return message;
   },
 
-  fromJSON(object: any): DoContinueStroke {
+  fromJSON(object: any): RevealLength {
     return {
-      x: isSet(object.x) ? globalThis.Number(object.x) : 0,
-      y: isSet(object.y) ? globalThis.Number(object.y) : 0,
+      wordLengths: globalThis.Array.isArray(object?.wordLengths)
+        ? object.wordLengths.map((e: any) => globalThis.Number(e))
+        : [],
     };
   },
 
-  toJSON(message: DoContinueStroke): unknown {
+  toJSON(message: RevealLength): unknown {
     const obj: any = {};
-    if (message.x !== 0) {
-      obj.x = message.x;
-    }
-    if (message.y !== 0) {
-      obj.y = message.y;
+    if (message.wordLengths?.length) {
+      obj.wordLengths = message.wordLengths.map((e) => Math.round(e));
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<DoContinueStroke>, I>>(base?: I): DoContinueStroke {
-    return DoContinueStroke.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<RevealLength>, I>>(base?: I): RevealLength {
+    return RevealLength.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<DoContinueStroke>, I>>(object: I): DoContinueStroke {
-    const message = createBaseDoContinueStroke();
-    message.x = object.x ?? 0;
-    message.y = object.y ?? 0;
+  fromPartial<I extends Exact<DeepPartial<RevealLength>, I>>(object: I): RevealLength {
+    const message = createBaseRevealLength();
+    message.wordLengths = object.wordLengths?.map((e) => e) || [];
     return message;
   },
 };
 
-function createBaseDoClearScreen(): DoClearScreen {
-  return {};
+function createBaseRevealLetter(): RevealLetter {
+  return { index: 0, letter: "" };
 }
 
-export const DoClearScreen: MessageFns<DoClearScreen> = {
-  encode(_: DoClearScreen, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+export const RevealLetter: MessageFns<RevealLetter> = {
+  encode(message: RevealLetter, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.index !== 0) {
+      writer.uint32(8).uint32(message.index);
+    }
+    if (message.letter !== "") {
+      writer.uint32(18).string(message.letter);
+    }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): DoClearScreen {
+  decode(input: BinaryReader | Uint8Array, length?: number): RevealLetter {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseDoClearScreen();
+    const message = createBaseRevealLetter();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.index = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.letter = reader.string();
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1791,83 +2907,136 @@ export const DoClearScreen: MessageFns<DoClearScreen> = {
     return message;
   },
 
-  fromJSON(_: any): DoClearScreen {
-    return {};
+  fromJSON(object: any): RevealLetter {
+    return {
+      index: isSet(object.index) ? globalThis.Number(object.index) : 0,
+      letter: isSet(object.letter) ? globalThis.String(object.letter) : "",
+    };
   },
 
-  toJSON(_: DoClearScreen): unknown {
+  toJSON(message: RevealLetter): unknown {
     const obj: any = {};
+    if (message.index !== 0) {
+      obj.index = Math.round(message.index);
+    }
+    if (message.letter !== "") {
+      obj.letter = message.letter;
+    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<DoClearScreen>, I>>(base?: I): DoClearScreen {
-    return DoClearScreen.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<RevealLetter>, I>>(base?: I): RevealLetter {
+    return RevealLetter.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<DoClearScreen>, I>>(_: I): DoClearScreen {
-    const message = createBaseDoClearScreen();
+  fromPartial<I extends Exact<DeepPartial<RevealLetter>, I>>(object: I): RevealLetter {
+    const message = createBaseRevealLetter();
+    message.index = object.index ?? 0;
+    message.letter = object.letter ?? "";
     return message;
   },
 };
 
-function createBaseDoUndo(): DoUndo {
-  return {};
+function createBaseDrawOp(): DrawOp {
+  return {
+    setColor: undefined,
+    startStroke: undefined,
+    continueStroke: undefined,
+    clearScreen: undefined,
+    undo: undefined,
+    redo: undefined,
+    strokePoints: undefined,
+  };
 }
 
-export const DoUndo: MessageFns<DoUndo> = {
-  encode(_: DoUndo, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+export const DrawOp: MessageFns<DrawOp> = {
+  encode(message: DrawOp, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.setColor !== undefined) {
+      DoSetColor.encode(message.setColor, writer.uint32(10).fork()).join();
+    }
+    if (message.startStroke !== undefined) {
+      DoStartStroke.encode(message.startStroke, writer.uint32(18).fork()).join();
+    }
+    if (message.continueStroke !== undefined) {
+      DoContinueStroke.encode(message.continueStroke, writer.uint32(26).fork()).join();
+    }
+    if (message.clearScreen !== undefined) {
+      DoClearScreen.encode(message.clearScreen, writer.uint32(34).fork()).join();
+    }
+    if (message.undo !== undefined) {
+      DoUndo.encode(message.undo, writer.uint32(42).fork()).join();
+    }
+    if (message.redo !== undefined) {
+      DoRedo.encode(message.redo, writer.uint32(50).fork()).join();
+    }
+    if (message.strokePoints !== undefined) {
+      DoStrokePoints.encode(message.strokePoints, writer.uint32(58).fork()).join();
+    }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): DoUndo {
+  decode(input: BinaryReader | Uint8Array, length?: number): DrawOp {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseDoUndo();
+    const message = createBaseDrawOp();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
-      }
-      if ((tag & 7) === 4 || tag === 0) {
-        break;
-      }
-      reader.skip(tag & 7);
-    }
-    return message;
-  },
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
 
-  fromJSON(_: any): DoUndo {
-    return {};
-  },
+          message.setColor = DoSetColor.decode(reader, reader.uint32());
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
 
-  toJSON(_: DoUndo): unknown {
-    const obj: any = {};
-    return obj;
-  },
+          message.startStroke = DoStartStroke.decode(reader, reader.uint32());
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
 
-  create<I extends Exact<DeepPartial<DoUndo>, I>>(base?: I): DoUndo {
-    return DoUndo.fromPartial(base ?? ({} as any));
-  },
-  fromPartial<I extends Exact<DeepPartial<DoUndo>, I>>(_: I): DoUndo {
-    const message = createBaseDoUndo();
-    return message;
-  },
-};
+          message.continueStroke = DoContinueStroke.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
 
-function createBaseDoRedo(): DoRedo {
-  return {};
-}
+          message.clearScreen = DoClearScreen.decode(reader, reader.uint32());
+          continue;
+        }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
 
-export const DoRedo: MessageFns<DoRedo> = {
-  encode(_: DoRedo, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    return writer;
-  },
+          message.undo = DoUndo.decode(reader, reader.uint32());
+          continue;
+        }
+        case 6: {
+          if (tag !== 50) {
+            break;
+          }
 
-  decode(input: BinaryReader | Uint8Array, length?: number): DoRedo {
-    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
-    let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseDoRedo();
-    while (reader.pos < end) {
-      const tag = reader.uint32();
-      switch (tag >>> 3) {
+          message.redo = DoRedo.decode(reader, reader.uint32());
+          continue;
+        }
+        case 7: {
+          if (tag !== 58) {
+            break;
+          }
+
+          message.strokePoints = DoStrokePoints.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -1877,43 +3046,89 @@ export const DoRedo: MessageFns<DoRedo> = {
     return message;
   },
 
-  fromJSON(_: any): DoRedo {
-    return {};
+  fromJSON(object: any): DrawOp {
+    return {
+      setColor: isSet(object.setColor) ? DoSetColor.fromJSON(object.setColor) : undefined,
+      startStroke: isSet(object.startStroke) ? DoStartStroke.fromJSON(object.startStroke) : undefined,
+      continueStroke: isSet(object.continueStroke) ? DoContinueStroke.fromJSON(object.continueStroke) : undefined,
+      clearScreen: isSet(object.clearScreen) ? DoClearScreen.fromJSON(object.clearScreen) : undefined,
+      undo: isSet(object.undo) ? DoUndo.fromJSON(object.undo) : undefined,
+      redo: isSet(object.redo) ? DoRedo.fromJSON(object.redo) : undefined,
+      strokePoints: isSet(object.strokePoints) ? DoStrokePoints.fromJSON(object.strokePoints) : undefined,
+    };
   },
 
-  toJSON(_: DoRedo): unknown {
+  toJSON(message: DrawOp): unknown {
     const obj: any = {};
+    if (message.setColor !== undefined) {
+      obj.setColor = DoSetColor.toJSON(message.setColor);
+    }
+    if (message.startStroke !== undefined) {
+      obj.startStroke = DoStartStroke.toJSON(message.startStroke);
+    }
+    if (message.continueStroke !== undefined) {
+      obj.continueStroke = DoContinueStroke.toJSON(message.continueStroke);
+    }
+    if (message.clearScreen !== undefined) {
+      obj.clearScreen = DoClearScreen.toJSON(message.clearScreen);
+    }
+    if (message.undo !== undefined) {
+      obj.undo = DoUndo.toJSON(message.undo);
+    }
+    if (message.redo !== undefined) {
+      obj.redo = DoRedo.toJSON(message.redo);
+    }
+    if (message.strokePoints !== undefined) {
+      obj.strokePoints = DoStrokePoints.toJSON(message.strokePoints);
+    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<DoRedo>, I>>(base?: I): DoRedo {
-    return DoRedo.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DrawOp>, I>>(base?: I): DrawOp {
+    return DrawOp.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<DoRedo>, I>>(_: I): DoRedo {
-    const message = createBaseDoRedo();
+  fromPartial<I extends Exact<DeepPartial<DrawOp>, I>>(object: I): DrawOp {
+    const message = createBaseDrawOp();
+    message.setColor = (object.setColor !== undefined && object.setColor !== null)
+      ? DoSetColor.fromPartial(object.setColor)
+      : undefined;
+    message.startStroke = (object.startStroke !== undefined && object.startStroke !== null)
+      ? DoStartStroke.fromPartial(object.startStroke)
+      : undefined;
+    message.continueStroke = (object.continueStroke !== undefined && object.continueStroke !== null)
+      ? DoContinueStroke.fromPartial(object.continueStroke)
+      : undefined;
+    message.clearScreen = (object.clearScreen !== undefined && object.clearScreen !== null)
+      ? DoClearScreen.fromPartial(object.clearScreen)
+      : undefined;
+    message.undo = (object.undo !== undefined && object.undo !== null) ? DoUndo.fromPartial(object.undo) : undefined;
+    message.redo = (object.redo !== undefined && object.redo !== null) ? DoRedo.fromPartial(object.redo) : undefined;
+    message.strokePoints = (object.strokePoints !== undefined && object.strokePoints !== null)
+      ? DoStrokePoints.fromPartial(object.strokePoints)
+      : undefined;
     return message;
   },
 };
 
-function createBaseSeSetGame(): SeSetGame {
-  return { playerId: 0, game: undefined };
+function createBaseDoSetColor(): DoSetColor {
+  return { colorType: 0, color: "" };
 }
 
-export const SeSetGame: MessageFns<SeSetGame> = {
-  encode(message: SeSetGame, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerId !== 0) {
-      writer.uint32(8).uint32(message.playerId);
+export const DoSetColor: MessageFns<DoSetColor> = {
+  encode(message: DoSetColor, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.colorType !== 0) {
+      writer.uint32(8).int32(message.colorType);
     }
-    if (message.game !== undefined) {
-      Game.encode(message.game, writer.uint32(18).fork()).join();
+    if (message.color !== "") {
+      writer.uint32(18).string(message.color);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SeSetGame {
+  decode(input: BinaryReader | Uint8Array, length?: number): DoSetColor {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSeSetGame();
+    const message = createBaseDoSetColor();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -1922,7 +3137,7 @@ export const SeSetGame: MessageFns<SeSetGame> = {
             break;
           }
 
-          message.playerId = reader.uint32();
+          message.colorType = reader.int32() as any;
           continue;
         }
         case 2: {
@@ -1930,7 +3145,7 @@ export const SeSetGame: MessageFns<SeSetGame> = {
             break;
           }
 
-          message.game = Game.decode(reader, reader.uint32());
+          message.color = reader.string();
           continue;
         }
       }
@@ -1942,54 +3157,57 @@ export const SeSetGame: MessageFns<SeSetGame> = {
     return message;
   },
 
-  fromJSON(object: any): SeSetGame {
+  fromJSON(object: any): DoSetColor {
     return {
-      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
-      game: isSet(object.game) ? Game.fromJSON(object.game) : undefined,
-    };
+      colorType: isSet(object.colorType) ? colorTypeFromJSON(object.colorType) : 0,
+      color: isSet(object.color) ? globalThis.String(object.color) : "",
+    };
   },
 
-  toJSON(message: SeSetGame): unknown {
+  toJSON(message: DoSetColor): unknown {
     const obj: any = {};
-    if (message.playerId !== 0) {
-      obj.playerId = Math.round(message.playerId);
+    if (message.colorType !== 0) {
+      obj.colorType = colorTypeToJSON(message.colorType);
     }
-    if (message.game !== undefined) {
-      obj.game = Game.toJSON(message.game);
+    if (message.color !== "") {
+      obj.color = message.color;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SeSetGame>, I>>(base?: I): SeSetGame {
-    return SeSetGame.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DoSetColor>, I>>(base?: I): DoSetColor {
+    return DoSetColor.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SeSetGame>, I>>(object: I): SeSetGame {
-    const message = createBaseSeSetGame();
-    message.playerId = object.playerId ?? 0;
-    message.game = (object.game !== undefined && object.game !== null) ? Game.fromPartial(object.game) : undefined;
+  fromPartial<I extends Exact<DeepPartial<DoSetColor>, I>>(object: I): DoSetColor {
+    const message = createBaseDoSetColor();
+    message.colorType = object.colorType ?? 0;
+    message.color = object.color ?? "";
     return message;
   },
 };
 
-function createBaseSeError(): SeError {
-  return { seErrorType: 0, message: "" };
+function createBaseDoStartStroke(): DoStartStroke {
+  return { colorType: 0, x: 0, y: 0 };
 }
 
-export const SeError: MessageFns<SeError> = {
-  encode(message: SeError, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.seErrorType !== 0) {
-      writer.uint32(8).int32(message.seErrorType);
+export const DoStartStroke: MessageFns<DoStartStroke> = {
+  encode(message: DoStartStroke, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.colorType !== 0) {
+      writer.uint32(8).int32(message.colorType);
     }
-    if (message.message !== "") {
-      writer.uint32(18).string(message.message);
+    if (message.x !== 0) {
+      writer.uint32(21).float(message.x);
+    }
+    if (message.y !== 0) {
+      writer.uint32(29).float(message.y);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SeError {
+  decode(input: BinaryReader | Uint8Array, length?: number): DoStartStroke {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSeError();
+    const message = createBaseDoStartStroke();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -1998,15 +3216,23 @@ export const SeError: MessageFns<SeError> = {
             break;
           }
 
-          message.seErrorType = reader.int32() as any;
+          message.colorType = reader.int32() as any;
           continue;
         }
         case 2: {
-          if (tag !== 18) {
+          if (tag !== 21) {
             break;
           }
 
-          message.message = reader.string();
+          message.x = reader.float();
+          continue;
+        }
+        case 3: {
+          if (tag !== 29) {
+            break;
+          }
+
+          message.y = reader.float();
           continue;
         }
       }
@@ -2018,71 +3244,76 @@ export const SeError: MessageFns<SeError> = {
     return message;
   },
 
-  fromJSON(object: any): SeError {
+  fromJSON(object: any): DoStartStroke {
     return {
-      seErrorType: isSet(object.seErrorType) ? seErrorTypeFromJSON(object.seErrorType) : 0,
-      message: isSet(object.message) ? globalThis.String(object.message) : "",
+      colorType: isSet(object.colorType) ? colorTypeFromJSON(object.colorType) : 0,
+      x: isSet(object.x) ? globalThis.Number(object.x) : 0,
+      y: isSet(object.y) ? globalThis.Number(object.y) : 0,
     };
   },
 
-  toJSON(message: SeError): unknown {
+  toJSON(message: DoStartStroke): unknown {
     const obj: any = {};
-    if (message.seErrorType !== 0) {
-      obj.seErrorType = seErrorTypeToJSON(message.seErrorType);
+    if (message.colorType !== 0) {
+      obj.colorType = colorTypeToJSON(message.colorType);
     }
-    if (message.message !== "") {
-      obj.message = message.message;
+    if (message.x !== 0) {
+      obj.x = message.x;
+    }
+    if (message.y !== 0) {
+      obj.y = message.y;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SeError>, I>>(base?: I): SeError {
-    return SeError.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DoStartStroke>, I>>(base?: I): DoStartStroke {
+    return DoStartStroke.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SeError>, I>>(object: I): SeError {
-    const message = createBaseSeError();
-    message.seErrorType = object.seErrorType ?? 0;
-    message.message = object.message ?? "";
+  fromPartial<I extends Exact<DeepPartial<DoStartStroke>, I>>(object: I): DoStartStroke {
+    const message = createBaseDoStartStroke();
+    message.colorType = object.colorType ?? 0;
+    message.x = object.x ?? 0;
+    message.y = object.y ?? 0;
     return message;
   },
 };
 
-function createBaseSePlayerJoin(): SePlayerJoin {
-  return { playerId: 0, name: "" };
+function createBaseDoContinueStroke(): DoContinueStroke {
+  return { x: 0, y: 0 };
 }
 
-export const SePlayerJoin: MessageFns<SePlayerJoin> = {
-  encode(message: SePlayerJoin, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerId !== 0) {
-      writer.uint32(8).uint32(message.playerId);
+export const DoContinueStroke: MessageFns<DoContinueStroke> = {
+  encode(message: DoContinueStroke, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.x !== 0) {
+      writer.uint32(13).float(message.x);
     }
-    if (message.name !== "") {
-      writer.uint32(18).string(message.name);
+    if (message.y !== 0) {
+      writer.uint32(21).float(message.y);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerJoin {
+  decode(input: BinaryReader | Uint8Array, length?: number): DoContinueStroke {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerJoin();
+    const message = createBaseDoContinueStroke();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 8) {
+          if (tag !== 13) {
             break;
           }
 
-          message.playerId = reader.uint32();
+          message.x = reader.float();
           continue;
         }
         case 2: {
-          if (tag !== 18) {
+          if (tag !== 21) {
             break;
           }
 
-          message.name = reader.string();
+          message.y = reader.float();
           continue;
         }
       }
@@ -2094,51 +3325,65 @@ export const SePlayerJoin: MessageFns<SePlayerJoin> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerJoin {
+  fromJSON(object: any): DoContinueStroke {
     return {
-      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
-      name: isSet(object.name) ? globalThis.String(object.name) : "",
+      x: isSet(object.x) ? globalThis.Number(object.x) : 0,
+      y: isSet(object.y) ? globalThis.Number(object.y) : 0,
     };
   },
 
-  toJSON(message: SePlayerJoin): unknown {
+  toJSON(message: DoContinueStroke): unknown {
     const obj: any = {};
-    if (message.playerId !== 0) {
-      obj.playerId = Math.round(message.playerId);
+    if (message.x !== 0) {
+      obj.x = message.x;
     }
-    if (message.name !== "") {
-      obj.name = message.name;
+    if (message.y !== 0) {
+      obj.y = message.y;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerJoin>, I>>(base?: I): SePlayerJoin {
-    return SePlayerJoin.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DoContinueStroke>, I>>(base?: I): DoContinueStroke {
+    return DoContinueStroke.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerJoin>, I>>(object: I): SePlayerJoin {
-    const message = createBaseSePlayerJoin();
-    message.playerId = object.playerId ?? 0;
-    message.name = object.name ?? "";
+  fromPartial<I extends Exact<DeepPartial<DoContinueStroke>, I>>(object: I): DoContinueStroke {
+    const message = createBaseDoContinueStroke();
+    message.x = object.x ?? 0;
+    message.y = object.y ?? 0;
     return message;
   },
 };
 
-function createBaseSePlayerLeave(): SePlayerLeave {
-  return { playerId: 0 };
+function createBaseDoStrokePoints(): DoStrokePoints {
+  return { start: false, colorType: 0, x: 0, y: 0, deltas: [] };
 }
 
-export const SePlayerLeave: MessageFns<SePlayerLeave> = {
-  encode(message: SePlayerLeave, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerId !== 0) {
-      writer.uint32(8).uint32(message.playerId);
+export const DoStrokePoints: MessageFns<DoStrokePoints> = {
+  encode(message: DoStrokePoints, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.start !== false) {
+      writer.uint32(8).bool(message.start);
+    }
+    if (message.colorType !== 0) {
+      writer.uint32(16).int32(message.colorType);
+    }
+    if (message.x !== 0) {
+      writer.uint32(24).uint32(message.x);
+    }
+    if (message.y !== 0) {
+      writer.uint32(32).uint32(message.y);
+    }
+    writer.uint32(42).fork();
+    for (const v of message.deltas) {
+      writer.sint32(v);
     }
+    writer.join();
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerLeave {
+  decode(input: BinaryReader | Uint8Array, length?: number): DoStrokePoints {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerLeave();
+    const message = createBaseDoStrokePoints();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2147,9 +3392,51 @@ export const SePlayerLeave: MessageFns<SePlayerLeave> = {
             break;
           }
 
-          message.playerId = reader.uint32();
+          message.start = reader.bool();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.colorType = reader.int32() as any;
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.x = reader.uint32();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.y = reader.uint32();
           continue;
         }
+        case 5: {
+          if (tag === 40) {
+            message.deltas.push(reader.sint32());
+
+            continue;
+          }
+
+          if (tag === 42) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.deltas.push(reader.sint32());
+            }
+
+            continue;
+          }
+
+          break;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2159,55 +3446,66 @@ export const SePlayerLeave: MessageFns<SePlayerLeave> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerLeave {
-    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
+  fromJSON(object: any): DoStrokePoints {
+    return {
+      start: isSet(object.start) ? globalThis.Boolean(object.start) : false,
+      colorType: isSet(object.colorType) ? colorTypeFromJSON(object.colorType) : 0,
+      x: isSet(object.x) ? globalThis.Number(object.x) : 0,
+      y: isSet(object.y) ? globalThis.Number(object.y) : 0,
+      deltas: globalThis.Array.isArray(object?.deltas) ? object.deltas.map((e: any) => globalThis.Number(e)) : [],
+    };
   },
 
-  toJSON(message: SePlayerLeave): unknown {
+  toJSON(message: DoStrokePoints): unknown {
     const obj: any = {};
-    if (message.playerId !== 0) {
-      obj.playerId = Math.round(message.playerId);
+    if (message.start !== false) {
+      obj.start = message.start;
+    }
+    if (message.colorType !== 0) {
+      obj.colorType = colorTypeToJSON(message.colorType);
+    }
+    if (message.x !== 0) {
+      obj.x = Math.round(message.x);
+    }
+    if (message.y !== 0) {
+      obj.y = Math.round(message.y);
+    }
+    if (message.deltas?.length) {
+      obj.deltas = message.deltas.map((e) => Math.round(e));
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerLeave>, I>>(base?: I): SePlayerLeave {
-    return SePlayerLeave.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DoStrokePoints>, I>>(base?: I): DoStrokePoints {
+    return DoStrokePoints.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerLeave>, I>>(object: I): SePlayerLeave {
-    const message = createBaseSePlayerLeave();
-    message.playerId = object.playerId ?? 0;
+  fromPartial<I extends Exact<DeepPartial<DoStrokePoints>, I>>(object: I): DoStrokePoints {
+    const message = createBaseDoStrokePoints();
+    message.start = object.start ?? false;
+    message.colorType = object.colorType ?? 0;
+    message.x = object.x ?? 0;
+    message.y = object.y ?? 0;
+    message.deltas = object.deltas?.map((e) => e) || [];
     return message;
   },
 };
 
-function createBaseSePlayerConnect(): SePlayerConnect {
-  return { playerId: 0 };
+function createBaseDoClearScreen(): DoClearScreen {
+  return {};
 }
 
-export const SePlayerConnect: MessageFns<SePlayerConnect> = {
-  encode(message: SePlayerConnect, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerId !== 0) {
-      writer.uint32(8).uint32(message.playerId);
-    }
+export const DoClearScreen: MessageFns<DoClearScreen> = {
+  encode(_: DoClearScreen, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerConnect {
+  decode(input: BinaryReader | Uint8Array, length?: number): DoClearScreen {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerConnect();
+    const message = createBaseDoClearScreen();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
-        case 1: {
-          if (tag !== 8) {
-            break;
-          }
-
-          message.playerId = reader.uint32();
-          continue;
-        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2217,55 +3515,40 @@ export const SePlayerConnect: MessageFns<SePlayerConnect> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerConnect {
-    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
+  fromJSON(_: any): DoClearScreen {
+    return {};
   },
 
-  toJSON(message: SePlayerConnect): unknown {
+  toJSON(_: DoClearScreen): unknown {
     const obj: any = {};
-    if (message.playerId !== 0) {
-      obj.playerId = Math.round(message.playerId);
-    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerConnect>, I>>(base?: I): SePlayerConnect {
-    return SePlayerConnect.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DoClearScreen>, I>>(base?: I): DoClearScreen {
+    return DoClearScreen.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerConnect>, I>>(object: I): SePlayerConnect {
-    const message = createBaseSePlayerConnect();
-    message.playerId = object.playerId ?? 0;
+  fromPartial<I extends Exact<DeepPartial<DoClearScreen>, I>>(_: I): DoClearScreen {
+    const message = createBaseDoClearScreen();
     return message;
   },
 };
 
-function createBaseSePlayerDisconnect(): SePlayerDisconnect {
-  return { playerId: 0 };
+function createBaseDoUndo(): DoUndo {
+  return {};
 }
 
-export const SePlayerDisconnect: MessageFns<SePlayerDisconnect> = {
-  encode(message: SePlayerDisconnect, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerId !== 0) {
-      writer.uint32(8).uint32(message.playerId);
-    }
+export const DoUndo: MessageFns<DoUndo> = {
+  encode(_: DoUndo, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerDisconnect {
+  decode(input: BinaryReader | Uint8Array, length?: number): DoUndo {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerDisconnect();
+    const message = createBaseDoUndo();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
-        case 1: {
-          if (tag !== 8) {
-            break;
-          }
-
-          message.playerId = reader.uint32();
-          continue;
-        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2275,66 +3558,40 @@ export const SePlayerDisconnect: MessageFns<SePlayerDisconnect> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerDisconnect {
-    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
+  fromJSON(_: any): DoUndo {
+    return {};
   },
 
-  toJSON(message: SePlayerDisconnect): unknown {
+  toJSON(_: DoUndo): unknown {
     const obj: any = {};
-    if (message.playerId !== 0) {
-      obj.playerId = Math.round(message.playerId);
-    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerDisconnect>, I>>(base?: I): SePlayerDisconnect {
-    return SePlayerDisconnect.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DoUndo>, I>>(base?: I): DoUndo {
+    return DoUndo.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerDisconnect>, I>>(object: I): SePlayerDisconnect {
-    const message = createBaseSePlayerDisconnect();
-    message.playerId = object.playerId ?? 0;
+  fromPartial<I extends Exact<DeepPartial<DoUndo>, I>>(_: I): DoUndo {
+    const message = createBaseDoUndo();
     return message;
   },
 };
 
-function createBaseSePlayerRename(): SePlayerRename {
-  return { playerId: 0, name: "" };
+function createBaseDoRedo(): DoRedo {
+  return {};
 }
 
-export const SePlayerRename: MessageFns<SePlayerRename> = {
-  encode(message: SePlayerRename, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerId !== 0) {
-      writer.uint32(8).uint32(message.playerId);
-    }
-    if (message.name !== "") {
-      writer.uint32(18).string(message.name);
-    }
+export const DoRedo: MessageFns<DoRedo> = {
+  encode(_: DoRedo, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerRename {
+  decode(input: BinaryReader | Uint8Array, length?: number): DoRedo {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerRename();
+    const message = createBaseDoRedo();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
-        case 1: {
-          if (tag !== 8) {
-            break;
-          }
-
-          message.playerId = reader.uint32();
-          continue;
-        }
-        case 2: {
-          if (tag !== 18) {
-            break;
-          }
-
-          message.name = reader.string();
-          continue;
-        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -2344,54 +3601,46 @@ export const SePlayerRename: MessageFns<SePlayerRename> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerRename {
-    return {
-      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
-      name: isSet(object.name) ? globalThis.String(object.name) : "",
-    };
+  fromJSON(_: any): DoRedo {
+    return {};
   },
 
-  toJSON(message: SePlayerRename): unknown {
+  toJSON(_: DoRedo): unknown {
     const obj: any = {};
-    if (message.playerId !== 0) {
-      obj.playerId = Math.round(message.playerId);
-    }
-    if (message.name !== "") {
-      obj.name = message.name;
-    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerRename>, I>>(base?: I): SePlayerRename {
-    return SePlayerRename.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<DoRedo>, I>>(base?: I): DoRedo {
+    return DoRedo.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerRename>, I>>(object: I): SePlayerRename {
-    const message = createBaseSePlayerRename();
-    message.playerId = object.playerId ?? 0;
-    message.name = object.name ?? "";
+  fromPartial<I extends Exact<DeepPartial<DoRedo>, I>>(_: I): DoRedo {
+    const message = createBaseDoRedo();
     return message;
   },
 };
 
-function createBaseSePlayerIncRoundScore(): SePlayerIncRoundScore {
-  return { playerId: 0, incBy: 0 };
+function createBaseSeSetGame(): SeSetGame {
+  return { playerId: 0, game: undefined, spectating: false };
 }
 
-export const SePlayerIncRoundScore: MessageFns<SePlayerIncRoundScore> = {
-  encode(message: SePlayerIncRoundScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+export const SeSetGame: MessageFns<SeSetGame> = {
+  encode(message: SeSetGame, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     if (message.playerId !== 0) {
       writer.uint32(8).uint32(message.playerId);
     }
-    if (message.incBy !== 0) {
-      writer.uint32(16).uint32(message.incBy);
+    if (message.game !== undefined) {
+      Game.encode(message.game, writer.uint32(18).fork()).join();
+    }
+    if (message.spectating !== false) {
+      writer.uint32(24).bool(message.spectating);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerIncRoundScore {
+  decode(input: BinaryReader | Uint8Array, length?: number): SeSetGame {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerIncRoundScore();
+    const message = createBaseSeSetGame();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2404,11 +3653,19 @@ export const SePlayerIncRoundScore: MessageFns<SePlayerIncRoundScore> = {
           continue;
         }
         case 2: {
-          if (tag !== 16) {
+          if (tag !== 18) {
             break;
           }
 
-          message.incBy = reader.uint32();
+          message.game = Game.decode(reader, reader.uint32());
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.spectating = reader.bool();
           continue;
         }
       }
@@ -2420,54 +3677,59 @@ export const SePlayerIncRoundScore: MessageFns<SePlayerIncRoundScore> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerIncRoundScore {
+  fromJSON(object: any): SeSetGame {
     return {
       playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
-      incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0,
+      game: isSet(object.game) ? Game.fromJSON(object.game) : undefined,
+      spectating: isSet(object.spectating) ? globalThis.Boolean(object.spectating) : false,
     };
   },
 
-  toJSON(message: SePlayerIncRoundScore): unknown {
+  toJSON(message: SeSetGame): unknown {
     const obj: any = {};
     if (message.playerId !== 0) {
       obj.playerId = Math.round(message.playerId);
     }
-    if (message.incBy !== 0) {
-      obj.incBy = Math.round(message.incBy);
+    if (message.game !== undefined) {
+      obj.game = Game.toJSON(message.game);
+    }
+    if (message.spectating !== false) {
+      obj.spectating = message.spectating;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerIncRoundScore>, I>>(base?: I): SePlayerIncRoundScore {
-    return SePlayerIncRoundScore.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SeSetGame>, I>>(base?: I): SeSetGame {
+    return SeSetGame.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerIncRoundScore>, I>>(object: I): SePlayerIncRoundScore {
-    const message = createBaseSePlayerIncRoundScore();
+  fromPartial<I extends Exact<DeepPartial<SeSetGame>, I>>(object: I): SeSetGame {
+    const message = createBaseSeSetGame();
     message.playerId = object.playerId ?? 0;
-    message.incBy = object.incBy ?? 0;
+    message.game = (object.game !== undefined && object.game !== null) ? Game.fromPartial(object.game) : undefined;
+    message.spectating = object.spectating ?? false;
     return message;
   },
 };
 
-function createBaseSePlayerIncDrawScore(): SePlayerIncDrawScore {
-  return { drawerId: 0, incBy: 0 };
+function createBaseSeError(): SeError {
+  return { seErrorType: 0, message: "" };
 }
 
-export const SePlayerIncDrawScore: MessageFns<SePlayerIncDrawScore> = {
-  encode(message: SePlayerIncDrawScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.drawerId !== 0) {
-      writer.uint32(8).uint32(message.drawerId);
+export const SeError: MessageFns<SeError> = {
+  encode(message: SeError, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.seErrorType !== 0) {
+      writer.uint32(8).int32(message.seErrorType);
     }
-    if (message.incBy !== 0) {
-      writer.uint32(16).uint32(message.incBy);
+    if (message.message !== "") {
+      writer.uint32(18).string(message.message);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerIncDrawScore {
+  decode(input: BinaryReader | Uint8Array, length?: number): SeError {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerIncDrawScore();
+    const message = createBaseSeError();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2476,15 +3738,15 @@ export const SePlayerIncDrawScore: MessageFns<SePlayerIncDrawScore> = {
             break;
           }
 
-          message.drawerId = reader.uint32();
+          message.seErrorType = reader.int32() as any;
           continue;
         }
         case 2: {
-          if (tag !== 16) {
+          if (tag !== 18) {
             break;
           }
 
-          message.incBy = reader.uint32();
+          message.message = reader.string();
           continue;
         }
       }
@@ -2496,54 +3758,54 @@ export const SePlayerIncDrawScore: MessageFns<SePlayerIncDrawScore> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerIncDrawScore {
+  fromJSON(object: any): SeError {
     return {
-      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
-      incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0,
+      seErrorType: isSet(object.seErrorType) ? seErrorTypeFromJSON(object.seErrorType) : 0,
+      message: isSet(object.message) ? globalThis.String(object.message) : "",
     };
   },
 
-  toJSON(message: SePlayerIncDrawScore): unknown {
+  toJSON(message: SeError): unknown {
     const obj: any = {};
-    if (message.drawerId !== 0) {
-      obj.drawerId = Math.round(message.drawerId);
+    if (message.seErrorType !== 0) {
+      obj.seErrorType = seErrorTypeToJSON(message.seErrorType);
     }
-    if (message.incBy !== 0) {
-      obj.incBy = Math.round(message.incBy);
+    if (message.message !== "") {
+      obj.message = message.message;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerIncDrawScore>, I>>(base?: I): SePlayerIncDrawScore {
-    return SePlayerIncDrawScore.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SeError>, I>>(base?: I): SeError {
+    return SeError.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerIncDrawScore>, I>>(object: I): SePlayerIncDrawScore {
-    const message = createBaseSePlayerIncDrawScore();
-    message.drawerId = object.drawerId ?? 0;
-    message.incBy = object.incBy ?? 0;
+  fromPartial<I extends Exact<DeepPartial<SeError>, I>>(object: I): SeError {
+    const message = createBaseSeError();
+    message.seErrorType = object.seErrorType ?? 0;
+    message.message = object.message ?? "";
     return message;
   },
 };
 
-function createBaseSePlayerIncGuessScore(): SePlayerIncGuessScore {
-  return { guesserId: 0, incBy: 0 };
+function createBaseSePlayerJoin(): SePlayerJoin {
+  return { playerId: 0, name: "" };
 }
 
-export const SePlayerIncGuessScore: MessageFns<SePlayerIncGuessScore> = {
-  encode(message: SePlayerIncGuessScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.guesserId !== 0) {
-      writer.uint32(8).uint32(message.guesserId);
+export const SePlayerJoin: MessageFns<SePlayerJoin> = {
+  encode(message: SePlayerJoin, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
-    if (message.incBy !== 0) {
-      writer.uint32(16).uint32(message.incBy);
+    if (message.name !== "") {
+      writer.uint32(18).string(message.name);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerIncGuessScore {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerJoin {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerIncGuessScore();
+    const message = createBaseSePlayerJoin();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2552,15 +3814,15 @@ export const SePlayerIncGuessScore: MessageFns<SePlayerIncGuessScore> = {
             break;
           }
 
-          message.guesserId = reader.uint32();
+          message.playerId = reader.uint32();
           continue;
         }
         case 2: {
-          if (tag !== 16) {
+          if (tag !== 18) {
             break;
           }
 
-          message.incBy = reader.uint32();
+          message.name = reader.string();
           continue;
         }
       }
@@ -2572,51 +3834,51 @@ export const SePlayerIncGuessScore: MessageFns<SePlayerIncGuessScore> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerIncGuessScore {
+  fromJSON(object: any): SePlayerJoin {
     return {
-      guesserId: isSet(object.guesserId) ? globalThis.Number(object.guesserId) : 0,
-      incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0,
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
     };
   },
 
-  toJSON(message: SePlayerIncGuessScore): unknown {
+  toJSON(message: SePlayerJoin): unknown {
     const obj: any = {};
-    if (message.guesserId !== 0) {
-      obj.guesserId = Math.round(message.guesserId);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
-    if (message.incBy !== 0) {
-      obj.incBy = Math.round(message.incBy);
+    if (message.name !== "") {
+      obj.name = message.name;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerIncGuessScore>, I>>(base?: I): SePlayerIncGuessScore {
-    return SePlayerIncGuessScore.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerJoin>, I>>(base?: I): SePlayerJoin {
+    return SePlayerJoin.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerIncGuessScore>, I>>(object: I): SePlayerIncGuessScore {
-    const message = createBaseSePlayerIncGuessScore();
-    message.guesserId = object.guesserId ?? 0;
-    message.incBy = object.incBy ?? 0;
+  fromPartial<I extends Exact<DeepPartial<SePlayerJoin>, I>>(object: I): SePlayerJoin {
+    const message = createBaseSePlayerJoin();
+    message.playerId = object.playerId ?? 0;
+    message.name = object.name ?? "";
     return message;
   },
 };
 
-function createBaseSeRoundIncDrawScore(): SeRoundIncDrawScore {
-  return { incBy: 0 };
+function createBaseSePlayerLeave(): SePlayerLeave {
+  return { playerId: 0 };
 }
 
-export const SeRoundIncDrawScore: MessageFns<SeRoundIncDrawScore> = {
-  encode(message: SeRoundIncDrawScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.incBy !== 0) {
-      writer.uint32(8).uint32(message.incBy);
+export const SePlayerLeave: MessageFns<SePlayerLeave> = {
+  encode(message: SePlayerLeave, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundIncDrawScore {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerLeave {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSeRoundIncDrawScore();
+    const message = createBaseSePlayerLeave();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2625,7 +3887,7 @@ export const SeRoundIncDrawScore: MessageFns<SeRoundIncDrawScore> = {
             break;
           }
 
-          message.incBy = reader.uint32();
+          message.playerId = reader.uint32();
           continue;
         }
       }
@@ -2637,44 +3899,44 @@ export const SeRoundIncDrawScore: MessageFns<SeRoundIncDrawScore> = {
     return message;
   },
 
-  fromJSON(object: any): SeRoundIncDrawScore {
-    return { incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0 };
+  fromJSON(object: any): SePlayerLeave {
+    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
   },
 
-  toJSON(message: SeRoundIncDrawScore): unknown {
+  toJSON(message: SePlayerLeave): unknown {
     const obj: any = {};
-    if (message.incBy !== 0) {
-      obj.incBy = Math.round(message.incBy);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SeRoundIncDrawScore>, I>>(base?: I): SeRoundIncDrawScore {
-    return SeRoundIncDrawScore.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerLeave>, I>>(base?: I): SePlayerLeave {
+    return SePlayerLeave.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SeRoundIncDrawScore>, I>>(object: I): SeRoundIncDrawScore {
-    const message = createBaseSeRoundIncDrawScore();
-    message.incBy = object.incBy ?? 0;
+  fromPartial<I extends Exact<DeepPartial<SePlayerLeave>, I>>(object: I): SePlayerLeave {
+    const message = createBaseSePlayerLeave();
+    message.playerId = object.playerId ?? 0;
     return message;
   },
 };
 
-function createBaseSeRoundIncGuessScore(): SeRoundIncGuessScore {
-  return { incBy: 0 };
+function createBaseSePlayerConnect(): SePlayerConnect {
+  return { playerId: 0 };
 }
 
-export const SeRoundIncGuessScore: MessageFns<SeRoundIncGuessScore> = {
-  encode(message: SeRoundIncGuessScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.incBy !== 0) {
-      writer.uint32(8).uint32(message.incBy);
+export const SePlayerConnect: MessageFns<SePlayerConnect> = {
+  encode(message: SePlayerConnect, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundIncGuessScore {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerConnect {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSeRoundIncGuessScore();
+    const message = createBaseSePlayerConnect();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2683,7 +3945,7 @@ export const SeRoundIncGuessScore: MessageFns<SeRoundIncGuessScore> = {
             break;
           }
 
-          message.incBy = reader.uint32();
+          message.playerId = reader.uint32();
           continue;
         }
       }
@@ -2695,47 +3957,44 @@ export const SeRoundIncGuessScore: MessageFns<SeRoundIncGuessScore> = {
     return message;
   },
 
-  fromJSON(object: any): SeRoundIncGuessScore {
-    return { incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0 };
+  fromJSON(object: any): SePlayerConnect {
+    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
   },
 
-  toJSON(message: SeRoundIncGuessScore): unknown {
+  toJSON(message: SePlayerConnect): unknown {
     const obj: any = {};
-    if (message.incBy !== 0) {
-      obj.incBy = Math.round(message.incBy);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SeRoundIncGuessScore>, I>>(base?: I): SeRoundIncGuessScore {
-    return SeRoundIncGuessScore.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerConnect>, I>>(base?: I): SePlayerConnect {
+    return SePlayerConnect.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SeRoundIncGuessScore>, I>>(object: I): SeRoundIncGuessScore {
-    const message = createBaseSeRoundIncGuessScore();
-    message.incBy = object.incBy ?? 0;
+  fromPartial<I extends Exact<DeepPartial<SePlayerConnect>, I>>(object: I): SePlayerConnect {
+    const message = createBaseSePlayerConnect();
+    message.playerId = object.playerId ?? 0;
     return message;
   },
 };
 
-function createBaseSePlayerDrawOp(): SePlayerDrawOp {
-  return { drawerId: 0, drawOp: undefined };
+function createBaseSePlayerDisconnect(): SePlayerDisconnect {
+  return { playerId: 0 };
 }
 
-export const SePlayerDrawOp: MessageFns<SePlayerDrawOp> = {
-  encode(message: SePlayerDrawOp, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.drawerId !== 0) {
-      writer.uint32(8).uint32(message.drawerId);
-    }
-    if (message.drawOp !== undefined) {
-      DrawOp.encode(message.drawOp, writer.uint32(18).fork()).join();
+export const SePlayerDisconnect: MessageFns<SePlayerDisconnect> = {
+  encode(message: SePlayerDisconnect, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerDrawOp {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerDisconnect {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerDrawOp();
+    const message = createBaseSePlayerDisconnect();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2744,15 +4003,7 @@ export const SePlayerDrawOp: MessageFns<SePlayerDrawOp> = {
             break;
           }
 
-          message.drawerId = reader.uint32();
-          continue;
-        }
-        case 2: {
-          if (tag !== 18) {
-            break;
-          }
-
-          message.drawOp = DrawOp.decode(reader, reader.uint32());
+          message.playerId = reader.uint32();
           continue;
         }
       }
@@ -2764,68 +4015,47 @@ export const SePlayerDrawOp: MessageFns<SePlayerDrawOp> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerDrawOp {
-    return {
-      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
-      drawOp: isSet(object.drawOp) ? DrawOp.fromJSON(object.drawOp) : undefined,
-    };
+  fromJSON(object: any): SePlayerDisconnect {
+    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
   },
 
-  toJSON(message: SePlayerDrawOp): unknown {
+  toJSON(message: SePlayerDisconnect): unknown {
     const obj: any = {};
-    if (message.drawerId !== 0) {
-      obj.drawerId = Math.round(message.drawerId);
-    }
-    if (message.drawOp !== undefined) {
-      obj.drawOp = DrawOp.toJSON(message.drawOp);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerDrawOp>, I>>(base?: I): SePlayerDrawOp {
-    return SePlayerDrawOp.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerDisconnect>, I>>(base?: I): SePlayerDisconnect {
+    return SePlayerDisconnect.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerDrawOp>, I>>(object: I): SePlayerDrawOp {
-    const message = createBaseSePlayerDrawOp();
-    message.drawerId = object.drawerId ?? 0;
-    message.drawOp = (object.drawOp !== undefined && object.drawOp !== null)
-      ? DrawOp.fromPartial(object.drawOp)
-      : undefined;
+  fromPartial<I extends Exact<DeepPartial<SePlayerDisconnect>, I>>(object: I): SePlayerDisconnect {
+    const message = createBaseSePlayerDisconnect();
+    message.playerId = object.playerId ?? 0;
     return message;
   },
 };
 
-function createBaseSeRoundNew(): SeRoundNew {
-  return { roundId: 0, drawerId: 0, easyWord: 0, hardWord: 0, startingPhase: 0, phaseEndsAt: 0 };
+function createBaseSePlayerRename(): SePlayerRename {
+  return { playerId: 0, name: "" };
 }
 
-export const SeRoundNew: MessageFns<SeRoundNew> = {
-  encode(message: SeRoundNew, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.roundId !== 0) {
-      writer.uint32(8).uint32(message.roundId);
-    }
-    if (message.drawerId !== 0) {
-      writer.uint32(16).uint32(message.drawerId);
-    }
-    if (message.easyWord !== 0) {
-      writer.uint32(24).uint32(message.easyWord);
-    }
-    if (message.hardWord !== 0) {
-      writer.uint32(32).uint32(message.hardWord);
-    }
-    if (message.startingPhase !== 0) {
-      writer.uint32(40).int32(message.startingPhase);
+export const SePlayerRename: MessageFns<SePlayerRename> = {
+  encode(message: SePlayerRename, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
-    if (message.phaseEndsAt !== 0) {
-      writer.uint32(48).uint64(message.phaseEndsAt);
+    if (message.name !== "") {
+      writer.uint32(18).string(message.name);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundNew {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerRename {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSeRoundNew();
+    const message = createBaseSePlayerRename();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2834,47 +4064,15 @@ export const SeRoundNew: MessageFns<SeRoundNew> = {
             break;
           }
 
-          message.roundId = reader.uint32();
+          message.playerId = reader.uint32();
           continue;
         }
         case 2: {
-          if (tag !== 16) {
-            break;
-          }
-
-          message.drawerId = reader.uint32();
-          continue;
-        }
-        case 3: {
-          if (tag !== 24) {
-            break;
-          }
-
-          message.easyWord = reader.uint32();
-          continue;
-        }
-        case 4: {
-          if (tag !== 32) {
-            break;
-          }
-
-          message.hardWord = reader.uint32();
-          continue;
-        }
-        case 5: {
-          if (tag !== 40) {
-            break;
-          }
-
-          message.startingPhase = reader.int32() as any;
-          continue;
-        }
-        case 6: {
-          if (tag !== 48) {
+          if (tag !== 18) {
             break;
           }
 
-          message.phaseEndsAt = longToNumber(reader.uint64());
+          message.name = reader.string();
           continue;
         }
       }
@@ -2886,74 +4084,54 @@ export const SeRoundNew: MessageFns<SeRoundNew> = {
     return message;
   },
 
-  fromJSON(object: any): SeRoundNew {
+  fromJSON(object: any): SePlayerRename {
     return {
-      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
-      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
-      easyWord: isSet(object.easyWord) ? globalThis.Number(object.easyWord) : 0,
-      hardWord: isSet(object.hardWord) ? globalThis.Number(object.hardWord) : 0,
-      startingPhase: isSet(object.startingPhase) ? phaseFromJSON(object.startingPhase) : 0,
-      phaseEndsAt: isSet(object.phaseEndsAt) ? globalThis.Number(object.phaseEndsAt) : 0,
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
     };
   },
 
-  toJSON(message: SeRoundNew): unknown {
+  toJSON(message: SePlayerRename): unknown {
     const obj: any = {};
-    if (message.roundId !== 0) {
-      obj.roundId = Math.round(message.roundId);
-    }
-    if (message.drawerId !== 0) {
-      obj.drawerId = Math.round(message.drawerId);
-    }
-    if (message.easyWord !== 0) {
-      obj.easyWord = Math.round(message.easyWord);
-    }
-    if (message.hardWord !== 0) {
-      obj.hardWord = Math.round(message.hardWord);
-    }
-    if (message.startingPhase !== 0) {
-      obj.startingPhase = phaseToJSON(message.startingPhase);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
-    if (message.phaseEndsAt !== 0) {
-      obj.phaseEndsAt = Math.round(message.phaseEndsAt);
+    if (message.name !== "") {
+      obj.name = message.name;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SeRoundNew>, I>>(base?: I): SeRoundNew {
-    return SeRoundNew.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerRename>, I>>(base?: I): SePlayerRename {
+    return SePlayerRename.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SeRoundNew>, I>>(object: I): SeRoundNew {
-    const message = createBaseSeRoundNew();
-    message.roundId = object.roundId ?? 0;
-    message.drawerId = object.drawerId ?? 0;
-    message.easyWord = object.easyWord ?? 0;
-    message.hardWord = object.hardWord ?? 0;
-    message.startingPhase = object.startingPhase ?? 0;
-    message.phaseEndsAt = object.phaseEndsAt ?? 0;
-    return message;
+  fromPartial<I extends Exact<DeepPartial<SePlayerRename>, I>>(object: I): SePlayerRename {
+    const message = createBaseSePlayerRename();
+    message.playerId = object.playerId ?? 0;
+    message.name = object.name ?? "";
+    return message;
   },
 };
 
-function createBaseSeRoundChangePhase(): SeRoundChangePhase {
-  return { phase: 0, phaseEndsAt: 0 };
+function createBaseSePlayerIncRoundScore(): SePlayerIncRoundScore {
+  return { playerId: 0, incBy: 0 };
 }
 
-export const SeRoundChangePhase: MessageFns<SeRoundChangePhase> = {
-  encode(message: SeRoundChangePhase, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.phase !== 0) {
-      writer.uint32(8).int32(message.phase);
+export const SePlayerIncRoundScore: MessageFns<SePlayerIncRoundScore> = {
+  encode(message: SePlayerIncRoundScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
-    if (message.phaseEndsAt !== 0) {
-      writer.uint32(16).uint64(message.phaseEndsAt);
+    if (message.incBy !== 0) {
+      writer.uint32(16).uint32(message.incBy);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundChangePhase {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerIncRoundScore {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSeRoundChangePhase();
+    const message = createBaseSePlayerIncRoundScore();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -2962,7 +4140,7 @@ export const SeRoundChangePhase: MessageFns<SeRoundChangePhase> = {
             break;
           }
 
-          message.phase = reader.int32() as any;
+          message.playerId = reader.uint32();
           continue;
         }
         case 2: {
@@ -2970,7 +4148,7 @@ export const SeRoundChangePhase: MessageFns<SeRoundChangePhase> = {
             break;
           }
 
-          message.phaseEndsAt = longToNumber(reader.uint64());
+          message.incBy = reader.uint32();
           continue;
         }
       }
@@ -2982,54 +4160,54 @@ export const SeRoundChangePhase: MessageFns<SeRoundChangePhase> = {
     return message;
   },
 
-  fromJSON(object: any): SeRoundChangePhase {
+  fromJSON(object: any): SePlayerIncRoundScore {
     return {
-      phase: isSet(object.phase) ? phaseFromJSON(object.phase) : 0,
-      phaseEndsAt: isSet(object.phaseEndsAt) ? globalThis.Number(object.phaseEndsAt) : 0,
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0,
     };
   },
 
-  toJSON(message: SeRoundChangePhase): unknown {
+  toJSON(message: SePlayerIncRoundScore): unknown {
     const obj: any = {};
-    if (message.phase !== 0) {
-      obj.phase = phaseToJSON(message.phase);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
-    if (message.phaseEndsAt !== 0) {
-      obj.phaseEndsAt = Math.round(message.phaseEndsAt);
+    if (message.incBy !== 0) {
+      obj.incBy = Math.round(message.incBy);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SeRoundChangePhase>, I>>(base?: I): SeRoundChangePhase {
-    return SeRoundChangePhase.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerIncRoundScore>, I>>(base?: I): SePlayerIncRoundScore {
+    return SePlayerIncRoundScore.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SeRoundChangePhase>, I>>(object: I): SeRoundChangePhase {
-    const message = createBaseSeRoundChangePhase();
-    message.phase = object.phase ?? 0;
-    message.phaseEndsAt = object.phaseEndsAt ?? 0;
+  fromPartial<I extends Exact<DeepPartial<SePlayerIncRoundScore>, I>>(object: I): SePlayerIncRoundScore {
+    const message = createBaseSePlayerIncRoundScore();
+    message.playerId = object.playerId ?? 0;
+    message.incBy = object.incBy ?? 0;
     return message;
   },
 };
 
-function createBaseSePlayerChooseWord(): SePlayerChooseWord {
-  return { drawerId: 0, choice: 0 };
+function createBaseSePlayerIncDrawScore(): SePlayerIncDrawScore {
+  return { drawerId: 0, incBy: 0 };
 }
 
-export const SePlayerChooseWord: MessageFns<SePlayerChooseWord> = {
-  encode(message: SePlayerChooseWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+export const SePlayerIncDrawScore: MessageFns<SePlayerIncDrawScore> = {
+  encode(message: SePlayerIncDrawScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     if (message.drawerId !== 0) {
       writer.uint32(8).uint32(message.drawerId);
     }
-    if (message.choice !== 0) {
-      writer.uint32(16).int32(message.choice);
+    if (message.incBy !== 0) {
+      writer.uint32(16).uint32(message.incBy);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerChooseWord {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerIncDrawScore {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerChooseWord();
+    const message = createBaseSePlayerIncDrawScore();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -3046,7 +4224,7 @@ export const SePlayerChooseWord: MessageFns<SePlayerChooseWord> = {
             break;
           }
 
-          message.choice = reader.int32() as any;
+          message.incBy = reader.uint32();
           continue;
         }
       }
@@ -3058,57 +4236,54 @@ export const SePlayerChooseWord: MessageFns<SePlayerChooseWord> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerChooseWord {
+  fromJSON(object: any): SePlayerIncDrawScore {
     return {
       drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
-      choice: isSet(object.choice) ? wordChoiceFromJSON(object.choice) : 0,
+      incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0,
     };
   },
 
-  toJSON(message: SePlayerChooseWord): unknown {
+  toJSON(message: SePlayerIncDrawScore): unknown {
     const obj: any = {};
     if (message.drawerId !== 0) {
       obj.drawerId = Math.round(message.drawerId);
     }
-    if (message.choice !== 0) {
-      obj.choice = wordChoiceToJSON(message.choice);
+    if (message.incBy !== 0) {
+      obj.incBy = Math.round(message.incBy);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerChooseWord>, I>>(base?: I): SePlayerChooseWord {
-    return SePlayerChooseWord.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerIncDrawScore>, I>>(base?: I): SePlayerIncDrawScore {
+    return SePlayerIncDrawScore.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerChooseWord>, I>>(object: I): SePlayerChooseWord {
-    const message = createBaseSePlayerChooseWord();
+  fromPartial<I extends Exact<DeepPartial<SePlayerIncDrawScore>, I>>(object: I): SePlayerIncDrawScore {
+    const message = createBaseSePlayerIncDrawScore();
     message.drawerId = object.drawerId ?? 0;
-    message.choice = object.choice ?? 0;
+    message.incBy = object.incBy ?? 0;
     return message;
   },
 };
 
-function createBaseSePlayerGuessWord(): SePlayerGuessWord {
-  return { guesserId: 0, guess: "", afterDrawOps: 0 };
+function createBaseSePlayerIncGuessScore(): SePlayerIncGuessScore {
+  return { guesserId: 0, incBy: 0 };
 }
 
-export const SePlayerGuessWord: MessageFns<SePlayerGuessWord> = {
-  encode(message: SePlayerGuessWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+export const SePlayerIncGuessScore: MessageFns<SePlayerIncGuessScore> = {
+  encode(message: SePlayerIncGuessScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     if (message.guesserId !== 0) {
       writer.uint32(8).uint32(message.guesserId);
     }
-    if (message.guess !== "") {
-      writer.uint32(18).string(message.guess);
-    }
-    if (message.afterDrawOps !== 0) {
-      writer.uint32(24).uint32(message.afterDrawOps);
+    if (message.incBy !== 0) {
+      writer.uint32(16).uint32(message.incBy);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerGuessWord {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerIncGuessScore {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerGuessWord();
+    const message = createBaseSePlayerIncGuessScore();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -3121,19 +4296,11 @@ export const SePlayerGuessWord: MessageFns<SePlayerGuessWord> = {
           continue;
         }
         case 2: {
-          if (tag !== 18) {
-            break;
-          }
-
-          message.guess = reader.string();
-          continue;
-        }
-        case 3: {
-          if (tag !== 24) {
+          if (tag !== 16) {
             break;
           }
 
-          message.afterDrawOps = reader.uint32();
+          message.incBy = reader.uint32();
           continue;
         }
       }
@@ -3145,59 +4312,51 @@ export const SePlayerGuessWord: MessageFns<SePlayerGuessWord> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerGuessWord {
+  fromJSON(object: any): SePlayerIncGuessScore {
     return {
       guesserId: isSet(object.guesserId) ? globalThis.Number(object.guesserId) : 0,
-      guess: isSet(object.guess) ? globalThis.String(object.guess) : "",
-      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
+      incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0,
     };
   },
 
-  toJSON(message: SePlayerGuessWord): unknown {
+  toJSON(message: SePlayerIncGuessScore): unknown {
     const obj: any = {};
     if (message.guesserId !== 0) {
       obj.guesserId = Math.round(message.guesserId);
     }
-    if (message.guess !== "") {
-      obj.guess = message.guess;
-    }
-    if (message.afterDrawOps !== 0) {
-      obj.afterDrawOps = Math.round(message.afterDrawOps);
+    if (message.incBy !== 0) {
+      obj.incBy = Math.round(message.incBy);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerGuessWord>, I>>(base?: I): SePlayerGuessWord {
-    return SePlayerGuessWord.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SePlayerIncGuessScore>, I>>(base?: I): SePlayerIncGuessScore {
+    return SePlayerIncGuessScore.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerGuessWord>, I>>(object: I): SePlayerGuessWord {
-    const message = createBaseSePlayerGuessWord();
+  fromPartial<I extends Exact<DeepPartial<SePlayerIncGuessScore>, I>>(object: I): SePlayerIncGuessScore {
+    const message = createBaseSePlayerIncGuessScore();
     message.guesserId = object.guesserId ?? 0;
-    message.guess = object.guess ?? "";
-    message.afterDrawOps = object.afterDrawOps ?? 0;
+    message.incBy = object.incBy ?? 0;
     return message;
   },
 };
 
-function createBaseSePlayerLikeRound(): SePlayerLikeRound {
-  return { playerId: 0, roundId: 0 };
+function createBaseSeRoundIncDrawScore(): SeRoundIncDrawScore {
+  return { incBy: 0 };
 }
 
-export const SePlayerLikeRound: MessageFns<SePlayerLikeRound> = {
-  encode(message: SePlayerLikeRound, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerId !== 0) {
-      writer.uint32(8).uint32(message.playerId);
-    }
-    if (message.roundId !== 0) {
-      writer.uint32(16).uint32(message.roundId);
+export const SeRoundIncDrawScore: MessageFns<SeRoundIncDrawScore> = {
+  encode(message: SeRoundIncDrawScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.incBy !== 0) {
+      writer.uint32(8).uint32(message.incBy);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerLikeRound {
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundIncDrawScore {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseSePlayerLikeRound();
+    const message = createBaseSeRoundIncDrawScore();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -3206,15 +4365,65 @@ export const SePlayerLikeRound: MessageFns<SePlayerLikeRound> = {
             break;
           }
 
-          message.playerId = reader.uint32();
+          message.incBy = reader.uint32();
           continue;
         }
-        case 2: {
-          if (tag !== 16) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeRoundIncDrawScore {
+    return { incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0 };
+  },
+
+  toJSON(message: SeRoundIncDrawScore): unknown {
+    const obj: any = {};
+    if (message.incBy !== 0) {
+      obj.incBy = Math.round(message.incBy);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundIncDrawScore>, I>>(base?: I): SeRoundIncDrawScore {
+    return SeRoundIncDrawScore.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundIncDrawScore>, I>>(object: I): SeRoundIncDrawScore {
+    const message = createBaseSeRoundIncDrawScore();
+    message.incBy = object.incBy ?? 0;
+    return message;
+  },
+};
+
+function createBaseSeRoundIncGuessScore(): SeRoundIncGuessScore {
+  return { incBy: 0 };
+}
+
+export const SeRoundIncGuessScore: MessageFns<SeRoundIncGuessScore> = {
+  encode(message: SeRoundIncGuessScore, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.incBy !== 0) {
+      writer.uint32(8).uint32(message.incBy);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundIncGuessScore {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundIncGuessScore();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
             break;
           }
 
-          message.roundId = reader.uint32();
+          message.incBy = reader.uint32();
           continue;
         }
       }
@@ -3226,130 +4435,56 @@ export const SePlayerLikeRound: MessageFns<SePlayerLikeRound> = {
     return message;
   },
 
-  fromJSON(object: any): SePlayerLikeRound {
-    return {
-      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
-      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
-    };
+  fromJSON(object: any): SeRoundIncGuessScore {
+    return { incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0 };
   },
 
-  toJSON(message: SePlayerLikeRound): unknown {
+  toJSON(message: SeRoundIncGuessScore): unknown {
     const obj: any = {};
-    if (message.playerId !== 0) {
-      obj.playerId = Math.round(message.playerId);
-    }
-    if (message.roundId !== 0) {
-      obj.roundId = Math.round(message.roundId);
+    if (message.incBy !== 0) {
+      obj.incBy = Math.round(message.incBy);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<SePlayerLikeRound>, I>>(base?: I): SePlayerLikeRound {
-    return SePlayerLikeRound.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SeRoundIncGuessScore>, I>>(base?: I): SeRoundIncGuessScore {
+    return SeRoundIncGuessScore.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<SePlayerLikeRound>, I>>(object: I): SePlayerLikeRound {
-    const message = createBaseSePlayerLikeRound();
-    message.playerId = object.playerId ?? 0;
-    message.roundId = object.roundId ?? 0;
+  fromPartial<I extends Exact<DeepPartial<SeRoundIncGuessScore>, I>>(object: I): SeRoundIncGuessScore {
+    const message = createBaseSeRoundIncGuessScore();
+    message.incBy = object.incBy ?? 0;
     return message;
   },
 };
 
-function createBaseServerEvent(): ServerEvent {
-  return {
-    playerJoin: undefined,
-    playerLeave: undefined,
-    playerRename: undefined,
-    playerIncRoundScore: undefined,
-    playerIncDrawScore: undefined,
-    playerIncGuessScore: undefined,
-    setGame: undefined,
-    error: undefined,
-    playerConnect: undefined,
-    playerDisconnect: undefined,
-    playerDrawOp: undefined,
-    newRound: undefined,
-    playerChooseWord: undefined,
-    playerGuessWord: undefined,
-    playerLikeRound: undefined,
-    roundIncDrawScore: undefined,
-    roundIncGuessScore: undefined,
-    roundChangePhase: undefined,
-  };
+function createBaseSePlayerDrawOp(): SePlayerDrawOp {
+  return { drawerId: 0, drawOp: undefined };
 }
 
-export const ServerEvent: MessageFns<ServerEvent> = {
-  encode(message: ServerEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.playerJoin !== undefined) {
-      SePlayerJoin.encode(message.playerJoin, writer.uint32(10).fork()).join();
-    }
-    if (message.playerLeave !== undefined) {
-      SePlayerLeave.encode(message.playerLeave, writer.uint32(18).fork()).join();
-    }
-    if (message.playerRename !== undefined) {
-      SePlayerRename.encode(message.playerRename, writer.uint32(26).fork()).join();
-    }
-    if (message.playerIncRoundScore !== undefined) {
-      SePlayerIncRoundScore.encode(message.playerIncRoundScore, writer.uint32(34).fork()).join();
-    }
-    if (message.playerIncDrawScore !== undefined) {
-      SePlayerIncDrawScore.encode(message.playerIncDrawScore, writer.uint32(82).fork()).join();
-    }
-    if (message.playerIncGuessScore !== undefined) {
-      SePlayerIncGuessScore.encode(message.playerIncGuessScore, writer.uint32(90).fork()).join();
-    }
-    if (message.setGame !== undefined) {
-      SeSetGame.encode(message.setGame, writer.uint32(42).fork()).join();
-    }
-    if (message.error !== undefined) {
-      SeError.encode(message.error, writer.uint32(50).fork()).join();
-    }
-    if (message.playerConnect !== undefined) {
-      SePlayerConnect.encode(message.playerConnect, writer.uint32(58).fork()).join();
-    }
-    if (message.playerDisconnect !== undefined) {
-      SePlayerDisconnect.encode(message.playerDisconnect, writer.uint32(66).fork()).join();
-    }
-    if (message.playerDrawOp !== undefined) {
-      SePlayerDrawOp.encode(message.playerDrawOp, writer.uint32(74).fork()).join();
-    }
-    if (message.newRound !== undefined) {
-      SeRoundNew.encode(message.newRound, writer.uint32(98).fork()).join();
-    }
-    if (message.playerChooseWord !== undefined) {
-      SePlayerChooseWord.encode(message.playerChooseWord, writer.uint32(106).fork()).join();
-    }
-    if (message.playerGuessWord !== undefined) {
-      SePlayerGuessWord.encode(message.playerGuessWord, writer.uint32(114).fork()).join();
-    }
-    if (message.playerLikeRound !== undefined) {
-      SePlayerLikeRound.encode(message.playerLikeRound, writer.uint32(122).fork()).join();
-    }
-    if (message.roundIncDrawScore !== undefined) {
-      SeRoundIncDrawScore.encode(message.roundIncDrawScore, writer.uint32(130).fork()).join();
-    }
-    if (message.roundIncGuessScore !== undefined) {
-      SeRoundIncGuessScore.encode(message.roundIncGuessScore, writer.uint32(138).fork()).join();
+export const SePlayerDrawOp: MessageFns<SePlayerDrawOp> = {
+  encode(message: SePlayerDrawOp, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.drawerId !== 0) {
+      writer.uint32(8).uint32(message.drawerId);
     }
-    if (message.roundChangePhase !== undefined) {
-      SeRoundChangePhase.encode(message.roundChangePhase, writer.uint32(146).fork()).join();
+    if (message.drawOp !== undefined) {
+      DrawOp.encode(message.drawOp, writer.uint32(18).fork()).join();
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): ServerEvent {
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerDrawOp {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseServerEvent();
+    const message = createBaseSePlayerDrawOp();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 10) {
+          if (tag !== 8) {
             break;
           }
 
-          message.playerJoin = SePlayerJoin.decode(reader, reader.uint32());
+          message.drawerId = reader.uint32();
           continue;
         }
         case 2: {
@@ -3357,135 +4492,241 @@ export const ServerEvent: MessageFns<ServerEvent> = {
             break;
           }
 
-          message.playerLeave = SePlayerLeave.decode(reader, reader.uint32());
+          message.drawOp = DrawOp.decode(reader, reader.uint32());
           continue;
         }
-        case 3: {
-          if (tag !== 26) {
-            break;
-          }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
 
-          message.playerRename = SePlayerRename.decode(reader, reader.uint32());
-          continue;
-        }
-        case 4: {
-          if (tag !== 34) {
-            break;
-          }
+  fromJSON(object: any): SePlayerDrawOp {
+    return {
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+      drawOp: isSet(object.drawOp) ? DrawOp.fromJSON(object.drawOp) : undefined,
+    };
+  },
 
-          message.playerIncRoundScore = SePlayerIncRoundScore.decode(reader, reader.uint32());
-          continue;
-        }
-        case 10: {
-          if (tag !== 82) {
-            break;
-          }
+  toJSON(message: SePlayerDrawOp): unknown {
+    const obj: any = {};
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
+    if (message.drawOp !== undefined) {
+      obj.drawOp = DrawOp.toJSON(message.drawOp);
+    }
+    return obj;
+  },
 
-          message.playerIncDrawScore = SePlayerIncDrawScore.decode(reader, reader.uint32());
-          continue;
-        }
-        case 11: {
-          if (tag !== 90) {
-            break;
-          }
+  create<I extends Exact<DeepPartial<SePlayerDrawOp>, I>>(base?: I): SePlayerDrawOp {
+    return SePlayerDrawOp.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerDrawOp>, I>>(object: I): SePlayerDrawOp {
+    const message = createBaseSePlayerDrawOp();
+    message.drawerId = object.drawerId ?? 0;
+    message.drawOp = (object.drawOp !== undefined && object.drawOp !== null)
+      ? DrawOp.fromPartial(object.drawOp)
+      : undefined;
+    return message;
+  },
+};
 
-          message.playerIncGuessScore = SePlayerIncGuessScore.decode(reader, reader.uint32());
-          continue;
-        }
-        case 5: {
-          if (tag !== 42) {
-            break;
-          }
+function createBaseSeRoundNew(): SeRoundNew {
+  return { roundId: 0, drawerId: 0, easyWord: 0, hardWord: 0, startingPhase: 0, phaseEndsAt: 0, rivalId: undefined };
+}
 
-          message.setGame = SeSetGame.decode(reader, reader.uint32());
-          continue;
-        }
-        case 6: {
-          if (tag !== 50) {
-            break;
-          }
+export const SeRoundNew: MessageFns<SeRoundNew> = {
+  encode(message: SeRoundNew, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.roundId !== 0) {
+      writer.uint32(8).uint32(message.roundId);
+    }
+    if (message.drawerId !== 0) {
+      writer.uint32(16).uint32(message.drawerId);
+    }
+    if (message.easyWord !== 0) {
+      writer.uint32(24).uint32(message.easyWord);
+    }
+    if (message.hardWord !== 0) {
+      writer.uint32(32).uint32(message.hardWord);
+    }
+    if (message.startingPhase !== 0) {
+      writer.uint32(40).int32(message.startingPhase);
+    }
+    if (message.phaseEndsAt !== 0) {
+      writer.uint32(48).uint64(message.phaseEndsAt);
+    }
+    if (message.rivalId !== undefined) {
+      writer.uint32(56).uint32(message.rivalId);
+    }
+    return writer;
+  },
 
-          message.error = SeError.decode(reader, reader.uint32());
-          continue;
-        }
-        case 7: {
-          if (tag !== 58) {
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundNew {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundNew();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
             break;
           }
 
-          message.playerConnect = SePlayerConnect.decode(reader, reader.uint32());
+          message.roundId = reader.uint32();
           continue;
         }
-        case 8: {
-          if (tag !== 66) {
+        case 2: {
+          if (tag !== 16) {
             break;
           }
 
-          message.playerDisconnect = SePlayerDisconnect.decode(reader, reader.uint32());
+          message.drawerId = reader.uint32();
           continue;
         }
-        case 9: {
-          if (tag !== 74) {
+        case 3: {
+          if (tag !== 24) {
             break;
           }
 
-          message.playerDrawOp = SePlayerDrawOp.decode(reader, reader.uint32());
+          message.easyWord = reader.uint32();
           continue;
         }
-        case 12: {
-          if (tag !== 98) {
+        case 4: {
+          if (tag !== 32) {
             break;
           }
 
-          message.newRound = SeRoundNew.decode(reader, reader.uint32());
+          message.hardWord = reader.uint32();
           continue;
         }
-        case 13: {
-          if (tag !== 106) {
+        case 5: {
+          if (tag !== 40) {
             break;
           }
 
-          message.playerChooseWord = SePlayerChooseWord.decode(reader, reader.uint32());
+          message.startingPhase = reader.int32() as any;
           continue;
         }
-        case 14: {
-          if (tag !== 114) {
+        case 6: {
+          if (tag !== 48) {
             break;
           }
 
-          message.playerGuessWord = SePlayerGuessWord.decode(reader, reader.uint32());
+          message.phaseEndsAt = longToNumber(reader.uint64());
           continue;
         }
-        case 15: {
-          if (tag !== 122) {
+        case 7: {
+          if (tag !== 56) {
             break;
           }
 
-          message.playerLikeRound = SePlayerLikeRound.decode(reader, reader.uint32());
+          message.rivalId = reader.uint32();
           continue;
         }
-        case 16: {
-          if (tag !== 130) {
-            break;
-          }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
 
-          message.roundIncDrawScore = SeRoundIncDrawScore.decode(reader, reader.uint32());
-          continue;
-        }
-        case 17: {
-          if (tag !== 138) {
+  fromJSON(object: any): SeRoundNew {
+    return {
+      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+      easyWord: isSet(object.easyWord) ? globalThis.Number(object.easyWord) : 0,
+      hardWord: isSet(object.hardWord) ? globalThis.Number(object.hardWord) : 0,
+      startingPhase: isSet(object.startingPhase) ? phaseFromJSON(object.startingPhase) : 0,
+      phaseEndsAt: isSet(object.phaseEndsAt) ? globalThis.Number(object.phaseEndsAt) : 0,
+      rivalId: isSet(object.rivalId) ? globalThis.Number(object.rivalId) : undefined,
+    };
+  },
+
+  toJSON(message: SeRoundNew): unknown {
+    const obj: any = {};
+    if (message.roundId !== 0) {
+      obj.roundId = Math.round(message.roundId);
+    }
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
+    if (message.easyWord !== 0) {
+      obj.easyWord = Math.round(message.easyWord);
+    }
+    if (message.hardWord !== 0) {
+      obj.hardWord = Math.round(message.hardWord);
+    }
+    if (message.startingPhase !== 0) {
+      obj.startingPhase = phaseToJSON(message.startingPhase);
+    }
+    if (message.phaseEndsAt !== 0) {
+      obj.phaseEndsAt = Math.round(message.phaseEndsAt);
+    }
+    if (message.rivalId !== undefined) {
+      obj.rivalId = Math.round(message.rivalId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundNew>, I>>(base?: I): SeRoundNew {
+    return SeRoundNew.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundNew>, I>>(object: I): SeRoundNew {
+    const message = createBaseSeRoundNew();
+    message.roundId = object.roundId ?? 0;
+    message.drawerId = object.drawerId ?? 0;
+    message.easyWord = object.easyWord ?? 0;
+    message.hardWord = object.hardWord ?? 0;
+    message.startingPhase = object.startingPhase ?? 0;
+    message.phaseEndsAt = object.phaseEndsAt ?? 0;
+    message.rivalId = object.rivalId ?? undefined;
+    return message;
+  },
+};
+
+function createBaseSeRoundChangePhase(): SeRoundChangePhase {
+  return { phase: 0, phaseEndsAt: 0 };
+}
+
+export const SeRoundChangePhase: MessageFns<SeRoundChangePhase> = {
+  encode(message: SeRoundChangePhase, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.phase !== 0) {
+      writer.uint32(8).int32(message.phase);
+    }
+    if (message.phaseEndsAt !== 0) {
+      writer.uint32(16).uint64(message.phaseEndsAt);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundChangePhase {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundChangePhase();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
             break;
           }
 
-          message.roundIncGuessScore = SeRoundIncGuessScore.decode(reader, reader.uint32());
+          message.phase = reader.int32() as any;
           continue;
         }
-        case 18: {
-          if (tag !== 146) {
+        case 2: {
+          if (tag !== 16) {
             break;
           }
 
-          message.roundChangePhase = SeRoundChangePhase.decode(reader, reader.uint32());
+          message.phaseEndsAt = longToNumber(reader.uint64());
           continue;
         }
       }
@@ -3497,108 +4738,2551 @@ export const ServerEvent: MessageFns<ServerEvent> = {
     return message;
   },
 
-  fromJSON(object: any): ServerEvent {
+  fromJSON(object: any): SeRoundChangePhase {
     return {
-      playerJoin: isSet(object.playerJoin) ? SePlayerJoin.fromJSON(object.playerJoin) : undefined,
-      playerLeave: isSet(object.playerLeave) ? SePlayerLeave.fromJSON(object.playerLeave) : undefined,
-      playerRename: isSet(object.playerRename) ? SePlayerRename.fromJSON(object.playerRename) : undefined,
-      playerIncRoundScore: isSet(object.playerIncRoundScore)
-        ? SePlayerIncRoundScore.fromJSON(object.playerIncRoundScore)
-        : undefined,
-      playerIncDrawScore: isSet(object.playerIncDrawScore)
-        ? SePlayerIncDrawScore.fromJSON(object.playerIncDrawScore)
-        : undefined,
-      playerIncGuessScore: isSet(object.playerIncGuessScore)
-        ? SePlayerIncGuessScore.fromJSON(object.playerIncGuessScore)
-        : undefined,
-      setGame: isSet(object.setGame) ? SeSetGame.fromJSON(object.setGame) : undefined,
-      error: isSet(object.error) ? SeError.fromJSON(object.error) : undefined,
-      playerConnect: isSet(object.playerConnect) ? SePlayerConnect.fromJSON(object.playerConnect) : undefined,
-      playerDisconnect: isSet(object.playerDisconnect)
-        ? SePlayerDisconnect.fromJSON(object.playerDisconnect)
-        : undefined,
-      playerDrawOp: isSet(object.playerDrawOp) ? SePlayerDrawOp.fromJSON(object.playerDrawOp) : undefined,
-      newRound: isSet(object.newRound) ? SeRoundNew.fromJSON(object.newRound) : undefined,
-      playerChooseWord: isSet(object.playerChooseWord)
-        ? SePlayerChooseWord.fromJSON(object.playerChooseWord)
-        : undefined,
-      playerGuessWord: isSet(object.playerGuessWord) ? SePlayerGuessWord.fromJSON(object.playerGuessWord) : undefined,
-      playerLikeRound: isSet(object.playerLikeRound) ? SePlayerLikeRound.fromJSON(object.playerLikeRound) : undefined,
-      roundIncDrawScore: isSet(object.roundIncDrawScore)
-        ? SeRoundIncDrawScore.fromJSON(object.roundIncDrawScore)
-        : undefined,
-      roundIncGuessScore: isSet(object.roundIncGuessScore)
-        ? SeRoundIncGuessScore.fromJSON(object.roundIncGuessScore)
-        : undefined,
-      roundChangePhase: isSet(object.roundChangePhase)
-        ? SeRoundChangePhase.fromJSON(object.roundChangePhase)
-        : undefined,
+      phase: isSet(object.phase) ? phaseFromJSON(object.phase) : 0,
+      phaseEndsAt: isSet(object.phaseEndsAt) ? globalThis.Number(object.phaseEndsAt) : 0,
     };
   },
 
-  toJSON(message: ServerEvent): unknown {
+  toJSON(message: SeRoundChangePhase): unknown {
     const obj: any = {};
-    if (message.playerJoin !== undefined) {
-      obj.playerJoin = SePlayerJoin.toJSON(message.playerJoin);
-    }
-    if (message.playerLeave !== undefined) {
-      obj.playerLeave = SePlayerLeave.toJSON(message.playerLeave);
-    }
-    if (message.playerRename !== undefined) {
-      obj.playerRename = SePlayerRename.toJSON(message.playerRename);
-    }
-    if (message.playerIncRoundScore !== undefined) {
-      obj.playerIncRoundScore = SePlayerIncRoundScore.toJSON(message.playerIncRoundScore);
-    }
-    if (message.playerIncDrawScore !== undefined) {
-      obj.playerIncDrawScore = SePlayerIncDrawScore.toJSON(message.playerIncDrawScore);
-    }
-    if (message.playerIncGuessScore !== undefined) {
-      obj.playerIncGuessScore = SePlayerIncGuessScore.toJSON(message.playerIncGuessScore);
-    }
-    if (message.setGame !== undefined) {
-      obj.setGame = SeSetGame.toJSON(message.setGame);
-    }
-    if (message.error !== undefined) {
-      obj.error = SeError.toJSON(message.error);
-    }
-    if (message.playerConnect !== undefined) {
-      obj.playerConnect = SePlayerConnect.toJSON(message.playerConnect);
-    }
-    if (message.playerDisconnect !== undefined) {
-      obj.playerDisconnect = SePlayerDisconnect.toJSON(message.playerDisconnect);
-    }
-    if (message.playerDrawOp !== undefined) {
-      obj.playerDrawOp = SePlayerDrawOp.toJSON(message.playerDrawOp);
+    if (message.phase !== 0) {
+      obj.phase = phaseToJSON(message.phase);
     }
-    if (message.newRound !== undefined) {
-      obj.newRound = SeRoundNew.toJSON(message.newRound);
+    if (message.phaseEndsAt !== 0) {
+      obj.phaseEndsAt = Math.round(message.phaseEndsAt);
     }
-    if (message.playerChooseWord !== undefined) {
-      obj.playerChooseWord = SePlayerChooseWord.toJSON(message.playerChooseWord);
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundChangePhase>, I>>(base?: I): SeRoundChangePhase {
+    return SeRoundChangePhase.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundChangePhase>, I>>(object: I): SeRoundChangePhase {
+    const message = createBaseSeRoundChangePhase();
+    message.phase = object.phase ?? 0;
+    message.phaseEndsAt = object.phaseEndsAt ?? 0;
+    return message;
+  },
+};
+
+function createBaseSeRoundHint(): SeRoundHint {
+  return { hint: undefined };
+}
+
+export const SeRoundHint: MessageFns<SeRoundHint> = {
+  encode(message: SeRoundHint, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.hint !== undefined) {
+      Hint.encode(message.hint, writer.uint32(10).fork()).join();
     }
-    if (message.playerGuessWord !== undefined) {
-      obj.playerGuessWord = SePlayerGuessWord.toJSON(message.playerGuessWord);
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundHint {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundHint();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.hint = Hint.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
     }
-    if (message.playerLikeRound !== undefined) {
-      obj.playerLikeRound = SePlayerLikeRound.toJSON(message.playerLikeRound);
+    return message;
+  },
+
+  fromJSON(object: any): SeRoundHint {
+    return { hint: isSet(object.hint) ? Hint.fromJSON(object.hint) : undefined };
+  },
+
+  toJSON(message: SeRoundHint): unknown {
+    const obj: any = {};
+    if (message.hint !== undefined) {
+      obj.hint = Hint.toJSON(message.hint);
     }
-    if (message.roundIncDrawScore !== undefined) {
-      obj.roundIncDrawScore = SeRoundIncDrawScore.toJSON(message.roundIncDrawScore);
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundHint>, I>>(base?: I): SeRoundHint {
+    return SeRoundHint.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundHint>, I>>(object: I): SeRoundHint {
+    const message = createBaseSeRoundHint();
+    message.hint = (object.hint !== undefined && object.hint !== null) ? Hint.fromPartial(object.hint) : undefined;
+    return message;
+  },
+};
+
+function createBaseSeRoundGuess(): SeRoundGuess {
+  return { guess: undefined };
+}
+
+export const SeRoundGuess: MessageFns<SeRoundGuess> = {
+  encode(message: SeRoundGuess, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.guess !== undefined) {
+      Guess.encode(message.guess, writer.uint32(10).fork()).join();
     }
-    if (message.roundIncGuessScore !== undefined) {
-      obj.roundIncGuessScore = SeRoundIncGuessScore.toJSON(message.roundIncGuessScore);
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundGuess {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundGuess();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.guess = Guess.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
     }
-    if (message.roundChangePhase !== undefined) {
-      obj.roundChangePhase = SeRoundChangePhase.toJSON(message.roundChangePhase);
+    return message;
+  },
+
+  fromJSON(object: any): SeRoundGuess {
+    return { guess: isSet(object.guess) ? Guess.fromJSON(object.guess) : undefined };
+  },
+
+  toJSON(message: SeRoundGuess): unknown {
+    const obj: any = {};
+    if (message.guess !== undefined) {
+      obj.guess = Guess.toJSON(message.guess);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<ServerEvent>, I>>(base?: I): ServerEvent {
-    return ServerEvent.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<SeRoundGuess>, I>>(base?: I): SeRoundGuess {
+    return SeRoundGuess.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<ServerEvent>, I>>(object: I): ServerEvent {
+  fromPartial<I extends Exact<DeepPartial<SeRoundGuess>, I>>(object: I): SeRoundGuess {
+    const message = createBaseSeRoundGuess();
+    message.guess = (object.guess !== undefined && object.guess !== null) ? Guess.fromPartial(object.guess) : undefined;
+    return message;
+  },
+};
+
+function createBaseSeRoundRevealWords(): SeRoundRevealWords {
+  return { easyWord: 0, hardWord: 0 };
+}
+
+export const SeRoundRevealWords: MessageFns<SeRoundRevealWords> = {
+  encode(message: SeRoundRevealWords, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.easyWord !== 0) {
+      writer.uint32(8).uint32(message.easyWord);
+    }
+    if (message.hardWord !== 0) {
+      writer.uint32(16).uint32(message.hardWord);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundRevealWords {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundRevealWords();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.easyWord = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.hardWord = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeRoundRevealWords {
+    return {
+      easyWord: isSet(object.easyWord) ? globalThis.Number(object.easyWord) : 0,
+      hardWord: isSet(object.hardWord) ? globalThis.Number(object.hardWord) : 0,
+    };
+  },
+
+  toJSON(message: SeRoundRevealWords): unknown {
+    const obj: any = {};
+    if (message.easyWord !== 0) {
+      obj.easyWord = Math.round(message.easyWord);
+    }
+    if (message.hardWord !== 0) {
+      obj.hardWord = Math.round(message.hardWord);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundRevealWords>, I>>(base?: I): SeRoundRevealWords {
+    return SeRoundRevealWords.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundRevealWords>, I>>(object: I): SeRoundRevealWords {
+    const message = createBaseSeRoundRevealWords();
+    message.easyWord = object.easyWord ?? 0;
+    message.hardWord = object.hardWord ?? 0;
+    return message;
+  },
+};
+
+function createBaseSeRoundSkip(): SeRoundSkip {
+  return { reason: 0, phaseEndsAt: 0 };
+}
+
+export const SeRoundSkip: MessageFns<SeRoundSkip> = {
+  encode(message: SeRoundSkip, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.reason !== 0) {
+      writer.uint32(8).int32(message.reason);
+    }
+    if (message.phaseEndsAt !== 0) {
+      writer.uint32(16).uint64(message.phaseEndsAt);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundSkip {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundSkip();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.phaseEndsAt = longToNumber(reader.uint64());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeRoundSkip {
+    return {
+      reason: isSet(object.reason) ? skipReasonFromJSON(object.reason) : 0,
+      phaseEndsAt: isSet(object.phaseEndsAt) ? globalThis.Number(object.phaseEndsAt) : 0,
+    };
+  },
+
+  toJSON(message: SeRoundSkip): unknown {
+    const obj: any = {};
+    if (message.reason !== 0) {
+      obj.reason = skipReasonToJSON(message.reason);
+    }
+    if (message.phaseEndsAt !== 0) {
+      obj.phaseEndsAt = Math.round(message.phaseEndsAt);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundSkip>, I>>(base?: I): SeRoundSkip {
+    return SeRoundSkip.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundSkip>, I>>(object: I): SeRoundSkip {
+    const message = createBaseSeRoundSkip();
+    message.reason = object.reason ?? 0;
+    message.phaseEndsAt = object.phaseEndsAt ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerChooseWord(): SePlayerChooseWord {
+  return { drawerId: 0, choice: 0 };
+}
+
+export const SePlayerChooseWord: MessageFns<SePlayerChooseWord> = {
+  encode(message: SePlayerChooseWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.drawerId !== 0) {
+      writer.uint32(8).uint32(message.drawerId);
+    }
+    if (message.choice !== 0) {
+      writer.uint32(16).int32(message.choice);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerChooseWord {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerChooseWord();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.drawerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.choice = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerChooseWord {
+    return {
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+      choice: isSet(object.choice) ? wordChoiceFromJSON(object.choice) : 0,
+    };
+  },
+
+  toJSON(message: SePlayerChooseWord): unknown {
+    const obj: any = {};
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
+    if (message.choice !== 0) {
+      obj.choice = wordChoiceToJSON(message.choice);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerChooseWord>, I>>(base?: I): SePlayerChooseWord {
+    return SePlayerChooseWord.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerChooseWord>, I>>(object: I): SePlayerChooseWord {
+    const message = createBaseSePlayerChooseWord();
+    message.drawerId = object.drawerId ?? 0;
+    message.choice = object.choice ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerGuessWord(): SePlayerGuessWord {
+  return { guesserId: 0, guess: "", afterDrawOps: 0, watchingId: undefined };
+}
+
+export const SePlayerGuessWord: MessageFns<SePlayerGuessWord> = {
+  encode(message: SePlayerGuessWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.guesserId !== 0) {
+      writer.uint32(8).uint32(message.guesserId);
+    }
+    if (message.guess !== "") {
+      writer.uint32(18).string(message.guess);
+    }
+    if (message.afterDrawOps !== 0) {
+      writer.uint32(24).uint32(message.afterDrawOps);
+    }
+    if (message.watchingId !== undefined) {
+      writer.uint32(32).uint32(message.watchingId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerGuessWord {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerGuessWord();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.guesserId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.guess = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.afterDrawOps = reader.uint32();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.watchingId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerGuessWord {
+    return {
+      guesserId: isSet(object.guesserId) ? globalThis.Number(object.guesserId) : 0,
+      guess: isSet(object.guess) ? globalThis.String(object.guess) : "",
+      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
+      watchingId: isSet(object.watchingId) ? globalThis.Number(object.watchingId) : undefined,
+    };
+  },
+
+  toJSON(message: SePlayerGuessWord): unknown {
+    const obj: any = {};
+    if (message.guesserId !== 0) {
+      obj.guesserId = Math.round(message.guesserId);
+    }
+    if (message.guess !== "") {
+      obj.guess = message.guess;
+    }
+    if (message.afterDrawOps !== 0) {
+      obj.afterDrawOps = Math.round(message.afterDrawOps);
+    }
+    if (message.watchingId !== undefined) {
+      obj.watchingId = Math.round(message.watchingId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerGuessWord>, I>>(base?: I): SePlayerGuessWord {
+    return SePlayerGuessWord.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerGuessWord>, I>>(object: I): SePlayerGuessWord {
+    const message = createBaseSePlayerGuessWord();
+    message.guesserId = object.guesserId ?? 0;
+    message.guess = object.guess ?? "";
+    message.afterDrawOps = object.afterDrawOps ?? 0;
+    message.watchingId = object.watchingId ?? undefined;
+    return message;
+  },
+};
+
+function createBaseSePlayerGuessClose(): SePlayerGuessClose {
+  return { guesserId: 0 };
+}
+
+export const SePlayerGuessClose: MessageFns<SePlayerGuessClose> = {
+  encode(message: SePlayerGuessClose, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.guesserId !== 0) {
+      writer.uint32(8).uint32(message.guesserId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerGuessClose {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerGuessClose();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.guesserId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerGuessClose {
+    return { guesserId: isSet(object.guesserId) ? globalThis.Number(object.guesserId) : 0 };
+  },
+
+  toJSON(message: SePlayerGuessClose): unknown {
+    const obj: any = {};
+    if (message.guesserId !== 0) {
+      obj.guesserId = Math.round(message.guesserId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerGuessClose>, I>>(base?: I): SePlayerGuessClose {
+    return SePlayerGuessClose.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerGuessClose>, I>>(object: I): SePlayerGuessClose {
+    const message = createBaseSePlayerGuessClose();
+    message.guesserId = object.guesserId ?? 0;
+    return message;
+  },
+};
+
+function createBaseMostLiked(): MostLiked {
+  return { roundId: 0, drawerId: 0, likes: 0, rivalId: undefined };
+}
+
+export const MostLiked: MessageFns<MostLiked> = {
+  encode(message: MostLiked, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.roundId !== 0) {
+      writer.uint32(8).uint32(message.roundId);
+    }
+    if (message.drawerId !== 0) {
+      writer.uint32(16).uint32(message.drawerId);
+    }
+    if (message.likes !== 0) {
+      writer.uint32(24).uint32(message.likes);
+    }
+    if (message.rivalId !== undefined) {
+      writer.uint32(32).uint32(message.rivalId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): MostLiked {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseMostLiked();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.roundId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.drawerId = reader.uint32();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.likes = reader.uint32();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.rivalId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): MostLiked {
+    return {
+      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+      likes: isSet(object.likes) ? globalThis.Number(object.likes) : 0,
+      rivalId: isSet(object.rivalId) ? globalThis.Number(object.rivalId) : undefined,
+    };
+  },
+
+  toJSON(message: MostLiked): unknown {
+    const obj: any = {};
+    if (message.roundId !== 0) {
+      obj.roundId = Math.round(message.roundId);
+    }
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
+    if (message.likes !== 0) {
+      obj.likes = Math.round(message.likes);
+    }
+    if (message.rivalId !== undefined) {
+      obj.rivalId = Math.round(message.rivalId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<MostLiked>, I>>(base?: I): MostLiked {
+    return MostLiked.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<MostLiked>, I>>(object: I): MostLiked {
+    const message = createBaseMostLiked();
+    message.roundId = object.roundId ?? 0;
+    message.drawerId = object.drawerId ?? 0;
+    message.likes = object.likes ?? 0;
+    message.rivalId = object.rivalId ?? undefined;
+    return message;
+  },
+};
+
+function createBaseSeGameOver(): SeGameOver {
+  return { standings: [], mostLiked: undefined };
+}
+
+export const SeGameOver: MessageFns<SeGameOver> = {
+  encode(message: SeGameOver, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    for (const v of message.standings) {
+      Standing.encode(v!, writer.uint32(10).fork()).join();
+    }
+    if (message.mostLiked !== undefined) {
+      MostLiked.encode(message.mostLiked, writer.uint32(18).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeGameOver {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeGameOver();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.standings.push(Standing.decode(reader, reader.uint32()));
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.mostLiked = MostLiked.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeGameOver {
+    return {
+      standings: globalThis.Array.isArray(object?.standings)
+        ? object.standings.map((e: any) => Standing.fromJSON(e))
+        : [],
+      mostLiked: isSet(object.mostLiked) ? MostLiked.fromJSON(object.mostLiked) : undefined,
+    };
+  },
+
+  toJSON(message: SeGameOver): unknown {
+    const obj: any = {};
+    if (message.standings?.length) {
+      obj.standings = message.standings.map((e) => Standing.toJSON(e));
+    }
+    if (message.mostLiked !== undefined) {
+      obj.mostLiked = MostLiked.toJSON(message.mostLiked);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeGameOver>, I>>(base?: I): SeGameOver {
+    return SeGameOver.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeGameOver>, I>>(object: I): SeGameOver {
+    const message = createBaseSeGameOver();
+    message.standings = object.standings?.map((e) => Standing.fromPartial(e)) || [];
+    message.mostLiked = (object.mostLiked !== undefined && object.mostLiked !== null)
+      ? MostLiked.fromPartial(object.mostLiked)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseSePlayerPlayAgain(): SePlayerPlayAgain {
+  return { playerId: 0 };
+}
+
+export const SePlayerPlayAgain: MessageFns<SePlayerPlayAgain> = {
+  encode(message: SePlayerPlayAgain, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerPlayAgain {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerPlayAgain();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerPlayAgain {
+    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
+  },
+
+  toJSON(message: SePlayerPlayAgain): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerPlayAgain>, I>>(base?: I): SePlayerPlayAgain {
+    return SePlayerPlayAgain.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerPlayAgain>, I>>(object: I): SePlayerPlayAgain {
+    const message = createBaseSePlayerPlayAgain();
+    message.playerId = object.playerId ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerStartMatch(): SePlayerStartMatch {
+  return { playerId: 0 };
+}
+
+export const SePlayerStartMatch: MessageFns<SePlayerStartMatch> = {
+  encode(message: SePlayerStartMatch, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerStartMatch {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerStartMatch();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerStartMatch {
+    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
+  },
+
+  toJSON(message: SePlayerStartMatch): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerStartMatch>, I>>(base?: I): SePlayerStartMatch {
+    return SePlayerStartMatch.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerStartMatch>, I>>(object: I): SePlayerStartMatch {
+    const message = createBaseSePlayerStartMatch();
+    message.playerId = object.playerId ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerReady(): SePlayerReady {
+  return { playerId: 0, ready: false };
+}
+
+export const SePlayerReady: MessageFns<SePlayerReady> = {
+  encode(message: SePlayerReady, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    if (message.ready !== false) {
+      writer.uint32(16).bool(message.ready);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerReady {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerReady();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.ready = reader.bool();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerReady {
+    return {
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      ready: isSet(object.ready) ? globalThis.Boolean(object.ready) : false,
+    };
+  },
+
+  toJSON(message: SePlayerReady): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    if (message.ready !== false) {
+      obj.ready = message.ready;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerReady>, I>>(base?: I): SePlayerReady {
+    return SePlayerReady.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerReady>, I>>(object: I): SePlayerReady {
+    const message = createBaseSePlayerReady();
+    message.playerId = object.playerId ?? 0;
+    message.ready = object.ready ?? false;
+    return message;
+  },
+};
+
+function createBaseSeSpectators(): SeSpectators {
+  return { spectators: 0 };
+}
+
+export const SeSpectators: MessageFns<SeSpectators> = {
+  encode(message: SeSpectators, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.spectators !== 0) {
+      writer.uint32(8).uint32(message.spectators);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeSpectators {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeSpectators();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.spectators = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeSpectators {
+    return { spectators: isSet(object.spectators) ? globalThis.Number(object.spectators) : 0 };
+  },
+
+  toJSON(message: SeSpectators): unknown {
+    const obj: any = {};
+    if (message.spectators !== 0) {
+      obj.spectators = Math.round(message.spectators);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeSpectators>, I>>(base?: I): SeSpectators {
+    return SeSpectators.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeSpectators>, I>>(object: I): SeSpectators {
+    const message = createBaseSeSpectators();
+    message.spectators = object.spectators ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerVote(): SePlayerVote {
+  return { voterId: 0, drawerId: 0 };
+}
+
+export const SePlayerVote: MessageFns<SePlayerVote> = {
+  encode(message: SePlayerVote, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.voterId !== 0) {
+      writer.uint32(8).uint32(message.voterId);
+    }
+    if (message.drawerId !== 0) {
+      writer.uint32(16).uint32(message.drawerId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerVote {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerVote();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.voterId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.drawerId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerVote {
+    return {
+      voterId: isSet(object.voterId) ? globalThis.Number(object.voterId) : 0,
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+    };
+  },
+
+  toJSON(message: SePlayerVote): unknown {
+    const obj: any = {};
+    if (message.voterId !== 0) {
+      obj.voterId = Math.round(message.voterId);
+    }
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerVote>, I>>(base?: I): SePlayerVote {
+    return SePlayerVote.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerVote>, I>>(object: I): SePlayerVote {
+    const message = createBaseSePlayerVote();
+    message.voterId = object.voterId ?? 0;
+    message.drawerId = object.drawerId ?? 0;
+    return message;
+  },
+};
+
+function createBaseSeRoundVote(): SeRoundVote {
+  return { vote: undefined };
+}
+
+export const SeRoundVote: MessageFns<SeRoundVote> = {
+  encode(message: SeRoundVote, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.vote !== undefined) {
+      Vote.encode(message.vote, writer.uint32(10).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundVote {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundVote();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.vote = Vote.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeRoundVote {
+    return { vote: isSet(object.vote) ? Vote.fromJSON(object.vote) : undefined };
+  },
+
+  toJSON(message: SeRoundVote): unknown {
+    const obj: any = {};
+    if (message.vote !== undefined) {
+      obj.vote = Vote.toJSON(message.vote);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundVote>, I>>(base?: I): SeRoundVote {
+    return SeRoundVote.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundVote>, I>>(object: I): SeRoundVote {
+    const message = createBaseSeRoundVote();
+    message.vote = (object.vote !== undefined && object.vote !== null) ? Vote.fromPartial(object.vote) : undefined;
+    return message;
+  },
+};
+
+function createBaseSeVoteResult(): SeVoteResult {
+  return { winnerIds: [], incBy: 0 };
+}
+
+export const SeVoteResult: MessageFns<SeVoteResult> = {
+  encode(message: SeVoteResult, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    writer.uint32(10).fork();
+    for (const v of message.winnerIds) {
+      writer.uint32(v);
+    }
+    writer.join();
+    if (message.incBy !== 0) {
+      writer.uint32(16).uint32(message.incBy);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeVoteResult {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeVoteResult();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag === 8) {
+            message.winnerIds.push(reader.uint32());
+
+            continue;
+          }
+
+          if (tag === 10) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.winnerIds.push(reader.uint32());
+            }
+
+            continue;
+          }
+
+          break;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.incBy = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeVoteResult {
+    return {
+      winnerIds: globalThis.Array.isArray(object?.winnerIds)
+        ? object.winnerIds.map((e: any) => globalThis.Number(e))
+        : [],
+      incBy: isSet(object.incBy) ? globalThis.Number(object.incBy) : 0,
+    };
+  },
+
+  toJSON(message: SeVoteResult): unknown {
+    const obj: any = {};
+    if (message.winnerIds?.length) {
+      obj.winnerIds = message.winnerIds.map((e) => Math.round(e));
+    }
+    if (message.incBy !== 0) {
+      obj.incBy = Math.round(message.incBy);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeVoteResult>, I>>(base?: I): SeVoteResult {
+    return SeVoteResult.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeVoteResult>, I>>(object: I): SeVoteResult {
+    const message = createBaseSeVoteResult();
+    message.winnerIds = object.winnerIds?.map((e) => e) || [];
+    message.incBy = object.incBy ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerChat(): SePlayerChat {
+  return { playerId: 0, text: "" };
+}
+
+export const SePlayerChat: MessageFns<SePlayerChat> = {
+  encode(message: SePlayerChat, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    if (message.text !== "") {
+      writer.uint32(18).string(message.text);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerChat {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerChat();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.text = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerChat {
+    return {
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      text: isSet(object.text) ? globalThis.String(object.text) : "",
+    };
+  },
+
+  toJSON(message: SePlayerChat): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    if (message.text !== "") {
+      obj.text = message.text;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerChat>, I>>(base?: I): SePlayerChat {
+    return SePlayerChat.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerChat>, I>>(object: I): SePlayerChat {
+    const message = createBaseSePlayerChat();
+    message.playerId = object.playerId ?? 0;
+    message.text = object.text ?? "";
+    return message;
+  },
+};
+
+function createBaseSeRoomChat(): SeRoomChat {
+  return { message: undefined };
+}
+
+export const SeRoomChat: MessageFns<SeRoomChat> = {
+  encode(message: SeRoomChat, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.message !== undefined) {
+      ChatMessage.encode(message.message, writer.uint32(10).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoomChat {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoomChat();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.message = ChatMessage.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeRoomChat {
+    return { message: isSet(object.message) ? ChatMessage.fromJSON(object.message) : undefined };
+  },
+
+  toJSON(message: SeRoomChat): unknown {
+    const obj: any = {};
+    if (message.message !== undefined) {
+      obj.message = ChatMessage.toJSON(message.message);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoomChat>, I>>(base?: I): SeRoomChat {
+    return SeRoomChat.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoomChat>, I>>(object: I): SeRoomChat {
+    const message = createBaseSeRoomChat();
+    message.message = (object.message !== undefined && object.message !== null)
+      ? ChatMessage.fromPartial(object.message)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseSePlayerChatReject(): SePlayerChatReject {
+  return { playerId: 0, reason: 0 };
+}
+
+export const SePlayerChatReject: MessageFns<SePlayerChatReject> = {
+  encode(message: SePlayerChatReject, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    if (message.reason !== 0) {
+      writer.uint32(16).int32(message.reason);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerChatReject {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerChatReject();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerChatReject {
+    return {
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      reason: isSet(object.reason) ? chatRejectReasonFromJSON(object.reason) : 0,
+    };
+  },
+
+  toJSON(message: SePlayerChatReject): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    if (message.reason !== 0) {
+      obj.reason = chatRejectReasonToJSON(message.reason);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerChatReject>, I>>(base?: I): SePlayerChatReject {
+    return SePlayerChatReject.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerChatReject>, I>>(object: I): SePlayerChatReject {
+    const message = createBaseSePlayerChatReject();
+    message.playerId = object.playerId ?? 0;
+    message.reason = object.reason ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerEndGame(): SePlayerEndGame {
+  return { playerId: 0 };
+}
+
+export const SePlayerEndGame: MessageFns<SePlayerEndGame> = {
+  encode(message: SePlayerEndGame, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerEndGame {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerEndGame();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerEndGame {
+    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
+  },
+
+  toJSON(message: SePlayerEndGame): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerEndGame>, I>>(base?: I): SePlayerEndGame {
+    return SePlayerEndGame.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerEndGame>, I>>(object: I): SePlayerEndGame {
+    const message = createBaseSePlayerEndGame();
+    message.playerId = object.playerId ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerKick(): SePlayerKick {
+  return { hostId: 0, playerId: 0 };
+}
+
+export const SePlayerKick: MessageFns<SePlayerKick> = {
+  encode(message: SePlayerKick, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.hostId !== 0) {
+      writer.uint32(8).uint32(message.hostId);
+    }
+    if (message.playerId !== 0) {
+      writer.uint32(16).uint32(message.playerId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerKick {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerKick();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.hostId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerKick {
+    return {
+      hostId: isSet(object.hostId) ? globalThis.Number(object.hostId) : 0,
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+    };
+  },
+
+  toJSON(message: SePlayerKick): unknown {
+    const obj: any = {};
+    if (message.hostId !== 0) {
+      obj.hostId = Math.round(message.hostId);
+    }
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerKick>, I>>(base?: I): SePlayerKick {
+    return SePlayerKick.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerKick>, I>>(object: I): SePlayerKick {
+    const message = createBaseSePlayerKick();
+    message.hostId = object.hostId ?? 0;
+    message.playerId = object.playerId ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerChangeSettings(): SePlayerChangeSettings {
+  return { playerId: 0, settings: undefined };
+}
+
+export const SePlayerChangeSettings: MessageFns<SePlayerChangeSettings> = {
+  encode(message: SePlayerChangeSettings, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    if (message.settings !== undefined) {
+      GameSettings.encode(message.settings, writer.uint32(18).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerChangeSettings {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerChangeSettings();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.settings = GameSettings.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerChangeSettings {
+    return {
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      settings: isSet(object.settings) ? GameSettings.fromJSON(object.settings) : undefined,
+    };
+  },
+
+  toJSON(message: SePlayerChangeSettings): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    if (message.settings !== undefined) {
+      obj.settings = GameSettings.toJSON(message.settings);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerChangeSettings>, I>>(base?: I): SePlayerChangeSettings {
+    return SePlayerChangeSettings.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerChangeSettings>, I>>(object: I): SePlayerChangeSettings {
+    const message = createBaseSePlayerChangeSettings();
+    message.playerId = object.playerId ?? 0;
+    message.settings = (object.settings !== undefined && object.settings !== null)
+      ? GameSettings.fromPartial(object.settings)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseSePlayerDrawOpReject(): SePlayerDrawOpReject {
+  return { drawerId: 0, reason: 0 };
+}
+
+export const SePlayerDrawOpReject: MessageFns<SePlayerDrawOpReject> = {
+  encode(message: SePlayerDrawOpReject, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.drawerId !== 0) {
+      writer.uint32(8).uint32(message.drawerId);
+    }
+    if (message.reason !== 0) {
+      writer.uint32(16).int32(message.reason);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerDrawOpReject {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerDrawOpReject();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.drawerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.reason = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerDrawOpReject {
+    return {
+      drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0,
+      reason: isSet(object.reason) ? drawOpRejectReasonFromJSON(object.reason) : 0,
+    };
+  },
+
+  toJSON(message: SePlayerDrawOpReject): unknown {
+    const obj: any = {};
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
+    }
+    if (message.reason !== 0) {
+      obj.reason = drawOpRejectReasonToJSON(message.reason);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerDrawOpReject>, I>>(base?: I): SePlayerDrawOpReject {
+    return SePlayerDrawOpReject.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerDrawOpReject>, I>>(object: I): SePlayerDrawOpReject {
+    const message = createBaseSePlayerDrawOpReject();
+    message.drawerId = object.drawerId ?? 0;
+    message.reason = object.reason ?? 0;
+    return message;
+  },
+};
+
+function createBaseSePlayerLikeRound(): SePlayerLikeRound {
+  return { playerId: 0, roundId: 0 };
+}
+
+export const SePlayerLikeRound: MessageFns<SePlayerLikeRound> = {
+  encode(message: SePlayerLikeRound, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    if (message.roundId !== 0) {
+      writer.uint32(16).uint32(message.roundId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SePlayerLikeRound {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSePlayerLikeRound();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.roundId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SePlayerLikeRound {
+    return {
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
+    };
+  },
+
+  toJSON(message: SePlayerLikeRound): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    if (message.roundId !== 0) {
+      obj.roundId = Math.round(message.roundId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SePlayerLikeRound>, I>>(base?: I): SePlayerLikeRound {
+    return SePlayerLikeRound.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SePlayerLikeRound>, I>>(object: I): SePlayerLikeRound {
+    const message = createBaseSePlayerLikeRound();
+    message.playerId = object.playerId ?? 0;
+    message.roundId = object.roundId ?? 0;
+    return message;
+  },
+};
+
+function createBaseSeRoundLike(): SeRoundLike {
+  return { playerId: 0, roundId: 0 };
+}
+
+export const SeRoundLike: MessageFns<SeRoundLike> = {
+  encode(message: SeRoundLike, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
+    }
+    if (message.roundId !== 0) {
+      writer.uint32(16).uint32(message.roundId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): SeRoundLike {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSeRoundLike();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.playerId = reader.uint32();
+          continue;
+        }
+        case 2: {
+          if (tag !== 16) {
+            break;
+          }
+
+          message.roundId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): SeRoundLike {
+    return {
+      playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0,
+      roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0,
+    };
+  },
+
+  toJSON(message: SeRoundLike): unknown {
+    const obj: any = {};
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
+    }
+    if (message.roundId !== 0) {
+      obj.roundId = Math.round(message.roundId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<SeRoundLike>, I>>(base?: I): SeRoundLike {
+    return SeRoundLike.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<SeRoundLike>, I>>(object: I): SeRoundLike {
+    const message = createBaseSeRoundLike();
+    message.playerId = object.playerId ?? 0;
+    message.roundId = object.roundId ?? 0;
+    return message;
+  },
+};
+
+function createBaseServerEvent(): ServerEvent {
+  return {
+    playerJoin: undefined,
+    playerLeave: undefined,
+    playerRename: undefined,
+    playerIncRoundScore: undefined,
+    playerIncDrawScore: undefined,
+    playerIncGuessScore: undefined,
+    setGame: undefined,
+    error: undefined,
+    playerConnect: undefined,
+    playerDisconnect: undefined,
+    playerDrawOp: undefined,
+    newRound: undefined,
+    playerChooseWord: undefined,
+    playerGuessWord: undefined,
+    playerLikeRound: undefined,
+    roundIncDrawScore: undefined,
+    roundIncGuessScore: undefined,
+    roundChangePhase: undefined,
+    roundSkip: undefined,
+    roundHint: undefined,
+    roundGuess: undefined,
+    roundRevealWords: undefined,
+    playerGuessClose: undefined,
+    playerChangeSettings: undefined,
+    gameOver: undefined,
+    playerPlayAgain: undefined,
+    playerDrawOpReject: undefined,
+    playerStartMatch: undefined,
+    playerEndGame: undefined,
+    playerKick: undefined,
+    playerReady: undefined,
+    spectators: undefined,
+    playerVote: undefined,
+    roundVote: undefined,
+    voteResult: undefined,
+    playerChat: undefined,
+    roomChat: undefined,
+    playerChatReject: undefined,
+    roundLike: undefined,
+  };
+}
+
+export const ServerEvent: MessageFns<ServerEvent> = {
+  encode(message: ServerEvent, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerJoin !== undefined) {
+      SePlayerJoin.encode(message.playerJoin, writer.uint32(10).fork()).join();
+    }
+    if (message.playerLeave !== undefined) {
+      SePlayerLeave.encode(message.playerLeave, writer.uint32(18).fork()).join();
+    }
+    if (message.playerRename !== undefined) {
+      SePlayerRename.encode(message.playerRename, writer.uint32(26).fork()).join();
+    }
+    if (message.playerIncRoundScore !== undefined) {
+      SePlayerIncRoundScore.encode(message.playerIncRoundScore, writer.uint32(34).fork()).join();
+    }
+    if (message.playerIncDrawScore !== undefined) {
+      SePlayerIncDrawScore.encode(message.playerIncDrawScore, writer.uint32(82).fork()).join();
+    }
+    if (message.playerIncGuessScore !== undefined) {
+      SePlayerIncGuessScore.encode(message.playerIncGuessScore, writer.uint32(90).fork()).join();
+    }
+    if (message.setGame !== undefined) {
+      SeSetGame.encode(message.setGame, writer.uint32(42).fork()).join();
+    }
+    if (message.error !== undefined) {
+      SeError.encode(message.error, writer.uint32(50).fork()).join();
+    }
+    if (message.playerConnect !== undefined) {
+      SePlayerConnect.encode(message.playerConnect, writer.uint32(58).fork()).join();
+    }
+    if (message.playerDisconnect !== undefined) {
+      SePlayerDisconnect.encode(message.playerDisconnect, writer.uint32(66).fork()).join();
+    }
+    if (message.playerDrawOp !== undefined) {
+      SePlayerDrawOp.encode(message.playerDrawOp, writer.uint32(74).fork()).join();
+    }
+    if (message.newRound !== undefined) {
+      SeRoundNew.encode(message.newRound, writer.uint32(98).fork()).join();
+    }
+    if (message.playerChooseWord !== undefined) {
+      SePlayerChooseWord.encode(message.playerChooseWord, writer.uint32(106).fork()).join();
+    }
+    if (message.playerGuessWord !== undefined) {
+      SePlayerGuessWord.encode(message.playerGuessWord, writer.uint32(114).fork()).join();
+    }
+    if (message.playerLikeRound !== undefined) {
+      SePlayerLikeRound.encode(message.playerLikeRound, writer.uint32(122).fork()).join();
+    }
+    if (message.roundIncDrawScore !== undefined) {
+      SeRoundIncDrawScore.encode(message.roundIncDrawScore, writer.uint32(130).fork()).join();
+    }
+    if (message.roundIncGuessScore !== undefined) {
+      SeRoundIncGuessScore.encode(message.roundIncGuessScore, writer.uint32(138).fork()).join();
+    }
+    if (message.roundChangePhase !== undefined) {
+      SeRoundChangePhase.encode(message.roundChangePhase, writer.uint32(146).fork()).join();
+    }
+    if (message.roundSkip !== undefined) {
+      SeRoundSkip.encode(message.roundSkip, writer.uint32(154).fork()).join();
+    }
+    if (message.roundHint !== undefined) {
+      SeRoundHint.encode(message.roundHint, writer.uint32(162).fork()).join();
+    }
+    if (message.roundGuess !== undefined) {
+      SeRoundGuess.encode(message.roundGuess, writer.uint32(170).fork()).join();
+    }
+    if (message.roundRevealWords !== undefined) {
+      SeRoundRevealWords.encode(message.roundRevealWords, writer.uint32(178).fork()).join();
+    }
+    if (message.playerGuessClose !== undefined) {
+      SePlayerGuessClose.encode(message.playerGuessClose, writer.uint32(186).fork()).join();
+    }
+    if (message.playerChangeSettings !== undefined) {
+      SePlayerChangeSettings.encode(message.playerChangeSettings, writer.uint32(194).fork()).join();
+    }
+    if (message.gameOver !== undefined) {
+      SeGameOver.encode(message.gameOver, writer.uint32(202).fork()).join();
+    }
+    if (message.playerPlayAgain !== undefined) {
+      SePlayerPlayAgain.encode(message.playerPlayAgain, writer.uint32(210).fork()).join();
+    }
+    if (message.playerDrawOpReject !== undefined) {
+      SePlayerDrawOpReject.encode(message.playerDrawOpReject, writer.uint32(218).fork()).join();
+    }
+    if (message.playerStartMatch !== undefined) {
+      SePlayerStartMatch.encode(message.playerStartMatch, writer.uint32(226).fork()).join();
+    }
+    if (message.playerEndGame !== undefined) {
+      SePlayerEndGame.encode(message.playerEndGame, writer.uint32(234).fork()).join();
+    }
+    if (message.playerKick !== undefined) {
+      SePlayerKick.encode(message.playerKick, writer.uint32(242).fork()).join();
+    }
+    if (message.playerReady !== undefined) {
+      SePlayerReady.encode(message.playerReady, writer.uint32(250).fork()).join();
+    }
+    if (message.spectators !== undefined) {
+      SeSpectators.encode(message.spectators, writer.uint32(258).fork()).join();
+    }
+    if (message.playerVote !== undefined) {
+      SePlayerVote.encode(message.playerVote, writer.uint32(266).fork()).join();
+    }
+    if (message.roundVote !== undefined) {
+      SeRoundVote.encode(message.roundVote, writer.uint32(274).fork()).join();
+    }
+    if (message.voteResult !== undefined) {
+      SeVoteResult.encode(message.voteResult, writer.uint32(282).fork()).join();
+    }
+    if (message.playerChat !== undefined) {
+      SePlayerChat.encode(message.playerChat, writer.uint32(290).fork()).join();
+    }
+    if (message.roomChat !== undefined) {
+      SeRoomChat.encode(message.roomChat, writer.uint32(298).fork()).join();
+    }
+    if (message.playerChatReject !== undefined) {
+      SePlayerChatReject.encode(message.playerChatReject, writer.uint32(306).fork()).join();
+    }
+    if (message.roundLike !== undefined) {
+      SeRoundLike.encode(message.roundLike, writer.uint32(314).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ServerEvent {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseServerEvent();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.playerJoin = SePlayerJoin.decode(reader, reader.uint32());
+          continue;
+        }
+        case 2: {
+          if (tag !== 18) {
+            break;
+          }
+
+          message.playerLeave = SePlayerLeave.decode(reader, reader.uint32());
+          continue;
+        }
+        case 3: {
+          if (tag !== 26) {
+            break;
+          }
+
+          message.playerRename = SePlayerRename.decode(reader, reader.uint32());
+          continue;
+        }
+        case 4: {
+          if (tag !== 34) {
+            break;
+          }
+
+          message.playerIncRoundScore = SePlayerIncRoundScore.decode(reader, reader.uint32());
+          continue;
+        }
+        case 10: {
+          if (tag !== 82) {
+            break;
+          }
+
+          message.playerIncDrawScore = SePlayerIncDrawScore.decode(reader, reader.uint32());
+          continue;
+        }
+        case 11: {
+          if (tag !== 90) {
+            break;
+          }
+
+          message.playerIncGuessScore = SePlayerIncGuessScore.decode(reader, reader.uint32());
+          continue;
+        }
+        case 5: {
+          if (tag !== 42) {
+            break;
+          }
+
+          message.setGame = SeSetGame.decode(reader, reader.uint32());
+          continue;
+        }
+        case 6: {
+          if (tag !== 50) {
+            break;
+          }
+
+          message.error = SeError.decode(reader, reader.uint32());
+          continue;
+        }
+        case 7: {
+          if (tag !== 58) {
+            break;
+          }
+
+          message.playerConnect = SePlayerConnect.decode(reader, reader.uint32());
+          continue;
+        }
+        case 8: {
+          if (tag !== 66) {
+            break;
+          }
+
+          message.playerDisconnect = SePlayerDisconnect.decode(reader, reader.uint32());
+          continue;
+        }
+        case 9: {
+          if (tag !== 74) {
+            break;
+          }
+
+          message.playerDrawOp = SePlayerDrawOp.decode(reader, reader.uint32());
+          continue;
+        }
+        case 12: {
+          if (tag !== 98) {
+            break;
+          }
+
+          message.newRound = SeRoundNew.decode(reader, reader.uint32());
+          continue;
+        }
+        case 13: {
+          if (tag !== 106) {
+            break;
+          }
+
+          message.playerChooseWord = SePlayerChooseWord.decode(reader, reader.uint32());
+          continue;
+        }
+        case 14: {
+          if (tag !== 114) {
+            break;
+          }
+
+          message.playerGuessWord = SePlayerGuessWord.decode(reader, reader.uint32());
+          continue;
+        }
+        case 15: {
+          if (tag !== 122) {
+            break;
+          }
+
+          message.playerLikeRound = SePlayerLikeRound.decode(reader, reader.uint32());
+          continue;
+        }
+        case 16: {
+          if (tag !== 130) {
+            break;
+          }
+
+          message.roundIncDrawScore = SeRoundIncDrawScore.decode(reader, reader.uint32());
+          continue;
+        }
+        case 17: {
+          if (tag !== 138) {
+            break;
+          }
+
+          message.roundIncGuessScore = SeRoundIncGuessScore.decode(reader, reader.uint32());
+          continue;
+        }
+        case 18: {
+          if (tag !== 146) {
+            break;
+          }
+
+          message.roundChangePhase = SeRoundChangePhase.decode(reader, reader.uint32());
+          continue;
+        }
+        case 19: {
+          if (tag !== 154) {
+            break;
+          }
+
+          message.roundSkip = SeRoundSkip.decode(reader, reader.uint32());
+          continue;
+        }
+        case 20: {
+          if (tag !== 162) {
+            break;
+          }
+
+          message.roundHint = SeRoundHint.decode(reader, reader.uint32());
+          continue;
+        }
+        case 21: {
+          if (tag !== 170) {
+            break;
+          }
+
+          message.roundGuess = SeRoundGuess.decode(reader, reader.uint32());
+          continue;
+        }
+        case 22: {
+          if (tag !== 178) {
+            break;
+          }
+
+          message.roundRevealWords = SeRoundRevealWords.decode(reader, reader.uint32());
+          continue;
+        }
+        case 23: {
+          if (tag !== 186) {
+            break;
+          }
+
+          message.playerGuessClose = SePlayerGuessClose.decode(reader, reader.uint32());
+          continue;
+        }
+        case 24: {
+          if (tag !== 194) {
+            break;
+          }
+
+          message.playerChangeSettings = SePlayerChangeSettings.decode(reader, reader.uint32());
+          continue;
+        }
+        case 25: {
+          if (tag !== 202) {
+            break;
+          }
+
+          message.gameOver = SeGameOver.decode(reader, reader.uint32());
+          continue;
+        }
+        case 26: {
+          if (tag !== 210) {
+            break;
+          }
+
+          message.playerPlayAgain = SePlayerPlayAgain.decode(reader, reader.uint32());
+          continue;
+        }
+        case 27: {
+          if (tag !== 218) {
+            break;
+          }
+
+          message.playerDrawOpReject = SePlayerDrawOpReject.decode(reader, reader.uint32());
+          continue;
+        }
+        case 28: {
+          if (tag !== 226) {
+            break;
+          }
+
+          message.playerStartMatch = SePlayerStartMatch.decode(reader, reader.uint32());
+          continue;
+        }
+        case 29: {
+          if (tag !== 234) {
+            break;
+          }
+
+          message.playerEndGame = SePlayerEndGame.decode(reader, reader.uint32());
+          continue;
+        }
+        case 30: {
+          if (tag !== 242) {
+            break;
+          }
+
+          message.playerKick = SePlayerKick.decode(reader, reader.uint32());
+          continue;
+        }
+        case 31: {
+          if (tag !== 250) {
+            break;
+          }
+
+          message.playerReady = SePlayerReady.decode(reader, reader.uint32());
+          continue;
+        }
+        case 32: {
+          if (tag !== 258) {
+            break;
+          }
+
+          message.spectators = SeSpectators.decode(reader, reader.uint32());
+          continue;
+        }
+        case 33: {
+          if (tag !== 266) {
+            break;
+          }
+
+          message.playerVote = SePlayerVote.decode(reader, reader.uint32());
+          continue;
+        }
+        case 34: {
+          if (tag !== 274) {
+            break;
+          }
+
+          message.roundVote = SeRoundVote.decode(reader, reader.uint32());
+          continue;
+        }
+        case 35: {
+          if (tag !== 282) {
+            break;
+          }
+
+          message.voteResult = SeVoteResult.decode(reader, reader.uint32());
+          continue;
+        }
+        case 36: {
+          if (tag !== 290) {
+            break;
+          }
+
+          message.playerChat = SePlayerChat.decode(reader, reader.uint32());
+          continue;
+        }
+        case 37: {
+          if (tag !== 298) {
+            break;
+          }
+
+          message.roomChat = SeRoomChat.decode(reader, reader.uint32());
+          continue;
+        }
+        case 38: {
+          if (tag !== 306) {
+            break;
+          }
+
+          message.playerChatReject = SePlayerChatReject.decode(reader, reader.uint32());
+          continue;
+        }
+        case 39: {
+          if (tag !== 314) {
+            break;
+          }
+
+          message.roundLike = SeRoundLike.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ServerEvent {
+    return {
+      playerJoin: isSet(object.playerJoin) ? SePlayerJoin.fromJSON(object.playerJoin) : undefined,
+      playerLeave: isSet(object.playerLeave) ? SePlayerLeave.fromJSON(object.playerLeave) : undefined,
+      playerRename: isSet(object.playerRename) ? SePlayerRename.fromJSON(object.playerRename) : undefined,
+      playerIncRoundScore: isSet(object.playerIncRoundScore)
+        ? SePlayerIncRoundScore.fromJSON(object.playerIncRoundScore)
+        : undefined,
+      playerIncDrawScore: isSet(object.playerIncDrawScore)
+        ? SePlayerIncDrawScore.fromJSON(object.playerIncDrawScore)
+        : undefined,
+      playerIncGuessScore: isSet(object.playerIncGuessScore)
+        ? SePlayerIncGuessScore.fromJSON(object.playerIncGuessScore)
+        : undefined,
+      setGame: isSet(object.setGame) ? SeSetGame.fromJSON(object.setGame) : undefined,
+      error: isSet(object.error) ? SeError.fromJSON(object.error) : undefined,
+      playerConnect: isSet(object.playerConnect) ? SePlayerConnect.fromJSON(object.playerConnect) : undefined,
+      playerDisconnect: isSet(object.playerDisconnect)
+        ? SePlayerDisconnect.fromJSON(object.playerDisconnect)
+        : undefined,
+      playerDrawOp: isSet(object.playerDrawOp) ? SePlayerDrawOp.fromJSON(object.playerDrawOp) : undefined,
+      newRound: isSet(object.newRound) ? SeRoundNew.fromJSON(object.newRound) : undefined,
+      playerChooseWord: isSet(object.playerChooseWord)
+        ? SePlayerChooseWord.fromJSON(object.playerChooseWord)
+        : undefined,
+      playerGuessWord: isSet(object.playerGuessWord) ? SePlayerGuessWord.fromJSON(object.playerGuessWord) : undefined,
+      playerLikeRound: isSet(object.playerLikeRound) ? SePlayerLikeRound.fromJSON(object.playerLikeRound) : undefined,
+      roundIncDrawScore: isSet(object.roundIncDrawScore)
+        ? SeRoundIncDrawScore.fromJSON(object.roundIncDrawScore)
+        : undefined,
+      roundIncGuessScore: isSet(object.roundIncGuessScore)
+        ? SeRoundIncGuessScore.fromJSON(object.roundIncGuessScore)
+        : undefined,
+      roundChangePhase: isSet(object.roundChangePhase)
+        ? SeRoundChangePhase.fromJSON(object.roundChangePhase)
+        : undefined,
+      roundSkip: isSet(object.roundSkip) ? SeRoundSkip.fromJSON(object.roundSkip) : undefined,
+      roundHint: isSet(object.roundHint) ? SeRoundHint.fromJSON(object.roundHint) : undefined,
+      roundGuess: isSet(object.roundGuess) ? SeRoundGuess.fromJSON(object.roundGuess) : undefined,
+      roundRevealWords: isSet(object.roundRevealWords)
+        ? SeRoundRevealWords.fromJSON(object.roundRevealWords)
+        : undefined,
+      playerGuessClose: isSet(object.playerGuessClose)
+        ? SePlayerGuessClose.fromJSON(object.playerGuessClose)
+        : undefined,
+      playerChangeSettings: isSet(object.playerChangeSettings)
+        ? SePlayerChangeSettings.fromJSON(object.playerChangeSettings)
+        : undefined,
+      gameOver: isSet(object.gameOver) ? SeGameOver.fromJSON(object.gameOver) : undefined,
+      playerPlayAgain: isSet(object.playerPlayAgain) ? SePlayerPlayAgain.fromJSON(object.playerPlayAgain) : undefined,
+      playerDrawOpReject: isSet(object.playerDrawOpReject)
+        ? SePlayerDrawOpReject.fromJSON(object.playerDrawOpReject)
+        : undefined,
+      playerStartMatch: isSet(object.playerStartMatch)
+        ? SePlayerStartMatch.fromJSON(object.playerStartMatch)
+        : undefined,
+      playerEndGame: isSet(object.playerEndGame) ? SePlayerEndGame.fromJSON(object.playerEndGame) : undefined,
+      playerKick: isSet(object.playerKick) ? SePlayerKick.fromJSON(object.playerKick) : undefined,
+      playerReady: isSet(object.playerReady) ? SePlayerReady.fromJSON(object.playerReady) : undefined,
+      spectators: isSet(object.spectators) ? SeSpectators.fromJSON(object.spectators) : undefined,
+      playerVote: isSet(object.playerVote) ? SePlayerVote.fromJSON(object.playerVote) : undefined,
+      roundVote: isSet(object.roundVote) ? SeRoundVote.fromJSON(object.roundVote) : undefined,
+      voteResult: isSet(object.voteResult) ? SeVoteResult.fromJSON(object.voteResult) : undefined,
+      playerChat: isSet(object.playerChat) ? SePlayerChat.fromJSON(object.playerChat) : undefined,
+      roomChat: isSet(object.roomChat) ? SeRoomChat.fromJSON(object.roomChat) : undefined,
+      playerChatReject: isSet(object.playerChatReject)
+        ? SePlayerChatReject.fromJSON(object.playerChatReject)
+        : undefined,
+      roundLike: isSet(object.roundLike) ? SeRoundLike.fromJSON(object.roundLike) : undefined,
+    };
+  },
+
+  toJSON(message: ServerEvent): unknown {
+    const obj: any = {};
+    if (message.playerJoin !== undefined) {
+      obj.playerJoin = SePlayerJoin.toJSON(message.playerJoin);
+    }
+    if (message.playerLeave !== undefined) {
+      obj.playerLeave = SePlayerLeave.toJSON(message.playerLeave);
+    }
+    if (message.playerRename !== undefined) {
+      obj.playerRename = SePlayerRename.toJSON(message.playerRename);
+    }
+    if (message.playerIncRoundScore !== undefined) {
+      obj.playerIncRoundScore = SePlayerIncRoundScore.toJSON(message.playerIncRoundScore);
+    }
+    if (message.playerIncDrawScore !== undefined) {
+      obj.playerIncDrawScore = SePlayerIncDrawScore.toJSON(message.playerIncDrawScore);
+    }
+    if (message.playerIncGuessScore !== undefined) {
+      obj.playerIncGuessScore = SePlayerIncGuessScore.toJSON(message.playerIncGuessScore);
+    }
+    if (message.setGame !== undefined) {
+      obj.setGame = SeSetGame.toJSON(message.setGame);
+    }
+    if (message.error !== undefined) {
+      obj.error = SeError.toJSON(message.error);
+    }
+    if (message.playerConnect !== undefined) {
+      obj.playerConnect = SePlayerConnect.toJSON(message.playerConnect);
+    }
+    if (message.playerDisconnect !== undefined) {
+      obj.playerDisconnect = SePlayerDisconnect.toJSON(message.playerDisconnect);
+    }
+    if (message.playerDrawOp !== undefined) {
+      obj.playerDrawOp = SePlayerDrawOp.toJSON(message.playerDrawOp);
+    }
+    if (message.newRound !== undefined) {
+      obj.newRound = SeRoundNew.toJSON(message.newRound);
+    }
+    if (message.playerChooseWord !== undefined) {
+      obj.playerChooseWord = SePlayerChooseWord.toJSON(message.playerChooseWord);
+    }
+    if (message.playerGuessWord !== undefined) {
+      obj.playerGuessWord = SePlayerGuessWord.toJSON(message.playerGuessWord);
+    }
+    if (message.playerLikeRound !== undefined) {
+      obj.playerLikeRound = SePlayerLikeRound.toJSON(message.playerLikeRound);
+    }
+    if (message.roundIncDrawScore !== undefined) {
+      obj.roundIncDrawScore = SeRoundIncDrawScore.toJSON(message.roundIncDrawScore);
+    }
+    if (message.roundIncGuessScore !== undefined) {
+      obj.roundIncGuessScore = SeRoundIncGuessScore.toJSON(message.roundIncGuessScore);
+    }
+    if (message.roundChangePhase !== undefined) {
+      obj.roundChangePhase = SeRoundChangePhase.toJSON(message.roundChangePhase);
+    }
+    if (message.roundSkip !== undefined) {
+      obj.roundSkip = SeRoundSkip.toJSON(message.roundSkip);
+    }
+    if (message.roundHint !== undefined) {
+      obj.roundHint = SeRoundHint.toJSON(message.roundHint);
+    }
+    if (message.roundGuess !== undefined) {
+      obj.roundGuess = SeRoundGuess.toJSON(message.roundGuess);
+    }
+    if (message.roundRevealWords !== undefined) {
+      obj.roundRevealWords = SeRoundRevealWords.toJSON(message.roundRevealWords);
+    }
+    if (message.playerGuessClose !== undefined) {
+      obj.playerGuessClose = SePlayerGuessClose.toJSON(message.playerGuessClose);
+    }
+    if (message.playerChangeSettings !== undefined) {
+      obj.playerChangeSettings = SePlayerChangeSettings.toJSON(message.playerChangeSettings);
+    }
+    if (message.gameOver !== undefined) {
+      obj.gameOver = SeGameOver.toJSON(message.gameOver);
+    }
+    if (message.playerPlayAgain !== undefined) {
+      obj.playerPlayAgain = SePlayerPlayAgain.toJSON(message.playerPlayAgain);
+    }
+    if (message.playerDrawOpReject !== undefined) {
+      obj.playerDrawOpReject = SePlayerDrawOpReject.toJSON(message.playerDrawOpReject);
+    }
+    if (message.playerStartMatch !== undefined) {
+      obj.playerStartMatch = SePlayerStartMatch.toJSON(message.playerStartMatch);
+    }
+    if (message.playerEndGame !== undefined) {
+      obj.playerEndGame = SePlayerEndGame.toJSON(message.playerEndGame);
+    }
+    if (message.playerKick !== undefined) {
+      obj.playerKick = SePlayerKick.toJSON(message.playerKick);
+    }
+    if (message.playerReady !== undefined) {
+      obj.playerReady = SePlayerReady.toJSON(message.playerReady);
+    }
+    if (message.spectators !== undefined) {
+      obj.spectators = SeSpectators.toJSON(message.spectators);
+    }
+    if (message.playerVote !== undefined) {
+      obj.playerVote = SePlayerVote.toJSON(message.playerVote);
+    }
+    if (message.roundVote !== undefined) {
+      obj.roundVote = SeRoundVote.toJSON(message.roundVote);
+    }
+    if (message.voteResult !== undefined) {
+      obj.voteResult = SeVoteResult.toJSON(message.voteResult);
+    }
+    if (message.playerChat !== undefined) {
+      obj.playerChat = SePlayerChat.toJSON(message.playerChat);
+    }
+    if (message.roomChat !== undefined) {
+      obj.roomChat = SeRoomChat.toJSON(message.roomChat);
+    }
+    if (message.playerChatReject !== undefined) {
+      obj.playerChatReject = SePlayerChatReject.toJSON(message.playerChatReject);
+    }
+    if (message.roundLike !== undefined) {
+      obj.roundLike = SeRoundLike.toJSON(message.roundLike);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ServerEvent>, I>>(base?: I): ServerEvent {
+    return ServerEvent.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ServerEvent>, I>>(object: I): ServerEvent {
     const message = createBaseServerEvent();
     message.playerJoin = (object.playerJoin !== undefined && object.playerJoin !== null)
       ? SePlayerJoin.fromPartial(object.playerJoin)
@@ -3651,40 +7335,581 @@ export const ServerEvent: MessageFns<ServerEvent> = {
     message.roundIncGuessScore = (object.roundIncGuessScore !== undefined && object.roundIncGuessScore !== null)
       ? SeRoundIncGuessScore.fromPartial(object.roundIncGuessScore)
       : undefined;
-    message.roundChangePhase = (object.roundChangePhase !== undefined && object.roundChangePhase !== null)
-      ? SeRoundChangePhase.fromPartial(object.roundChangePhase)
+    message.roundChangePhase = (object.roundChangePhase !== undefined && object.roundChangePhase !== null)
+      ? SeRoundChangePhase.fromPartial(object.roundChangePhase)
+      : undefined;
+    message.roundSkip = (object.roundSkip !== undefined && object.roundSkip !== null)
+      ? SeRoundSkip.fromPartial(object.roundSkip)
+      : undefined;
+    message.roundHint = (object.roundHint !== undefined && object.roundHint !== null)
+      ? SeRoundHint.fromPartial(object.roundHint)
+      : undefined;
+    message.roundGuess = (object.roundGuess !== undefined && object.roundGuess !== null)
+      ? SeRoundGuess.fromPartial(object.roundGuess)
+      : undefined;
+    message.roundRevealWords = (object.roundRevealWords !== undefined && object.roundRevealWords !== null)
+      ? SeRoundRevealWords.fromPartial(object.roundRevealWords)
+      : undefined;
+    message.playerGuessClose = (object.playerGuessClose !== undefined && object.playerGuessClose !== null)
+      ? SePlayerGuessClose.fromPartial(object.playerGuessClose)
+      : undefined;
+    message.playerChangeSettings = (object.playerChangeSettings !== undefined && object.playerChangeSettings !== null)
+      ? SePlayerChangeSettings.fromPartial(object.playerChangeSettings)
+      : undefined;
+    message.gameOver = (object.gameOver !== undefined && object.gameOver !== null)
+      ? SeGameOver.fromPartial(object.gameOver)
+      : undefined;
+    message.playerPlayAgain = (object.playerPlayAgain !== undefined && object.playerPlayAgain !== null)
+      ? SePlayerPlayAgain.fromPartial(object.playerPlayAgain)
+      : undefined;
+    message.playerDrawOpReject = (object.playerDrawOpReject !== undefined && object.playerDrawOpReject !== null)
+      ? SePlayerDrawOpReject.fromPartial(object.playerDrawOpReject)
+      : undefined;
+    message.playerStartMatch = (object.playerStartMatch !== undefined && object.playerStartMatch !== null)
+      ? SePlayerStartMatch.fromPartial(object.playerStartMatch)
+      : undefined;
+    message.playerEndGame = (object.playerEndGame !== undefined && object.playerEndGame !== null)
+      ? SePlayerEndGame.fromPartial(object.playerEndGame)
+      : undefined;
+    message.playerKick = (object.playerKick !== undefined && object.playerKick !== null)
+      ? SePlayerKick.fromPartial(object.playerKick)
+      : undefined;
+    message.playerReady = (object.playerReady !== undefined && object.playerReady !== null)
+      ? SePlayerReady.fromPartial(object.playerReady)
+      : undefined;
+    message.spectators = (object.spectators !== undefined && object.spectators !== null)
+      ? SeSpectators.fromPartial(object.spectators)
+      : undefined;
+    message.playerVote = (object.playerVote !== undefined && object.playerVote !== null)
+      ? SePlayerVote.fromPartial(object.playerVote)
+      : undefined;
+    message.roundVote = (object.roundVote !== undefined && object.roundVote !== null)
+      ? SeRoundVote.fromPartial(object.roundVote)
+      : undefined;
+    message.voteResult = (object.voteResult !== undefined && object.voteResult !== null)
+      ? SeVoteResult.fromPartial(object.voteResult)
+      : undefined;
+    message.playerChat = (object.playerChat !== undefined && object.playerChat !== null)
+      ? SePlayerChat.fromPartial(object.playerChat)
+      : undefined;
+    message.roomChat = (object.roomChat !== undefined && object.roomChat !== null)
+      ? SeRoomChat.fromPartial(object.roomChat)
+      : undefined;
+    message.playerChatReject = (object.playerChatReject !== undefined && object.playerChatReject !== null)
+      ? SePlayerChatReject.fromPartial(object.playerChatReject)
+      : undefined;
+    message.roundLike = (object.roundLike !== undefined && object.roundLike !== null)
+      ? SeRoundLike.fromPartial(object.roundLike)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseServerEvents(): ServerEvents {
+  return { events: [] };
+}
+
+export const ServerEvents: MessageFns<ServerEvents> = {
+  encode(message: ServerEvents, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    for (const v of message.events) {
+      ServerEvent.encode(v!, writer.uint32(10).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): ServerEvents {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseServerEvents();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.events.push(ServerEvent.decode(reader, reader.uint32()));
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): ServerEvents {
+    return {
+      events: globalThis.Array.isArray(object?.events) ? object.events.map((e: any) => ServerEvent.fromJSON(e)) : [],
+    };
+  },
+
+  toJSON(message: ServerEvents): unknown {
+    const obj: any = {};
+    if (message.events?.length) {
+      obj.events = message.events.map((e) => ServerEvent.toJSON(e));
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<ServerEvents>, I>>(base?: I): ServerEvents {
+    return ServerEvents.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<ServerEvents>, I>>(object: I): ServerEvents {
+    const message = createBaseServerEvents();
+    message.events = object.events?.map((e) => ServerEvent.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseCeRename(): CeRename {
+  return { name: "" };
+}
+
+export const CeRename: MessageFns<CeRename> = {
+  encode(message: CeRename, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.name !== "") {
+      writer.uint32(10).string(message.name);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CeRename {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCeRename();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CeRename {
+    return { name: isSet(object.name) ? globalThis.String(object.name) : "" };
+  },
+
+  toJSON(message: CeRename): unknown {
+    const obj: any = {};
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CeRename>, I>>(base?: I): CeRename {
+    return CeRename.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CeRename>, I>>(object: I): CeRename {
+    const message = createBaseCeRename();
+    message.name = object.name ?? "";
+    return message;
+  },
+};
+
+function createBaseCeChooseWord(): CeChooseWord {
+  return { choice: 0 };
+}
+
+export const CeChooseWord: MessageFns<CeChooseWord> = {
+  encode(message: CeChooseWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.choice !== 0) {
+      writer.uint32(8).int32(message.choice);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CeChooseWord {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCeChooseWord();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.choice = reader.int32() as any;
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CeChooseWord {
+    return { choice: isSet(object.choice) ? wordChoiceFromJSON(object.choice) : 0 };
+  },
+
+  toJSON(message: CeChooseWord): unknown {
+    const obj: any = {};
+    if (message.choice !== 0) {
+      obj.choice = wordChoiceToJSON(message.choice);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CeChooseWord>, I>>(base?: I): CeChooseWord {
+    return CeChooseWord.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CeChooseWord>, I>>(object: I): CeChooseWord {
+    const message = createBaseCeChooseWord();
+    message.choice = object.choice ?? 0;
+    return message;
+  },
+};
+
+function createBaseCeGuessWord(): CeGuessWord {
+  return { guess: "", afterDrawOps: 0, watchingId: undefined };
+}
+
+export const CeGuessWord: MessageFns<CeGuessWord> = {
+  encode(message: CeGuessWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.guess !== "") {
+      writer.uint32(10).string(message.guess);
+    }
+    if (message.afterDrawOps !== 0) {
+      writer.uint32(24).uint32(message.afterDrawOps);
+    }
+    if (message.watchingId !== undefined) {
+      writer.uint32(32).uint32(message.watchingId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CeGuessWord {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCeGuessWord();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.guess = reader.string();
+          continue;
+        }
+        case 3: {
+          if (tag !== 24) {
+            break;
+          }
+
+          message.afterDrawOps = reader.uint32();
+          continue;
+        }
+        case 4: {
+          if (tag !== 32) {
+            break;
+          }
+
+          message.watchingId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CeGuessWord {
+    return {
+      guess: isSet(object.guess) ? globalThis.String(object.guess) : "",
+      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
+      watchingId: isSet(object.watchingId) ? globalThis.Number(object.watchingId) : undefined,
+    };
+  },
+
+  toJSON(message: CeGuessWord): unknown {
+    const obj: any = {};
+    if (message.guess !== "") {
+      obj.guess = message.guess;
+    }
+    if (message.afterDrawOps !== 0) {
+      obj.afterDrawOps = Math.round(message.afterDrawOps);
+    }
+    if (message.watchingId !== undefined) {
+      obj.watchingId = Math.round(message.watchingId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CeGuessWord>, I>>(base?: I): CeGuessWord {
+    return CeGuessWord.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CeGuessWord>, I>>(object: I): CeGuessWord {
+    const message = createBaseCeGuessWord();
+    message.guess = object.guess ?? "";
+    message.afterDrawOps = object.afterDrawOps ?? 0;
+    message.watchingId = object.watchingId ?? undefined;
+    return message;
+  },
+};
+
+function createBaseCeLikeRound(): CeLikeRound {
+  return { roundId: 0 };
+}
+
+export const CeLikeRound: MessageFns<CeLikeRound> = {
+  encode(message: CeLikeRound, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.roundId !== 0) {
+      writer.uint32(8).uint32(message.roundId);
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CeLikeRound {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCeLikeRound();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 8) {
+            break;
+          }
+
+          message.roundId = reader.uint32();
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CeLikeRound {
+    return { roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0 };
+  },
+
+  toJSON(message: CeLikeRound): unknown {
+    const obj: any = {};
+    if (message.roundId !== 0) {
+      obj.roundId = Math.round(message.roundId);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CeLikeRound>, I>>(base?: I): CeLikeRound {
+    return CeLikeRound.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CeLikeRound>, I>>(object: I): CeLikeRound {
+    const message = createBaseCeLikeRound();
+    message.roundId = object.roundId ?? 0;
+    return message;
+  },
+};
+
+function createBaseCeDrawOp(): CeDrawOp {
+  return { drawOp: undefined };
+}
+
+export const CeDrawOp: MessageFns<CeDrawOp> = {
+  encode(message: CeDrawOp, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.drawOp !== undefined) {
+      DrawOp.encode(message.drawOp, writer.uint32(10).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CeDrawOp {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCeDrawOp();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.drawOp = DrawOp.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CeDrawOp {
+    return { drawOp: isSet(object.drawOp) ? DrawOp.fromJSON(object.drawOp) : undefined };
+  },
+
+  toJSON(message: CeDrawOp): unknown {
+    const obj: any = {};
+    if (message.drawOp !== undefined) {
+      obj.drawOp = DrawOp.toJSON(message.drawOp);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CeDrawOp>, I>>(base?: I): CeDrawOp {
+    return CeDrawOp.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CeDrawOp>, I>>(object: I): CeDrawOp {
+    const message = createBaseCeDrawOp();
+    message.drawOp = (object.drawOp !== undefined && object.drawOp !== null)
+      ? DrawOp.fromPartial(object.drawOp)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseCeChangeSettings(): CeChangeSettings {
+  return { settings: undefined };
+}
+
+export const CeChangeSettings: MessageFns<CeChangeSettings> = {
+  encode(message: CeChangeSettings, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.settings !== undefined) {
+      GameSettings.encode(message.settings, writer.uint32(10).fork()).join();
+    }
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CeChangeSettings {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCeChangeSettings();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1: {
+          if (tag !== 10) {
+            break;
+          }
+
+          message.settings = GameSettings.decode(reader, reader.uint32());
+          continue;
+        }
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): CeChangeSettings {
+    return { settings: isSet(object.settings) ? GameSettings.fromJSON(object.settings) : undefined };
+  },
+
+  toJSON(message: CeChangeSettings): unknown {
+    const obj: any = {};
+    if (message.settings !== undefined) {
+      obj.settings = GameSettings.toJSON(message.settings);
+    }
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CeChangeSettings>, I>>(base?: I): CeChangeSettings {
+    return CeChangeSettings.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CeChangeSettings>, I>>(object: I): CeChangeSettings {
+    const message = createBaseCeChangeSettings();
+    message.settings = (object.settings !== undefined && object.settings !== null)
+      ? GameSettings.fromPartial(object.settings)
       : undefined;
     return message;
   },
 };
 
-function createBaseServerEvents(): ServerEvents {
-  return { events: [] };
+function createBaseCePlayAgain(): CePlayAgain {
+  return {};
+}
+
+export const CePlayAgain: MessageFns<CePlayAgain> = {
+  encode(_: CePlayAgain, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    return writer;
+  },
+
+  decode(input: BinaryReader | Uint8Array, length?: number): CePlayAgain {
+    const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseCePlayAgain();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skip(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): CePlayAgain {
+    return {};
+  },
+
+  toJSON(_: CePlayAgain): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create<I extends Exact<DeepPartial<CePlayAgain>, I>>(base?: I): CePlayAgain {
+    return CePlayAgain.fromPartial(base ?? ({} as any));
+  },
+  fromPartial<I extends Exact<DeepPartial<CePlayAgain>, I>>(_: I): CePlayAgain {
+    const message = createBaseCePlayAgain();
+    return message;
+  },
+};
+
+function createBaseCeStartMatch(): CeStartMatch {
+  return {};
 }
 
-export const ServerEvents: MessageFns<ServerEvents> = {
-  encode(message: ServerEvents, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    for (const v of message.events) {
-      ServerEvent.encode(v!, writer.uint32(10).fork()).join();
-    }
+export const CeStartMatch: MessageFns<CeStartMatch> = {
+  encode(_: CeStartMatch, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): ServerEvents {
+  decode(input: BinaryReader | Uint8Array, length?: number): CeStartMatch {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseServerEvents();
+    const message = createBaseCeStartMatch();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
-        case 1: {
-          if (tag !== 10) {
-            break;
-          }
-
-          message.events.push(ServerEvent.decode(reader, reader.uint32()));
-          continue;
-        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -3694,57 +7919,40 @@ export const ServerEvents: MessageFns<ServerEvents> = {
     return message;
   },
 
-  fromJSON(object: any): ServerEvents {
-    return {
-      events: globalThis.Array.isArray(object?.events) ? object.events.map((e: any) => ServerEvent.fromJSON(e)) : [],
-    };
+  fromJSON(_: any): CeStartMatch {
+    return {};
   },
 
-  toJSON(message: ServerEvents): unknown {
+  toJSON(_: CeStartMatch): unknown {
     const obj: any = {};
-    if (message.events?.length) {
-      obj.events = message.events.map((e) => ServerEvent.toJSON(e));
-    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<ServerEvents>, I>>(base?: I): ServerEvents {
-    return ServerEvents.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<CeStartMatch>, I>>(base?: I): CeStartMatch {
+    return CeStartMatch.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<ServerEvents>, I>>(object: I): ServerEvents {
-    const message = createBaseServerEvents();
-    message.events = object.events?.map((e) => ServerEvent.fromPartial(e)) || [];
+  fromPartial<I extends Exact<DeepPartial<CeStartMatch>, I>>(_: I): CeStartMatch {
+    const message = createBaseCeStartMatch();
     return message;
   },
 };
 
-function createBaseCeRename(): CeRename {
-  return { name: "" };
+function createBaseCeEndGame(): CeEndGame {
+  return {};
 }
 
-export const CeRename: MessageFns<CeRename> = {
-  encode(message: CeRename, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.name !== "") {
-      writer.uint32(10).string(message.name);
-    }
+export const CeEndGame: MessageFns<CeEndGame> = {
+  encode(_: CeEndGame, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): CeRename {
+  decode(input: BinaryReader | Uint8Array, length?: number): CeEndGame {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCeRename();
+    const message = createBaseCeEndGame();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
-        case 1: {
-          if (tag !== 10) {
-            break;
-          }
-
-          message.name = reader.string();
-          continue;
-        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -3754,44 +7962,40 @@ export const CeRename: MessageFns<CeRename> = {
     return message;
   },
 
-  fromJSON(object: any): CeRename {
-    return { name: isSet(object.name) ? globalThis.String(object.name) : "" };
+  fromJSON(_: any): CeEndGame {
+    return {};
   },
 
-  toJSON(message: CeRename): unknown {
+  toJSON(_: CeEndGame): unknown {
     const obj: any = {};
-    if (message.name !== "") {
-      obj.name = message.name;
-    }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<CeRename>, I>>(base?: I): CeRename {
-    return CeRename.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<CeEndGame>, I>>(base?: I): CeEndGame {
+    return CeEndGame.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<CeRename>, I>>(object: I): CeRename {
-    const message = createBaseCeRename();
-    message.name = object.name ?? "";
+  fromPartial<I extends Exact<DeepPartial<CeEndGame>, I>>(_: I): CeEndGame {
+    const message = createBaseCeEndGame();
     return message;
   },
 };
 
-function createBaseCeChooseWord(): CeChooseWord {
-  return { choice: 0 };
+function createBaseCeKickPlayer(): CeKickPlayer {
+  return { playerId: 0 };
 }
 
-export const CeChooseWord: MessageFns<CeChooseWord> = {
-  encode(message: CeChooseWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.choice !== 0) {
-      writer.uint32(8).int32(message.choice);
+export const CeKickPlayer: MessageFns<CeKickPlayer> = {
+  encode(message: CeKickPlayer, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.playerId !== 0) {
+      writer.uint32(8).uint32(message.playerId);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): CeChooseWord {
+  decode(input: BinaryReader | Uint8Array, length?: number): CeKickPlayer {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCeChooseWord();
+    const message = createBaseCeKickPlayer();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -3800,7 +8004,7 @@ export const CeChooseWord: MessageFns<CeChooseWord> = {
             break;
           }
 
-          message.choice = reader.int32() as any;
+          message.playerId = reader.uint32();
           continue;
         }
       }
@@ -3812,64 +8016,53 @@ export const CeChooseWord: MessageFns<CeChooseWord> = {
     return message;
   },
 
-  fromJSON(object: any): CeChooseWord {
-    return { choice: isSet(object.choice) ? wordChoiceFromJSON(object.choice) : 0 };
+  fromJSON(object: any): CeKickPlayer {
+    return { playerId: isSet(object.playerId) ? globalThis.Number(object.playerId) : 0 };
   },
 
-  toJSON(message: CeChooseWord): unknown {
+  toJSON(message: CeKickPlayer): unknown {
     const obj: any = {};
-    if (message.choice !== 0) {
-      obj.choice = wordChoiceToJSON(message.choice);
+    if (message.playerId !== 0) {
+      obj.playerId = Math.round(message.playerId);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<CeChooseWord>, I>>(base?: I): CeChooseWord {
-    return CeChooseWord.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<CeKickPlayer>, I>>(base?: I): CeKickPlayer {
+    return CeKickPlayer.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<CeChooseWord>, I>>(object: I): CeChooseWord {
-    const message = createBaseCeChooseWord();
-    message.choice = object.choice ?? 0;
+  fromPartial<I extends Exact<DeepPartial<CeKickPlayer>, I>>(object: I): CeKickPlayer {
+    const message = createBaseCeKickPlayer();
+    message.playerId = object.playerId ?? 0;
     return message;
   },
 };
 
-function createBaseCeGuessWord(): CeGuessWord {
-  return { guess: "", afterDrawOps: 0 };
+function createBaseCeReady(): CeReady {
+  return { ready: false };
 }
 
-export const CeGuessWord: MessageFns<CeGuessWord> = {
-  encode(message: CeGuessWord, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.guess !== "") {
-      writer.uint32(10).string(message.guess);
-    }
-    if (message.afterDrawOps !== 0) {
-      writer.uint32(24).uint32(message.afterDrawOps);
+export const CeReady: MessageFns<CeReady> = {
+  encode(message: CeReady, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.ready !== false) {
+      writer.uint32(8).bool(message.ready);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): CeGuessWord {
+  decode(input: BinaryReader | Uint8Array, length?: number): CeReady {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCeGuessWord();
+    const message = createBaseCeReady();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1: {
-          if (tag !== 10) {
-            break;
-          }
-
-          message.guess = reader.string();
-          continue;
-        }
-        case 3: {
-          if (tag !== 24) {
+          if (tag !== 8) {
             break;
           }
 
-          message.afterDrawOps = reader.uint32();
+          message.ready = reader.bool();
           continue;
         }
       }
@@ -3881,51 +8074,44 @@ export const CeGuessWord: MessageFns<CeGuessWord> = {
     return message;
   },
 
-  fromJSON(object: any): CeGuessWord {
-    return {
-      guess: isSet(object.guess) ? globalThis.String(object.guess) : "",
-      afterDrawOps: isSet(object.afterDrawOps) ? globalThis.Number(object.afterDrawOps) : 0,
-    };
+  fromJSON(object: any): CeReady {
+    return { ready: isSet(object.ready) ? globalThis.Boolean(object.ready) : false };
   },
 
-  toJSON(message: CeGuessWord): unknown {
+  toJSON(message: CeReady): unknown {
     const obj: any = {};
-    if (message.guess !== "") {
-      obj.guess = message.guess;
-    }
-    if (message.afterDrawOps !== 0) {
-      obj.afterDrawOps = Math.round(message.afterDrawOps);
+    if (message.ready !== false) {
+      obj.ready = message.ready;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<CeGuessWord>, I>>(base?: I): CeGuessWord {
-    return CeGuessWord.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<CeReady>, I>>(base?: I): CeReady {
+    return CeReady.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<CeGuessWord>, I>>(object: I): CeGuessWord {
-    const message = createBaseCeGuessWord();
-    message.guess = object.guess ?? "";
-    message.afterDrawOps = object.afterDrawOps ?? 0;
+  fromPartial<I extends Exact<DeepPartial<CeReady>, I>>(object: I): CeReady {
+    const message = createBaseCeReady();
+    message.ready = object.ready ?? false;
     return message;
   },
 };
 
-function createBaseCeLikeRound(): CeLikeRound {
-  return { roundId: 0 };
+function createBaseCeVote(): CeVote {
+  return { drawerId: 0 };
 }
 
-export const CeLikeRound: MessageFns<CeLikeRound> = {
-  encode(message: CeLikeRound, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.roundId !== 0) {
-      writer.uint32(8).uint32(message.roundId);
+export const CeVote: MessageFns<CeVote> = {
+  encode(message: CeVote, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.drawerId !== 0) {
+      writer.uint32(8).uint32(message.drawerId);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): CeLikeRound {
+  decode(input: BinaryReader | Uint8Array, length?: number): CeVote {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCeLikeRound();
+    const message = createBaseCeVote();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -3934,7 +8120,7 @@ export const CeLikeRound: MessageFns<CeLikeRound> = {
             break;
           }
 
-          message.roundId = reader.uint32();
+          message.drawerId = reader.uint32();
           continue;
         }
       }
@@ -3946,44 +8132,44 @@ export const CeLikeRound: MessageFns<CeLikeRound> = {
     return message;
   },
 
-  fromJSON(object: any): CeLikeRound {
-    return { roundId: isSet(object.roundId) ? globalThis.Number(object.roundId) : 0 };
+  fromJSON(object: any): CeVote {
+    return { drawerId: isSet(object.drawerId) ? globalThis.Number(object.drawerId) : 0 };
   },
 
-  toJSON(message: CeLikeRound): unknown {
+  toJSON(message: CeVote): unknown {
     const obj: any = {};
-    if (message.roundId !== 0) {
-      obj.roundId = Math.round(message.roundId);
+    if (message.drawerId !== 0) {
+      obj.drawerId = Math.round(message.drawerId);
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<CeLikeRound>, I>>(base?: I): CeLikeRound {
-    return CeLikeRound.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<CeVote>, I>>(base?: I): CeVote {
+    return CeVote.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<CeLikeRound>, I>>(object: I): CeLikeRound {
-    const message = createBaseCeLikeRound();
-    message.roundId = object.roundId ?? 0;
+  fromPartial<I extends Exact<DeepPartial<CeVote>, I>>(object: I): CeVote {
+    const message = createBaseCeVote();
+    message.drawerId = object.drawerId ?? 0;
     return message;
   },
 };
 
-function createBaseCeDrawOp(): CeDrawOp {
-  return { drawOp: undefined };
+function createBaseCeChat(): CeChat {
+  return { text: "" };
 }
 
-export const CeDrawOp: MessageFns<CeDrawOp> = {
-  encode(message: CeDrawOp, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
-    if (message.drawOp !== undefined) {
-      DrawOp.encode(message.drawOp, writer.uint32(10).fork()).join();
+export const CeChat: MessageFns<CeChat> = {
+  encode(message: CeChat, writer: BinaryWriter = new BinaryWriter()): BinaryWriter {
+    if (message.text !== "") {
+      writer.uint32(10).string(message.text);
     }
     return writer;
   },
 
-  decode(input: BinaryReader | Uint8Array, length?: number): CeDrawOp {
+  decode(input: BinaryReader | Uint8Array, length?: number): CeChat {
     const reader = input instanceof BinaryReader ? input : new BinaryReader(input);
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = createBaseCeDrawOp();
+    const message = createBaseCeChat();
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -3992,7 +8178,7 @@ export const CeDrawOp: MessageFns<CeDrawOp> = {
             break;
           }
 
-          message.drawOp = DrawOp.decode(reader, reader.uint32());
+          message.text = reader.string();
           continue;
         }
       }
@@ -4004,32 +8190,44 @@ export const CeDrawOp: MessageFns<CeDrawOp> = {
     return message;
   },
 
-  fromJSON(object: any): CeDrawOp {
-    return { drawOp: isSet(object.drawOp) ? DrawOp.fromJSON(object.drawOp) : undefined };
+  fromJSON(object: any): CeChat {
+    return { text: isSet(object.text) ? globalThis.String(object.text) : "" };
   },
 
-  toJSON(message: CeDrawOp): unknown {
+  toJSON(message: CeChat): unknown {
     const obj: any = {};
-    if (message.drawOp !== undefined) {
-      obj.drawOp = DrawOp.toJSON(message.drawOp);
+    if (message.text !== "") {
+      obj.text = message.text;
     }
     return obj;
   },
 
-  create<I extends Exact<DeepPartial<CeDrawOp>, I>>(base?: I): CeDrawOp {
-    return CeDrawOp.fromPartial(base ?? ({} as any));
+  create<I extends Exact<DeepPartial<CeChat>, I>>(base?: I): CeChat {
+    return CeChat.fromPartial(base ?? ({} as any));
   },
-  fromPartial<I extends Exact<DeepPartial<CeDrawOp>, I>>(object: I): CeDrawOp {
-    const message = createBaseCeDrawOp();
-    message.drawOp = (object.drawOp !== undefined && object.drawOp !== null)
-      ? DrawOp.fromPartial(object.drawOp)
-      : undefined;
+  fromPartial<I extends Exact<DeepPartial<CeChat>, I>>(object: I): CeChat {
+    const message = createBaseCeChat();
+    message.text = object.text ?? "";
     return message;
   },
 };
 
 function createBaseClientEvent(): ClientEvent {
-  return { rename: undefined, chooseWord: undefined, guessWord: undefined, likeRound: undefined, drawOp: undefined };
+  return {
+    rename: undefined,
+    chooseWord: undefined,
+    guessWord: undefined,
+    likeRound: undefined,
+    drawOp: undefined,
+    changeSettings: undefined,
+    playAgain: undefined,
+    startMatch: undefined,
+    endGame: undefined,
+    kickPlayer: undefined,
+    ready: undefined,
+    vote: undefined,
+    chat: undefined,
+  };
 }
 
 export const ClientEvent: MessageFns<ClientEvent> = {
@@ -4049,6 +8247,30 @@ export const ClientEvent: MessageFns<ClientEvent> = {
     if (message.drawOp !== undefined) {
       CeDrawOp.encode(message.drawOp, writer.uint32(42).fork()).join();
     }
+    if (message.changeSettings !== undefined) {
+      CeChangeSettings.encode(message.changeSettings, writer.uint32(50).fork()).join();
+    }
+    if (message.playAgain !== undefined) {
+      CePlayAgain.encode(message.playAgain, writer.uint32(58).fork()).join();
+    }
+    if (message.startMatch !== undefined) {
+      CeStartMatch.encode(message.startMatch, writer.uint32(66).fork()).join();
+    }
+    if (message.endGame !== undefined) {
+      CeEndGame.encode(message.endGame, writer.uint32(74).fork()).join();
+    }
+    if (message.kickPlayer !== undefined) {
+      CeKickPlayer.encode(message.kickPlayer, writer.uint32(82).fork()).join();
+    }
+    if (message.ready !== undefined) {
+      CeReady.encode(message.ready, writer.uint32(90).fork()).join();
+    }
+    if (message.vote !== undefined) {
+      CeVote.encode(message.vote, writer.uint32(98).fork()).join();
+    }
+    if (message.chat !== undefined) {
+      CeChat.encode(message.chat, writer.uint32(106).fork()).join();
+    }
     return writer;
   },
 
@@ -4099,6 +8321,70 @@ export const ClientEvent: MessageFns<ClientEvent> = {
           message.drawOp = CeDrawOp.decode(reader, reader.uint32());
           continue;
         }
+        case 6: {
+          if (tag !== 50) {
+            break;
+          }
+
+          message.changeSettings = CeChangeSettings.decode(reader, reader.uint32());
+          continue;
+        }
+        case 7: {
+          if (tag !== 58) {
+            break;
+          }
+
+          message.playAgain = CePlayAgain.decode(reader, reader.uint32());
+          continue;
+        }
+        case 8: {
+          if (tag !== 66) {
+            break;
+          }
+
+          message.startMatch = CeStartMatch.decode(reader, reader.uint32());
+          continue;
+        }
+        case 9: {
+          if (tag !== 74) {
+            break;
+          }
+
+          message.endGame = CeEndGame.decode(reader, reader.uint32());
+          continue;
+        }
+        case 10: {
+          if (tag !== 82) {
+            break;
+          }
+
+          message.kickPlayer = CeKickPlayer.decode(reader, reader.uint32());
+          continue;
+        }
+        case 11: {
+          if (tag !== 90) {
+            break;
+          }
+
+          message.ready = CeReady.decode(reader, reader.uint32());
+          continue;
+        }
+        case 12: {
+          if (tag !== 98) {
+            break;
+          }
+
+          message.vote = CeVote.decode(reader, reader.uint32());
+          continue;
+        }
+        case 13: {
+          if (tag !== 106) {
+            break;
+          }
+
+          message.chat = CeChat.decode(reader, reader.uint32());
+          continue;
+        }
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -4115,6 +8401,14 @@ export const ClientEvent: MessageFns<ClientEvent> = {
       guessWord: isSet(object.guessWord) ? CeGuessWord.fromJSON(object.guessWord) : undefined,
       likeRound: isSet(object.likeRound) ? CeLikeRound.fromJSON(object.likeRound) : undefined,
       drawOp: isSet(object.drawOp) ? CeDrawOp.fromJSON(object.drawOp) : undefined,
+      changeSettings: isSet(object.changeSettings) ? CeChangeSettings.fromJSON(object.changeSettings) : undefined,
+      playAgain: isSet(object.playAgain) ? CePlayAgain.fromJSON(object.playAgain) : undefined,
+      startMatch: isSet(object.startMatch) ? CeStartMatch.fromJSON(object.startMatch) : undefined,
+      endGame: isSet(object.endGame) ? CeEndGame.fromJSON(object.endGame) : undefined,
+      kickPlayer: isSet(object.kickPlayer) ? CeKickPlayer.fromJSON(object.kickPlayer) : undefined,
+      ready: isSet(object.ready) ? CeReady.fromJSON(object.ready) : undefined,
+      vote: isSet(object.vote) ? CeVote.fromJSON(object.vote) : undefined,
+      chat: isSet(object.chat) ? CeChat.fromJSON(object.chat) : undefined,
     };
   },
 
@@ -4135,6 +8429,30 @@ export const ClientEvent: MessageFns<ClientEvent> = {
     if (message.drawOp !== undefined) {
       obj.drawOp = CeDrawOp.toJSON(message.drawOp);
     }
+    if (message.changeSettings !== undefined) {
+      obj.changeSettings = CeChangeSettings.toJSON(message.changeSettings);
+    }
+    if (message.playAgain !== undefined) {
+      obj.playAgain = CePlayAgain.toJSON(message.playAgain);
+    }
+    if (message.startMatch !== undefined) {
+      obj.startMatch = CeStartMatch.toJSON(message.startMatch);
+    }
+    if (message.endGame !== undefined) {
+      obj.endGame = CeEndGame.toJSON(message.endGame);
+    }
+    if (message.kickPlayer !== undefined) {
+      obj.kickPlayer = CeKickPlayer.toJSON(message.kickPlayer);
+    }
+    if (message.ready !== undefined) {
+      obj.ready = CeReady.toJSON(message.ready);
+    }
+    if (message.vote !== undefined) {
+      obj.vote = CeVote.toJSON(message.vote);
+    }
+    if (message.chat !== undefined) {
+      obj.chat = CeChat.toJSON(message.chat);
+    }
     return obj;
   },
 
@@ -4158,6 +8476,26 @@ export const ClientEvent: MessageFns<ClientEvent> = {
     message.drawOp = (object.drawOp !== undefined && object.drawOp !== null)
       ? CeDrawOp.fromPartial(object.drawOp)
       : undefined;
+    message.changeSettings = (object.changeSettings !== undefined && object.changeSettings !== null)
+      ? CeChangeSettings.fromPartial(object.changeSettings)
+      : undefined;
+    message.playAgain = (object.playAgain !== undefined && object.playAgain !== null)
+      ? CePlayAgain.fromPartial(object.playAgain)
+      : undefined;
+    message.startMatch = (object.startMatch !== undefined && object.startMatch !== null)
+      ? CeStartMatch.fromPartial(object.startMatch)
+      : undefined;
+    message.endGame = (object.endGame !== undefined && object.endGame !== null)
+      ? CeEndGame.fromPartial(object.endGame)
+      : undefined;
+    message.kickPlayer = (object.kickPlayer !== undefined && object.kickPlayer !== null)
+      ? CeKickPlayer.fromPartial(object.kickPlayer)
+      : undefined;
+    message.ready = (object.ready !== undefined && object.ready !== null)
+      ? CeReady.fromPartial(object.ready)
+      : undefined;
+    message.vote = (object.vote !== undefined && object.vote !== null) ? CeVote.fromPartial(object.vote) : undefined;
+    message.chat = (object.chat !== undefined && object.chat !== null) ? CeChat.fromPartial(object.chat) : undefined;
     return message;
   },
 };
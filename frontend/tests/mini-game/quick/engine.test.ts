@@ -1,14 +1,30 @@
 import { describe, test, expect } from 'vitest';
 import {
     ColorType,
+    DrawOp,
+    DrawOpRejectReason,
     Game,
+    GameSettings,
+    Guess,
+    Hint,
     Phase,
+    RoundLikes,
+    ScoringPolicyType,
     ServerEvent,
     ServerEvents,
     WordChoice,
+    DEFAULT_SETTINGS,
+    MAX_CHAT_HISTORY,
     advanceAllGame,
 } from 'src/game/mini/engine';
-import { server_advance } from 'tests/wasm/drawduel_wasm';
+import {
+    encode_stroke_points,
+    round_next_hint,
+    round_replay,
+    round_replay_from,
+    round_strokes,
+    server_advance,
+} from 'tests/wasm/drawduel_wasm';
 
 import isString from 'lodash-es/isString';
 function log(...args: any[]) {
@@ -21,7 +37,13 @@ function log(...args: any[]) {
     }
 }
 
-function expectServerClientGamesInSync(serverEvent: ServerEvent, game: Game) {
+// now is what the server's clock reads, pass a fixed
+// time so anything timed, e.g. scores, is deterministic
+function expectServerClientGamesInSync(
+    serverEvent: ServerEvent,
+    game: Game,
+    now: number = 0,
+): Game | undefined {
     // log({
     //     game,
     //     sourceServerEvent: serverEvent,
@@ -29,6 +51,7 @@ function expectServerClientGamesInSync(serverEvent: ServerEvent, game: Game) {
     let next_state = server_advance(
         ServerEvent.encode(serverEvent).finish(),
         Game.encode(game).finish(),
+        now,
     );
     if (next_state) {
         let serverEvents = ServerEvents.decode(
@@ -42,6 +65,7 @@ function expectServerClientGamesInSync(serverEvent: ServerEvent, game: Game) {
         //     serverGame,
         // });
         expect(clientGame).toStrictEqual(serverGame);
+        return serverGame;
     }
 }
 
@@ -494,3 +518,856 @@ describe(
         });
     },
 );
+
+// a round of "ice cream" (easy word 1) that adam is drawing,
+// whose play phase started at PLAY_STARTED_AT and lasts the
+// default 45 secs
+const PLAY_STARTED_AT = 1_000_000;
+
+function playingGame(settings?: GameSettings): Game {
+    let player = (name: string) => ({ name, connected: true });
+    return Game.fromPartial({
+        players: {
+            0: player('adam'),
+            1: player('bob'),
+            2: player('cat'),
+        },
+        hostId: 0,
+        settings,
+        round: {
+            roundId: 0,
+            phase: Phase.PLAY,
+            phaseEndsAt: PLAY_STARTED_AT + 45_000,
+            drawerId: 0,
+            easyWord: 1,
+            hardWord: 1,
+            wordChoice: WordChoice.EASY,
+        },
+    });
+}
+
+function guessWord(guesserId: number, guess: string): ServerEvent {
+    return ServerEvent.fromPartial({
+        playerGuessWord: {
+            guesserId,
+            guess,
+            afterDrawOps: 0,
+        },
+    });
+}
+
+// bob guesses right 10 secs into the round, then cat
+// 20 secs in, which ends the play phase
+function scoreTwoGuesses(settings?: GameSettings): Game {
+    let game = playingGame(settings);
+    game = expectServerClientGamesInSync(
+        guessWord(1, 'ice cream'),
+        game,
+        PLAY_STARTED_AT + 10_000,
+    )!;
+    game = expectServerClientGamesInSync(
+        guessWord(2, 'ice cream'),
+        game,
+        PLAY_STARTED_AT + 20_000,
+    )!;
+    return game;
+}
+
+function drawGuessScores(game: Game): [number, number][] {
+    return [0, 1, 2].map((playerId) => [
+        game.players[playerId].drawScore,
+        game.players[playerId].guessScore,
+    ]);
+}
+
+describe('mini game scoring', { timeout: 500 }, () => {
+    test('classic', () => {
+        let game = scoreTwoGuesses();
+        // (bonus + base - secs) * easy multipler, only the
+        // first correct guess scores the drawer & the bonus
+        expect(drawGuessScores(game)).toStrictEqual([
+            [240, 0],
+            [0, 240],
+            [0, 200],
+        ]);
+        expect(game.players[2].roundScore).toBe(200);
+        expect(game.round!.drawScore).toBe(240);
+        expect(game.round!.guessScore).toBe(440);
+        // everyone guessed, so the round moves on
+        expect(game.round!.phase).toBe(Phase.POST_PLAY);
+        expect(game.round!.phaseEndsAt).toBe(PLAY_STARTED_AT + 25_000);
+    });
+
+    test('linear decay', () => {
+        let game = scoreTwoGuesses({
+            ...DEFAULT_SETTINGS,
+            scoringPolicy: ScoringPolicyType.LINEAR_DECAY,
+        });
+        // base * secs left / play secs, truncated
+        expect(drawGuessScores(game)).toStrictEqual([
+            [186, 0],
+            [0, 186],
+            [0, 132],
+        ]);
+    });
+
+    test('drawer per guess', () => {
+        let game = scoreTwoGuesses({
+            ...DEFAULT_SETTINGS,
+            scoringPolicy: ScoringPolicyType.DRAWER_PER_GUESS,
+        });
+        expect(drawGuessScores(game)).toStrictEqual([
+            [220, 0],
+            [0, 240],
+            [0, 200],
+        ]);
+    });
+
+    test('ranked', () => {
+        let game = scoreTwoGuesses({
+            ...DEFAULT_SETTINGS,
+            scoringPolicy: ScoringPolicyType.RANKED,
+        });
+        // only the order matters, not how long it took
+        expect(drawGuessScores(game)).toStrictEqual([
+            [240, 0],
+            [0, 240],
+            [0, 120],
+        ]);
+    });
+
+    test('same guess at the same time scores the same', () => {
+        let first = scoreTwoGuesses();
+        let second = scoreTwoGuesses();
+        expect(second).toStrictEqual(first);
+    });
+});
+
+// how the server judged bob's guess for the playing
+// game's word, easy word 1 is "ice cream", 2 is "tear"
+function judge(guess: string, easyWord: number = 1) {
+    let game = playingGame();
+    game.round!.easyWord = easyWord;
+    let serverGame = expectServerClientGamesInSync(
+        guessWord(1, guess),
+        game,
+        PLAY_STARTED_AT + 10_000,
+    )!;
+    let guesses = serverGame.round!.guesses;
+    let judged = guesses[guesses.length - 1];
+    if (judged.correctGuess) {
+        return 'correct';
+    }
+    return judged.incorrectGuess!.close ? 'close' : 'incorrect';
+}
+
+describe('mini game guess matching', { timeout: 500 }, () => {
+    test('case, spacing & punctuation are ignored', () => {
+        expect(judge('ice cream')).toBe('correct');
+        expect(judge('Ice Cream')).toBe('correct');
+        expect(judge('icecream')).toBe('correct');
+        expect(judge('ice cream ')).toBe('correct');
+        expect(judge('ice  cream')).toBe('correct');
+        expect(judge('ice-cream')).toBe('correct');
+        expect(judge('Tear!', 2)).toBe('correct');
+    });
+
+    test('accents & lookalike forms are ignored', () => {
+        expect(judge('ÍCE CRÉAM')).toBe('correct');
+        expect(judge('ｉｃｅ ｃｒｅａｍ')).toBe('correct');
+    });
+
+    test('nothing left after normalizing is never close', () => {
+        expect(judge('!!!')).toBe('incorrect');
+        expect(judge('   ')).toBe('incorrect');
+    });
+
+    test('longer words forgive 2 typos', () => {
+        expect(judge('ice crem')).toBe('close');
+        expect(judge('ice creem')).toBe('close');
+        expect(judge('ice creeem')).toBe('close');
+        expect(judge('ICE CRÈME')).toBe('close');
+        expect(judge('ic crm')).toBe('incorrect');
+        expect(judge('ice')).toBe('incorrect');
+    });
+
+    test('short words forgive 1 typo', () => {
+        expect(judge('teer', 2)).toBe('close');
+        expect(judge('tears', 2)).toBe('close');
+        expect(judge('tier', 2)).toBe('incorrect');
+        expect(judge('te', 2)).toBe('incorrect');
+    });
+
+    test('saying the word among other words is correct', () => {
+        // judged incorrect, everyone would see the word
+        expect(judge('its ice cream')).toBe('correct');
+        expect(judge('ice cream please')).toBe('correct');
+        expect(judge('a tear', 2)).toBe('correct');
+    });
+
+    test('near misses are found among the other words', () => {
+        expect(judge('its ice creem')).toBe('close');
+        expect(judge('i like ice crem a lot')).toBe('close');
+        expect(judge('i think its teer', 2)).toBe('close');
+        expect(judge('a big ice cube')).toBe('incorrect');
+    });
+
+    test('typos are counted in chars not bytes', () => {
+        // ж is 2 bytes in utf-8, but only 1 typo
+        expect(judge('teжr', 2)).toBe('close');
+        expect(judge('ice creжm')).toBe('close');
+    });
+});
+
+// a stroke in the primary color from (0.25, 0.25) to
+// (0.5, 0.5), and a dot in the secondary color
+const strokeOps = [
+    DrawOp.fromPartial({
+        startStroke: { colorType: ColorType.PRIMARY, x: 0.25, y: 0.25 },
+    }),
+    DrawOp.fromPartial({ continueStroke: { x: 0.5, y: 0.5 } }),
+];
+const dotOps = [
+    DrawOp.fromPartial({
+        startStroke: { colorType: ColorType.SECONDARY, x: 0.75, y: 0.75 },
+    }),
+];
+const stroke = {
+    color: '#000000',
+    points: [
+        { x: 0.25, y: 0.25 },
+        { x: 0.5, y: 0.5 },
+    ],
+};
+const dot = { color: '#ffffff', points: [{ x: 0.75, y: 0.75 }] };
+const clearOp = DrawOp.fromPartial({ clearScreen: {} });
+const undoOp = DrawOp.fromPartial({ undo: {} });
+const redoOp = DrawOp.fromPartial({ redo: {} });
+
+// what's on adam's canvas after the draw ops
+function strokesAfter(drawOps: DrawOp[]) {
+    let game = playingGame();
+    game.round!.drawOps = drawOps;
+    return round_strokes(Game.encode(game).finish(), 0);
+}
+
+// positions of the letters the server reveals, in order,
+// until it runs out of hints for the round
+function revealedLetters(easyWord: number, roundId: number): number[] {
+    let game = playingGame();
+    game.round!.easyWord = easyWord;
+    game.round!.roundId = roundId;
+    let revealed: number[] = [];
+    let encoded: Uint8Array | undefined;
+    while ((encoded = round_next_hint(Game.encode(game).finish()))) {
+        let hint = Hint.decode(encoded);
+        game.round!.hints.push(hint);
+        if (hint.revealLetter) {
+            revealed.push(hint.revealLetter.index);
+        }
+    }
+    return revealed;
+}
+
+describe('mini game hints', { timeout: 500 }, () => {
+    test('revealed letters are spread across the word', () => {
+        // "ice cream", half its letters, none of them neighbors
+        expect(revealedLetters(1, 0)).toStrictEqual([0, 8, 4, 2]);
+        expect(revealedLetters(1, 3)).toStrictEqual([4, 0, 8, 2]);
+        // "tear"
+        expect(revealedLetters(2, 0)).toStrictEqual([0, 3]);
+        expect(revealedLetters(2, 1)).toStrictEqual([1, 3]);
+    });
+});
+
+describe('mini game canvas', { timeout: 500 }, () => {
+    test('undo & redo step thru strokes and clears', () => {
+        let drawOps = [
+            ...strokeOps,
+            clearOp,
+            ...dotOps,
+            undoOp,
+            undoOp,
+            redoOp,
+            redoOp,
+        ];
+        let expected = [
+            [stroke],
+            [stroke],
+            // clear
+            [],
+            // dot
+            [dot],
+            // undo the dot
+            [],
+            // undo the clear
+            [stroke],
+            // redo the clear
+            [],
+            // redo the dot
+            [dot],
+        ];
+        for (let ops = 1; ops <= drawOps.length; ops++) {
+            expect(strokesAfter(drawOps.slice(0, ops))).toStrictEqual(
+                expected[ops - 1],
+            );
+        }
+    });
+
+    test('a new stroke clears what could be redone', () => {
+        let strokes = strokesAfter([
+            ...strokeOps,
+            ...dotOps,
+            undoOp,
+            ...dotOps,
+            redoOp,
+        ]);
+        expect(strokes).toStrictEqual([stroke, dot]);
+    });
+
+    test('clearing a blank canvas is not an edit', () => {
+        let strokes = strokesAfter([...strokeOps, undoOp, clearOp, redoOp]);
+        expect(strokes).toStrictEqual([stroke]);
+    });
+
+    test('undo & redo with nothing to undo or redo do nothing', () => {
+        let strokes = strokesAfter([undoOp, redoOp, ...strokeOps, redoOp]);
+        expect(strokes).toStrictEqual([stroke]);
+    });
+});
+
+// coords are quantized to 4095 steps per side, so they
+// come back at most half a step off, plus f32 rounding
+const QUANTIZED_MAX = 4095;
+const MAX_QUANTIZE_ERROR = 0.5 / QUANTIZED_MAX + 1e-6;
+
+function encodeStrokePoints(points: number[][]): DrawOp | undefined {
+    let encoded = encode_stroke_points(
+        true,
+        ColorType.PRIMARY,
+        new Float32Array(points.flat()),
+    );
+    return encoded && DrawOp.decode(encoded);
+}
+
+// why the server turned down adam's stroke points,
+// undefined if it took them
+function strokePointsRejectReason(
+    x: number,
+    y: number,
+    deltas: number[],
+): DrawOpRejectReason | undefined {
+    let playerDrawOp = ServerEvent.fromPartial({
+        playerDrawOp: {
+            drawerId: 0,
+            drawOp: {
+                strokePoints: { start: true, x, y, deltas },
+            },
+        },
+    });
+    let next_state = server_advance(
+        ServerEvent.encode(playerDrawOp).finish(),
+        Game.encode(playingGame()).finish(),
+        PLAY_STARTED_AT,
+    );
+    let serverEvents = ServerEvents.decode(
+        new Uint8Array(next_state.apply_events),
+    );
+    return serverEvents.events[0].playerDrawOpReject?.reason;
+}
+
+describe('mini game stroke points', { timeout: 500 }, () => {
+    test('points round trip within the quantization error', () => {
+        let points = [
+            [0.1, 0.2],
+            [0.3, 0.4],
+            [0.9, 0.05],
+            [1, 0],
+            [0, 1],
+            [0.123456, 0.654321],
+        ];
+        let drawOp = encodeStrokePoints(points)!;
+        expect(drawOp.strokePoints!.deltas).toHaveLength(
+            (points.length - 1) * 2,
+        );
+        let strokes = strokesAfter([drawOp]);
+        expect(strokes).toHaveLength(1);
+        expect(strokes[0].points).toHaveLength(points.length);
+        strokes[0].points.forEach(
+            (point: { x: number; y: number }, idx: number) => {
+                let [x, y] = points[idx];
+                expect(Math.abs(point.x - x)).toBeLessThanOrEqual(
+                    MAX_QUANTIZE_ERROR,
+                );
+                expect(Math.abs(point.y - y)).toBeLessThanOrEqual(
+                    MAX_QUANTIZE_ERROR,
+                );
+            },
+        );
+    });
+
+    test('batches must have 1 to 128 points', () => {
+        let points = (count: number) =>
+            Array.from({ length: count }, (_, idx) => [idx / 200, 0.5]);
+        expect(encodeStrokePoints([])).toBeUndefined();
+        expect(encodeStrokePoints(points(128))).toBeDefined();
+        expect(encodeStrokePoints(points(129))).toBeUndefined();
+        expect(() =>
+            encode_stroke_points(
+                true,
+                ColorType.PRIMARY,
+                new Float32Array([0.5, 0.5, 0.5]),
+            ),
+        ).toThrow();
+    });
+
+    test('points on the canvas are accepted', () => {
+        expect(strokePointsRejectReason(10, 10, [1, 1])).toBeUndefined();
+        expect(
+            strokePointsRejectReason(QUANTIZED_MAX, QUANTIZED_MAX, []),
+        ).toBeUndefined();
+        expect(
+            strokePointsRejectReason(0, 0, new Array(127 * 2).fill(0)),
+        ).toBeUndefined();
+    });
+
+    test('odd length deltas are rejected', () => {
+        expect(strokePointsRejectReason(10, 10, [1])).toBe(
+            DrawOpRejectReason.INVALID_POINT,
+        );
+        expect(strokePointsRejectReason(10, 10, [1, 1, 1])).toBe(
+            DrawOpRejectReason.INVALID_POINT,
+        );
+    });
+
+    test('points off the canvas are rejected', () => {
+        expect(strokePointsRejectReason(QUANTIZED_MAX + 1, 0, [])).toBe(
+            DrawOpRejectReason.INVALID_POINT,
+        );
+        expect(strokePointsRejectReason(0, QUANTIZED_MAX + 1, [])).toBe(
+            DrawOpRejectReason.INVALID_POINT,
+        );
+        // the deltas walk a point that started on the canvas off it
+        expect(strokePointsRejectReason(QUANTIZED_MAX, 0, [1, 0])).toBe(
+            DrawOpRejectReason.INVALID_POINT,
+        );
+        expect(strokePointsRejectReason(0, 0, [0, -1])).toBe(
+            DrawOpRejectReason.INVALID_POINT,
+        );
+    });
+
+    test('too many points are rejected', () => {
+        expect(strokePointsRejectReason(0, 0, new Array(128 * 2).fill(0))).toBe(
+            DrawOpRejectReason.TOO_MANY_POINTS,
+        );
+    });
+});
+
+// adam draws 3 ops, bob guesses before the 1st, cat
+// right after the 1st along with a hint, and the 2nd
+// hint and dan's guess are anchored past the last op,
+// e.g. the drawer stopped drawing before they came in
+function replayingGame(): Game {
+    let game = playingGame();
+    let round = game.round!;
+    round.drawOps = [0, 0.25, 0.5].map((x) =>
+        DrawOp.fromPartial({ continueStroke: { x, y: 0.5 } }),
+    );
+    let guess = (guesserId: number, afterDrawOps: number) =>
+        Guess.fromPartial({
+            guesserId,
+            incorrectGuess: { guess: 'guess' },
+            afterDrawOps,
+        });
+    round.guesses = [guess(1, 0), guess(2, 1), guess(3, 7)];
+    let hint = (index: number, afterDrawOps: number) =>
+        Hint.fromPartial({
+            revealLetter: { index, letter: 'i' },
+            afterDrawOps,
+        });
+    round.hints = [hint(0, 1), hint(4, 4)];
+    return game;
+}
+
+// labels the replayed events, e.g. "op 0.25" for the
+// draw op at x 0.25, "guess 1" for bob's guess and
+// "hint 0" for the hint revealing the 1st letter
+function replaySteps(encoded: Uint8Array): string[] {
+    return ServerEvents.decode(encoded).events.map((event) => {
+        if (event.playerDrawOp) {
+            return `op ${event.playerDrawOp.drawOp!.continueStroke!.x}`;
+        } else if (event.roundGuess) {
+            return `guess ${event.roundGuess.guess!.guesserId}`;
+        } else if (event.roundHint) {
+            return `hint ${event.roundHint.hint!.revealLetter!.index}`;
+        }
+        throw new Error('unexpected replay event');
+    });
+}
+
+describe('mini game replay', { timeout: 500 }, () => {
+    test('steps are ordered by their anchors', () => {
+        let encoded = round_replay(Game.encode(replayingGame()).finish())!;
+        expect(replaySteps(encoded)).toStrictEqual([
+            'guess 1',
+            'op 0',
+            // hints go before guesses at the same anchor
+            'hint 0',
+            'guess 2',
+            'op 0.25',
+            'op 0.5',
+            // anchors past the last op come after every op
+            'hint 4',
+            'guess 3',
+        ]);
+    });
+
+    test('seeking skips everything up to just after the op', () => {
+        let game = Game.encode(replayingGame()).finish();
+        let stepsFrom = (drawOps: number) =>
+            replaySteps(round_replay_from(game, 0, drawOps)!);
+        expect(stepsFrom(0)).toStrictEqual([
+            'op 0',
+            'hint 0',
+            'guess 2',
+            'op 0.25',
+            'op 0.5',
+            'hint 4',
+            'guess 3',
+        ]);
+        expect(stepsFrom(1)).toStrictEqual([
+            'op 0.25',
+            'op 0.5',
+            'hint 4',
+            'guess 3',
+        ]);
+        expect(stepsFrom(2)).toStrictEqual(['op 0.5', 'hint 4', 'guess 3']);
+        // at the end, whatever's anchored past it is skipped too
+        expect(stepsFrom(3)).toStrictEqual([]);
+        expect(stepsFrom(10)).toStrictEqual([]);
+    });
+
+    test("players who aren't drawing have no replay", () => {
+        let game = Game.encode(replayingGame()).finish();
+        expect(round_replay_from(game, 1, 0)).toBeUndefined();
+    });
+});
+
+// the playing game after adam, the host, ended the match,
+// with everyone still holding their scores
+function gameOverGame(): Game {
+    let game = playingGame();
+    game.round!.phase = Phase.GAME_OVER;
+    for (let player of Object.values(game.players)) {
+        player.drawScore = 100;
+        player.guessScore = 50;
+        player.timesDrawn = 1;
+    }
+    return game;
+}
+
+describe('mini game rounds & matches', { timeout: 500 }, () => {
+    test('a new round resets round scores & counts the drawer', () => {
+        let game = playingGame();
+        game.players[1].roundScore = 200;
+        let newRound = ServerEvent.fromPartial({
+            newRound: {
+                roundId: 1,
+                drawerId: 1,
+                easyWord: 2,
+                hardWord: 3,
+                startingPhase: Phase.CHOOSE_WORD,
+                phaseEndsAt: PLAY_STARTED_AT + 10_000,
+            },
+        });
+        let serverGame = expectServerClientGamesInSync(newRound, game)!;
+        expect(serverGame.round!.roundId).toBe(1);
+        expect(serverGame.round!.drawerId).toBe(1);
+        expect(serverGame.round!.phase).toBe(Phase.CHOOSE_WORD);
+        expect(serverGame.players[1].roundScore).toBe(0);
+        expect(serverGame.players[1].timesDrawn).toBe(1);
+        expect(serverGame.roundLikes).toHaveLength(1);
+        // the same round again is ignored
+        expect(
+            expectServerClientGamesInSync(newRound, serverGame),
+        ).toBeUndefined();
+    });
+
+    test('only the drawer draws, and only while playing', () => {
+        let drawOp = (drawerId: number) =>
+            ServerEvent.fromPartial({
+                playerDrawOp: { drawerId, drawOp: strokeOps[0] },
+            });
+        let serverGame = expectServerClientGamesInSync(
+            drawOp(0),
+            playingGame(),
+        )!;
+        expect(serverGame.round!.drawOps).toStrictEqual([strokeOps[0]]);
+        expect(
+            expectServerClientGamesInSync(drawOp(1), playingGame()),
+        ).toBeUndefined();
+        let game = playingGame();
+        game.round!.phase = Phase.POST_PLAY;
+        expect(expectServerClientGamesInSync(drawOp(0), game)).toBeUndefined();
+    });
+
+    test('choosing a word starts the pre play phase', () => {
+        let game = playingGame();
+        game.round!.phase = Phase.CHOOSE_WORD;
+        let chooseWord = ServerEvent.fromPartial({
+            playerChooseWord: { drawerId: 0, choice: WordChoice.HARD },
+        });
+        let serverGame = expectServerClientGamesInSync(
+            chooseWord,
+            game,
+            PLAY_STARTED_AT,
+        )!;
+        expect(serverGame.round!.wordChoice).toBe(WordChoice.HARD);
+        expect(serverGame.round!.phase).toBe(Phase.PRE_PLAY);
+        expect(serverGame.round!.phaseEndsAt).toBe(PLAY_STARTED_AT + 5_000);
+    });
+
+    test('game over unreadies everyone', () => {
+        let game = playingGame();
+        game.players[1].ready = true;
+        let gameOver = ServerEvent.fromPartial({ gameOver: {} });
+        let serverGame = expectServerClientGamesInSync(gameOver, game)!;
+        expect(serverGame.round!.phase).toBe(Phase.GAME_OVER);
+        expect(serverGame.players[1].ready).toBe(false);
+    });
+
+    test('the host ending the game is game over', () => {
+        let endGame = (playerId: number) =>
+            ServerEvent.fromPartial({ playerEndGame: { playerId } });
+        let serverGame = expectServerClientGamesInSync(
+            endGame(0),
+            playingGame(),
+        )!;
+        expect(serverGame.round!.phase).toBe(Phase.GAME_OVER);
+        // the server sends game over instead, applying
+        // the end game itself lands in the same place
+        let clientGame = advanceAllGame(
+            ServerEvents.fromPartial({ events: [endGame(0)] }),
+            playingGame(),
+        );
+        expect(clientGame).toStrictEqual(serverGame);
+        expect(
+            expectServerClientGamesInSync(endGame(1), playingGame()),
+        ).toBeUndefined();
+    });
+
+    test('the host playing again wipes the scores', () => {
+        let playAgain = (playerId: number) =>
+            ServerEvent.fromPartial({ playerPlayAgain: { playerId } });
+        let serverGame = expectServerClientGamesInSync(
+            playAgain(0),
+            gameOverGame(),
+        )!;
+        expect(serverGame.round).toBeUndefined();
+        expect(serverGame.players[1].drawScore).toBe(0);
+        expect(serverGame.players[1].timesDrawn).toBe(0);
+        expect(
+            expectServerClientGamesInSync(playAgain(1), gameOverGame()),
+        ).toBeUndefined();
+    });
+
+    test('starting a match after game over plays again', () => {
+        let startMatch = (playerId: number) =>
+            ServerEvent.fromPartial({ playerStartMatch: { playerId } });
+        let serverGame = expectServerClientGamesInSync(
+            startMatch(0),
+            gameOverGame(),
+        )!;
+        expect(serverGame.round).toBeUndefined();
+        expect(serverGame.players[2].guessScore).toBe(0);
+        // a match is already on
+        expect(
+            expectServerClientGamesInSync(startMatch(0), playingGame()),
+        ).toBeUndefined();
+    });
+});
+
+function likeRound(playerId: number): ServerEvent {
+    return ServerEvent.fromPartial({
+        playerLikeRound: { playerId, roundId: 0 },
+    });
+}
+
+describe('mini game likes', { timeout: 500 }, () => {
+    test('a like is recorded once & scores the drawer', () => {
+        let game = playingGame();
+        game.roundLikes = [RoundLikes.fromPartial({ roundId: 0, drawerId: 0 })];
+        let serverGame = expectServerClientGamesInSync(likeRound(1), game)!;
+        expect(serverGame.roundLikes[0].likerIds).toStrictEqual([1]);
+        // the default like bonus
+        expect(serverGame.players[0].drawScore).toBe(5);
+        expect(serverGame.round!.drawScore).toBe(5);
+        expect(
+            expectServerClientGamesInSync(likeRound(1), serverGame),
+        ).toBeUndefined();
+    });
+
+    test("drawers can't like their own round", () => {
+        let game = playingGame();
+        game.roundLikes = [RoundLikes.fromPartial({ roundId: 0, drawerId: 0 })];
+        expect(
+            expectServerClientGamesInSync(likeRound(0), game),
+        ).toBeUndefined();
+    });
+});
+
+function kick(hostId: number, playerId: number): ServerEvent {
+    return ServerEvent.fromPartial({ playerKick: { hostId, playerId } });
+}
+
+describe('mini game kicks', { timeout: 500 }, () => {
+    test('the host can kick other players', () => {
+        let serverGame = expectServerClientGamesInSync(
+            kick(0, 2),
+            playingGame(),
+        )!;
+        expect(Object.keys(serverGame.players)).toStrictEqual(['0', '1']);
+    });
+
+    test("only the host kicks, and they can't kick themselves", () => {
+        expect(
+            expectServerClientGamesInSync(kick(1, 2), playingGame()),
+        ).toBeUndefined();
+        expect(
+            expectServerClientGamesInSync(kick(0, 0), playingGame()),
+        ).toBeUndefined();
+    });
+});
+
+function ready(playerId: number, ready: boolean = true): ServerEvent {
+    return ServerEvent.fromPartial({ playerReady: { playerId, ready } });
+}
+
+describe('mini game ready flags', { timeout: 500 }, () => {
+    test('players ready up in the lobby', () => {
+        let game = playingGame();
+        game.round = undefined;
+        let serverGame = expectServerClientGamesInSync(ready(1), game)!;
+        expect(serverGame.players[1].ready).toBe(true);
+        expect(
+            expectServerClientGamesInSync(ready(1), serverGame),
+        ).toBeUndefined();
+        serverGame = expectServerClientGamesInSync(
+            ready(1, false),
+            serverGame,
+        )!;
+        expect(serverGame.players[1].ready).toBe(false);
+    });
+
+    test("players can't ready up mid match", () => {
+        expect(
+            expectServerClientGamesInSync(ready(1), playingGame()),
+        ).toBeUndefined();
+    });
+
+    test('everyone ready after game over plays again', () => {
+        let game = gameOverGame();
+        game.players[0].ready = true;
+        let serverGame = expectServerClientGamesInSync(ready(1), game)!;
+        // cat isn't ready yet
+        expect(serverGame.round!.phase).toBe(Phase.GAME_OVER);
+        serverGame = expectServerClientGamesInSync(ready(2), serverGame)!;
+        expect(serverGame.round).toBeUndefined();
+        expect(serverGame.players[2].drawScore).toBe(0);
+        expect(serverGame.players[2].ready).toBe(true);
+    });
+});
+
+describe('mini game spectators', { timeout: 500 }, () => {
+    test('only how many are watching is shared', () => {
+        let spectators = ServerEvent.fromPartial({
+            spectators: { spectators: 3 },
+        });
+        let serverGame = expectServerClientGamesInSync(
+            spectators,
+            playingGame(),
+        )!;
+        expect(serverGame.spectators).toBe(3);
+    });
+});
+
+// the playing game as a duel between adam & bob,
+// with cat left to vote for the best drawing
+function votingGame(): Game {
+    let game = playingGame();
+    game.round!.rivalId = 1;
+    game.round!.phase = Phase.VOTE;
+    return game;
+}
+
+function vote(voterId: number, drawerId: number): ServerEvent {
+    return ServerEvent.fromPartial({ playerVote: { voterId, drawerId } });
+}
+
+describe('mini game votes', { timeout: 500 }, () => {
+    test('the last vote closes the vote & scores the winner', () => {
+        let serverGame = expectServerClientGamesInSync(
+            vote(2, 1),
+            votingGame(),
+            PLAY_STARTED_AT,
+        )!;
+        expect(serverGame.round!.votes).toStrictEqual([
+            { voterId: 2, drawerId: 1 },
+        ]);
+        // the default vote bonus
+        expect(drawGuessScores(serverGame)).toStrictEqual([
+            [0, 0],
+            [50, 0],
+            [0, 0],
+        ]);
+        expect(serverGame.round!.phase).toBe(Phase.POST_PLAY);
+        expect(serverGame.round!.phaseEndsAt).toBe(PLAY_STARTED_AT + 5_000);
+    });
+
+    test('drawers & players who voted already get no vote', () => {
+        expect(
+            expectServerClientGamesInSync(vote(0, 1), votingGame()),
+        ).toBeUndefined();
+        let game = votingGame();
+        game.round!.votes = [{ voterId: 2, drawerId: 0 }];
+        expect(expectServerClientGamesInSync(vote(2, 1), game)).toBeUndefined();
+    });
+});
+
+function chat(playerId: number, text: string): ServerEvent {
+    return ServerEvent.fromPartial({ playerChat: { playerId, text } });
+}
+
+describe('mini game chat', { timeout: 500 }, () => {
+    test('messages are trimmed & kept in order', () => {
+        let serverGame = expectServerClientGamesInSync(
+            chat(1, '  hi there  '),
+            playingGame(),
+        )!;
+        serverGame = expectServerClientGamesInSync(chat(2, 'hey'), serverGame)!;
+        expect(serverGame.chat).toStrictEqual([
+            { playerId: 1, text: 'hi there' },
+            { playerId: 2, text: 'hey' },
+        ]);
+    });
+
+    test('the oldest messages are dropped once the history is full', () => {
+        let game = playingGame();
+        game.chat = Array.from({ length: MAX_CHAT_HISTORY }, (_, i) => ({
+            playerId: 1,
+            text: `${i}`,
+        }));
+        let serverGame = expectServerClientGamesInSync(chat(2, 'hey'), game)!;
+        expect(serverGame.chat).toHaveLength(MAX_CHAT_HISTORY);
+        expect(serverGame.chat[0].text).toBe('1');
+        expect(serverGame.chat[MAX_CHAT_HISTORY - 1].text).toBe('hey');
+    });
+
+    test("the word can't be given away before it's revealed", () => {
+        let serverGame = expectServerClientGamesInSync(
+            chat(1, 'is it ice cream?'),
+            playingGame(),
+        )!;
+        expect(serverGame.chat).toStrictEqual([]);
+    });
+});
@@ -1,4 +1,10 @@
 /* tslint:disable */
 /* eslint-disable */
-export function client_advance(player_id: number, client_msg: Uint8Array, current_game: Uint8Array): any;
-export function server_advance(server_msg: Uint8Array, current_game: Uint8Array): any;
+export function client_advance(player_id: number, client_msg: Uint8Array, current_game: Uint8Array, now: number): any;
+export function encode_stroke_points(start: boolean, color_type: number, points: Float32Array): Uint8Array | undefined;
+export function round_next_hint(current_game: Uint8Array): Uint8Array | undefined;
+export function round_replay(current_game: Uint8Array): Uint8Array | undefined;
+export function round_replay_from(current_game: Uint8Array, drawer_id: number, draw_ops: number): Uint8Array | undefined;
+export function round_strokes(current_game: Uint8Array, drawer_id: number): any;
+export function round_svg(current_game: Uint8Array): string | undefined;
+export function server_advance(server_msg: Uint8Array, current_game: Uint8Array, now: number): any;
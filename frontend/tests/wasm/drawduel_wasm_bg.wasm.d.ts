@@ -1,8 +1,15 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
-export const client_advance: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
-export const server_advance: (a: number, b: number, c: number, d: number, e: number) => void;
+export const client_advance: (a: number, b: number, c: number, d: number, e: number, f: number, g: number) => void;
+export const encode_stroke_points: (a: number, b: number, c: number, d: number, e: number) => void;
+export const round_next_hint: (a: number, b: number, c: number) => void;
+export const round_replay: (a: number, b: number, c: number) => void;
+export const round_replay_from: (a: number, b: number, c: number, d: number, e: number) => void;
+export const round_strokes: (a: number, b: number, c: number, d: number) => void;
+export const round_svg: (a: number, b: number, c: number) => void;
+export const server_advance: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
 export const __wbindgen_export_0: (a: number, b: number) => number;
 export const __wbindgen_export_1: (a: number, b: number, c: number, d: number) => number;
+export const __wbindgen_export_2: (a: number, b: number, c: number) => void;
 export const __wbindgen_add_to_stack_pointer: (a: number) => number;